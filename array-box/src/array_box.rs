@@ -1,3 +1,13 @@
+use std::ffi::c_void;
+use std::mem::ManuallyDrop;
+use std::ops::Range;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// A function of the foreign owner of the data that is called with the release data
+/// once the array does not refer to the foreign data anymore.
+pub type ArrayBoxRelease = Option<extern "C" fn(*mut c_void)>;
+
 #[derive(Debug)]
 #[repr(C)]
 pub struct ArrayBox<T> {
@@ -5,6 +15,79 @@ pub struct ArrayBox<T> {
     pub length: usize,
     pub capacity: usize,
     pub owned: bool,
+    /// false once the foreign owner of the data invalidated it
+    pub valid: bool,
+    pub release: ArrayBoxRelease,
+    pub release_data: *mut c_void,
+    /// Shared with the views of my buffer, see [`ArrayBox::sub_array`]
+    views: ViewedBuffer,
+    /// The buffer of another array that I view
+    viewed: ViewedBuffer,
+}
+
+/// Tells the views of a buffer whether it is still there.
+/// It is a reference counted flag behind a nullable pointer, so that the array stays C-compatible.
+#[derive(Debug)]
+#[repr(C)]
+struct ViewedBuffer {
+    flag: *const c_void,
+}
+
+impl ViewedBuffer {
+    fn new() -> Self {
+        Self {
+            flag: Arc::into_raw(Arc::new(AtomicBool::new(true))) as *const c_void,
+        }
+    }
+
+    fn null() -> Self {
+        Self {
+            flag: std::ptr::null(),
+        }
+    }
+
+    fn is_null(&self) -> bool {
+        self.flag.is_null()
+    }
+
+    fn with_flag<R>(&self, op: impl FnOnce(&Arc<AtomicBool>) -> R) -> Option<R> {
+        if self.is_null() {
+            return None;
+        }
+        let flag = ManuallyDrop::new(unsafe { Arc::from_raw(self.flag as *const AtomicBool) });
+        Some(op(&flag))
+    }
+
+    /// The amount of arrays referring to the buffer
+    fn count(&self) -> usize {
+        self.with_flag(Arc::strong_count).unwrap_or(0)
+    }
+
+    fn is_freed(&self) -> bool {
+        self.with_flag(|flag| !flag.load(Ordering::Acquire))
+            .unwrap_or(false)
+    }
+
+    fn free(&self) {
+        self.with_flag(|flag| flag.store(false, Ordering::Release));
+    }
+}
+
+impl Clone for ViewedBuffer {
+    fn clone(&self) -> Self {
+        self.with_flag(|flag| Self {
+            flag: Arc::into_raw(flag.clone()) as *const c_void,
+        })
+        .unwrap_or_else(Self::null)
+    }
+}
+
+impl Drop for ViewedBuffer {
+    fn drop(&mut self) {
+        if !self.is_null() {
+            drop(unsafe { Arc::from_raw(self.flag as *const AtomicBool) });
+        }
+    }
 }
 
 impl<T> ArrayBox<T> {
@@ -14,6 +97,11 @@ impl<T> ArrayBox<T> {
             capacity: 0,
             data: std::ptr::null_mut(),
             owned: true,
+            valid: true,
+            release: None,
+            release_data: std::ptr::null_mut(),
+            views: ViewedBuffer::null(),
+            viewed: ViewedBuffer::null(),
         }
     }
 
@@ -33,24 +121,75 @@ impl<T> ArrayBox<T> {
 
     /// Create an array assuming that I don't own the data
     pub fn from_data(data: *mut T, length: usize) -> Self {
+        Self::from_foreign_data(data, length, None, std::ptr::null_mut())
+    }
+
+    /// Create an array that borrows the data of a foreign owner.
+    /// I call the release function with the release data once I am dropped or hold other data,
+    /// the owner must keep the data alive until then or invalidate me before freeing it.
+    pub fn from_foreign_data(
+        data: *mut T,
+        length: usize,
+        release: ArrayBoxRelease,
+        release_data: *mut c_void,
+    ) -> Self {
         ArrayBox {
             length,
             capacity: length,
             data,
             owned: false,
+            valid: true,
+            release,
+            release_data,
+            views: ViewedBuffer::null(),
+            viewed: ViewedBuffer::null(),
         }
     }
 
+    /// The foreign owner freed or moved my data, I forget about it
+    /// and must not be accessed anymore.
+    /// My views are invalidated too.
+    pub fn invalidate(&mut self) {
+        self.forget_views();
+        Self::free_buffer(self.data, self.length, self.capacity, self.owned);
+        self.data = std::ptr::null_mut();
+        self.length = 0;
+        self.capacity = 0;
+        self.owned = false;
+        self.valid = false;
+        // the owner already knows that the data is not used anymore
+        self.release = None;
+        self.release_data = std::ptr::null_mut();
+        self.viewed = ViewedBuffer::null();
+    }
+
+    /// Return false if my data was invalidated by its foreign owner,
+    /// or if I am a view of a buffer that was freed or moved
+    pub fn is_valid(&self) -> bool {
+        self.valid && !self.viewed.is_freed()
+    }
+
+    /// Return true if there are views of my buffer, in which case
+    /// I must not be resized, see [`ArrayBox::sub_array`]
+    pub fn has_views(&self) -> bool {
+        self.views.count() > 1
+    }
+
     /// Mutate me to hold a given vector
     pub fn set_vector(&mut self, vector: Vec<T>) {
         // first free existing char buffer
+        self.forget_views();
         Self::free_buffer(self.data, self.length, self.capacity, self.owned);
+        self.release_foreign_data();
         let mut data = vector;
         data.shrink_to_fit();
 
         self.length = data.len();
         self.capacity = data.capacity();
-        self.data = Self::vec_to_buffer(data)
+        self.data = Self::vec_to_buffer(data);
+        self.owned = true;
+        self.valid = true;
+        self.viewed = ViewedBuffer::null();
     }
 
     /// Mutate me to hold a given vector
@@ -62,8 +201,13 @@ impl<T> ArrayBox<T> {
         self.set_vector(vector);
     }
 
+    /// Return my items, which are empty if I am a view of a buffer that was freed
     pub fn to_slice(&self) -> &mut [T] {
-        unsafe { std::slice::from_raw_parts_mut(self.data, self.length) }
+        let data = self.data();
+        if data.is_null() {
+            return &mut [];
+        }
+        unsafe { std::slice::from_raw_parts_mut(data, self.length) }
     }
 
     pub fn copy_into(&self, another_array: &mut ArrayBox<T>) {
@@ -71,9 +215,9 @@ impl<T> ArrayBox<T> {
             self.length <= another_array.length,
             "The source does not fit into destination"
         );
-        assert!(!self.data.is_null(), "The source data must not be nil");
+        assert!(!self.data().is_null(), "The source data must not be nil");
         assert!(
-            !another_array.data.is_null(),
+            !another_array.data().is_null(),
             "The destination data must not be nil"
         );
         unsafe { std::ptr::copy_nonoverlapping::<T>(self.data, another_array.data, self.length) }
@@ -83,9 +227,13 @@ impl<T> ArrayBox<T> {
     where
         T: Clone,
     {
+        if self.data().is_null() {
+            return vec![];
+        }
         let vector = unsafe { Vec::from_raw_parts(self.data, self.length, self.capacity) };
         if self.owned {
             // I do not own data anymore
+            self.forget_views();
             self.owned = false;
             self.data = std::ptr::null_mut();
             vector
@@ -99,6 +247,10 @@ impl<T> ArrayBox<T> {
 
     pub fn at_put(&mut self, index: usize, object: T) {
         assert!(index < self.length, "Index must be less than array length");
+        assert!(
+            !self.viewed.is_freed(),
+            "The array must not be a view of a freed buffer"
+        );
 
         let slice = self.to_slice();
         slice[index] = object;
@@ -109,13 +261,125 @@ impl<T> ArrayBox<T> {
         T: Clone,
     {
         assert!(index < self.length, "Index must be less than array length");
+        assert!(
+            !self.viewed.is_freed(),
+            "The array must not be a view of a freed buffer"
+        );
 
         let slice = self.to_slice();
         slice[index].clone()
     }
 }
 
+impl<T> ArrayBox<T>
+where
+    T: Clone,
+{
+    /// Append an item to the end of the array
+    pub fn push(&mut self, item: T) {
+        self.with_vector(|vector| vector.push(item))
+    }
+
+    /// Remove the last item of the array or return None if it is empty
+    pub fn pop(&mut self) -> Option<T> {
+        self.with_vector(|vector| vector.pop())
+    }
+
+    /// Insert an item at a given index shifting all items after it to the right.
+    /// Panics if the index is greater than the length
+    pub fn insert(&mut self, index: usize, item: T) {
+        assert!(
+            index <= self.length,
+            "Index must not be greater than array length"
+        );
+        self.with_vector(|vector| vector.insert(index, item))
+    }
+
+    /// Remove and return an item at a given index shifting all items after it to the left.
+    /// Panics if the index is out of bounds
+    pub fn remove(&mut self, index: usize) -> T {
+        assert!(index < self.length, "Index must be less than array length");
+        self.with_vector(|vector| vector.remove(index))
+    }
+
+    /// Shorten the array to a given length, has no effect if the array is already shorter
+    pub fn truncate(&mut self, length: usize) {
+        if length < self.length {
+            self.with_vector(|vector| vector.truncate(length))
+        }
+    }
+
+    /// Resize the array to a given length filling new slots with a given item
+    pub fn resize(&mut self, length: usize, item: T) {
+        self.with_vector(|vector| vector.resize(length, item))
+    }
+
+    /// Reserve the capacity for at least the additional amount of items
+    pub fn reserve(&mut self, additional: usize) {
+        self.with_vector(|vector| vector.reserve(additional))
+    }
+
+    /// Append a copy of all items of a given slice to the end of the array
+    pub fn extend_from_slice(&mut self, items: &[T]) {
+        self.with_vector(|vector| vector.extend_from_slice(items))
+    }
+
+    /// Mutate my data as a vector keeping the extra capacity for the next operations.
+    /// Foreign data is copied into an owned vector first and released.
+    /// Panics if there are views of my buffer.
+    fn with_vector<R>(&mut self, op: impl FnOnce(&mut Vec<T>) -> R) -> R {
+        assert!(
+            !self.has_views(),
+            "The array must not be resized while it is viewed"
+        );
+        self.views = ViewedBuffer::null();
+        let mut vector = if self.data.is_null() {
+            vec![]
+        } else if self.owned {
+            unsafe { Vec::from_raw_parts(self.data, self.length, self.capacity) }
+        } else {
+            self.to_slice().to_vec()
+        };
+        // the data is moved into the vector and must not be freed twice if the operation panics
+        self.data = std::ptr::null_mut();
+        self.length = 0;
+        self.capacity = 0;
+        self.release_foreign_data();
+
+        let result = op(&mut vector);
+
+        self.length = vector.len();
+        self.capacity = vector.capacity();
+        self.data = Self::vec_to_buffer(vector);
+        self.owned = true;
+        self.viewed = ViewedBuffer::null();
+        result
+    }
+}
+
 impl<T> ArrayBox<T> {
+    /// Return my data or null if I am a view of a buffer that was freed,
+    /// the data pointer of a view is dangling then
+    fn data(&self) -> *mut T {
+        if self.viewed.is_freed() {
+            std::ptr::null_mut()
+        } else {
+            self.data
+        }
+    }
+
+    /// My buffer is freed or moved, the views must not access it anymore
+    fn forget_views(&mut self) {
+        std::mem::replace(&mut self.views, ViewedBuffer::null()).free();
+    }
+
+    fn release_foreign_data(&mut self) {
+        if let Some(release) = self.release.take() {
+            release(self.release_data);
+        }
+        self.release_data = std::ptr::null_mut();
+    }
+
     fn vec_to_buffer(mut _data: Vec<T>) -> *mut T {
         let _ptr = _data.as_mut_ptr();
         std::mem::forget(_data);
@@ -141,7 +405,9 @@ impl<T> Default for ArrayBox<T> {
 
 impl<T> Drop for ArrayBox<T> {
     fn drop(&mut self) {
+        self.forget_views();
         Self::free_buffer(self.data, self.length, self.capacity, self.owned);
+        self.release_foreign_data();
         self.data = std::ptr::null_mut();
         self.length = 0;
         self.capacity = 0;
@@ -161,9 +427,99 @@ where
     }
 }
 
+impl<T> ArrayBox<T>
+where
+    T: Copy,
+{
+    /// Copy the items within a range into another array starting at a given index.
+    /// Panics if the range or the destination are out of bounds
+    pub fn copy_range_into(
+        &self,
+        range: Range<usize>,
+        another_array: &mut ArrayBox<T>,
+        index: usize,
+    ) {
+        let source = &self.to_slice()[range];
+        another_array.to_slice()[index..index + source.len()].copy_from_slice(source);
+    }
+
+    /// Copy the items within a range to a given index of the same array, the ranges may overlap.
+    /// Panics if the range or the destination are out of bounds
+    pub fn copy_within(&mut self, range: Range<usize>, index: usize) {
+        self.to_slice().copy_within(range, index);
+    }
+
+    /// Set all items within a range to a given item
+    pub fn fill(&mut self, range: Range<usize>, item: T) {
+        self.to_slice()[range].fill(item);
+    }
+
+    pub fn reverse(&mut self) {
+        self.to_slice().reverse();
+    }
+
+    pub fn rotate_left(&mut self, amount: usize) {
+        self.to_slice().rotate_left(amount);
+    }
+
+    pub fn rotate_right(&mut self, amount: usize) {
+        self.to_slice().rotate_right(amount);
+    }
+
+    /// Create an array that views the items within a range without owning them.
+    /// The view shares my buffer, I must not be resized while it exists
+    /// and it becomes invalid once my buffer is freed, see [`ArrayBox::is_valid`].
+    pub fn sub_array(&mut self, range: Range<usize>) -> ArrayBox<T> {
+        assert!(
+            range.start <= range.end && range.end <= self.length,
+            "Range must be within the array"
+        );
+        let data = self.data();
+        let data = if data.is_null() {
+            data
+        } else {
+            unsafe { data.add(range.start) }
+        };
+        // a view of a view shares the buffer of the original array
+        let mut view = ArrayBox::from_data(data, range.len());
+        view.viewed = if self.viewed.is_null() {
+            if self.views.is_null() {
+                self.views = ViewedBuffer::new();
+            }
+            self.views.clone()
+        } else {
+            self.viewed.clone()
+        };
+        view
+    }
+}
+
+/// An array is serialized as a sequence of its elements,
+/// a deserialized array always owns its data.
+/// A view of a freed buffer fails to serialize, see [`ArrayBox::is_valid`].
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> serde::Serialize for ArrayBox<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if !self.is_valid() {
+            return Err(serde::ser::Error::custom(
+                "The array is a view of a buffer that was freed",
+            ));
+        }
+        serializer.collect_seq(self.to_slice().iter())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for ArrayBox<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Vec::<T>::deserialize(deserializer).map(Self::from_vector)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
 
     #[test]
     fn default_array_u8() {
@@ -180,4 +536,114 @@ mod test {
         assert_eq!(array.length, 5);
         assert_eq!(array.data.is_null(), false);
     }
+
+    #[test]
+    fn grow_and_shrink() {
+        let mut array = ArrayBox::<u8>::new();
+        array.push(1);
+        array.extend_from_slice(&[2, 3, 4]);
+        array.insert(0, 0);
+        assert_eq!(array.to_slice(), &[0, 1, 2, 3, 4]);
+
+        assert_eq!(array.remove(1), 1);
+        assert_eq!(array.pop(), Some(4));
+        assert_eq!(array.length, 3);
+
+        array.reserve(10);
+        assert!(array.capacity >= 13);
+        array.resize(5, 9);
+        assert_eq!(array.to_slice(), &[0, 2, 3, 9, 9]);
+
+        array.truncate(2);
+        assert_eq!(array.to_vector(), vec![0, 2]);
+    }
+
+    #[test]
+    fn grow_foreign_data() {
+        let mut data = vec![1u8, 2, 3];
+        let mut array = ArrayBox::from_data(data.as_mut_ptr(), data.len());
+        array.push(4);
+        assert!(array.owned);
+        assert_eq!(array.to_slice(), &[1, 2, 3, 4]);
+        assert_eq!(data, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn copy_and_rearrange_ranges() {
+        let mut array = ArrayBox::<u8>::from_vector(vec![0, 1, 2, 3, 4]);
+        let mut another_array = ArrayBox::<u8>::new_with(0, 4);
+
+        array.copy_range_into(1..3, &mut another_array, 2);
+        assert_eq!(another_array.to_slice(), &[0, 0, 1, 2]);
+
+        array.copy_within(0..3, 2);
+        assert_eq!(array.to_slice(), &[0, 1, 0, 1, 2]);
+
+        array.fill(0..2, 7);
+        array.reverse();
+        assert_eq!(array.to_slice(), &[2, 1, 0, 7, 7]);
+        array.rotate_left(2);
+        assert_eq!(array.to_slice(), &[0, 7, 7, 2, 1]);
+        array.rotate_right(1);
+        assert_eq!(array.to_slice(), &[1, 0, 7, 7, 2]);
+
+        let mut view = array.sub_array(1..4);
+        assert!(!view.owned);
+        view.fill(0..3, 5);
+        assert_eq!(array.to_slice(), &[1, 5, 5, 5, 2]);
+    }
+
+    #[test]
+    fn views_of_a_freed_buffer() {
+        let mut array = ArrayBox::<u8>::from_vector(vec![0, 1, 2, 3, 4]);
+        let mut view = array.sub_array(1..4);
+        let mut view_of_view = view.sub_array(1..2);
+        assert!(array.has_views());
+        assert_eq!(view_of_view.to_slice(), &[2]);
+
+        // a view copies the items when it grows, which does not affect the other views
+        view.push(5);
+        assert!(view.is_valid() && view_of_view.is_valid());
+        assert_eq!(view.to_slice(), &[1, 2, 3, 5]);
+
+        drop(view);
+        assert!(array.has_views());
+        drop(array);
+        assert!(!view_of_view.is_valid());
+        assert_eq!(view_of_view.to_slice(), &[] as &[u8]);
+        assert!(view_of_view.sub_array(0..1).to_slice().is_empty());
+        assert_eq!(view_of_view.to_vector(), Vec::<u8>::new());
+    }
+
+    #[test]
+    #[should_panic(expected = "The array must not be resized while it is viewed")]
+    fn resize_while_viewed() {
+        let mut array = ArrayBox::<u8>::from_vector(vec![0, 1, 2]);
+        let _view = array.sub_array(0..2);
+        array.push(3);
+    }
+
+    static RELEASED: AtomicUsize = AtomicUsize::new(0);
+
+    extern "C" fn release(release_data: *mut c_void) {
+        RELEASED.fetch_add(release_data as usize, Ordering::SeqCst);
+    }
+
+    #[test]
+    fn release_foreign_data() {
+        let mut data = vec![1u8, 2, 3];
+        let release_data = std::ptr::without_provenance_mut(1);
+
+        let array = ArrayBox::from_foreign_data(data.as_mut_ptr(), 3, Some(release), release_data);
+        assert_eq!(array.to_vector(), vec![1, 2, 3]);
+        assert_eq!(RELEASED.load(Ordering::SeqCst), 1);
+
+        let mut array =
+            ArrayBox::from_foreign_data(data.as_mut_ptr(), 3, Some(release), release_data);
+        array.invalidate();
+        assert!(!array.is_valid());
+        assert!(array.to_slice().is_empty());
+        drop(array);
+        assert_eq!(RELEASED.load(Ordering::SeqCst), 1);
+    }
 }
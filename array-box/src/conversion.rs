@@ -0,0 +1,307 @@
+use crate::{ArrayBox, ArrayNumber};
+
+/// How the items of an array are converted to another number type.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[repr(u8)]
+pub enum ArrayConversion {
+    /// Values outside of the range of the target type become its min or max,
+    /// floats are truncated towards zero and NaN becomes 0
+    Saturating = 0,
+    /// Integers keep their lowest bits like the `as` cast,
+    /// floats are truncated towards zero before they are wrapped
+    Wrapping = 1,
+    /// The range of an integer type is mapped onto 0..1 of a float type and back,
+    /// floats are clamped to 0..1 and integers are rescaled between their ranges
+    Normalizing = 2,
+    /// Fails if any value can not be represented exactly by the target type
+    Lossless = 3,
+}
+
+impl TryFrom<u8> for ArrayConversion {
+    /// The unknown value
+    type Error = u8;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Saturating),
+            1 => Ok(Self::Wrapping),
+            2 => Ok(Self::Normalizing),
+            3 => Ok(Self::Lossless),
+            _ => Err(value),
+        }
+    }
+}
+
+/// A number that an [`ArrayBox`] can be converted from and to.
+pub trait ArrayConvert: ArrayNumber {
+    fn is_float() -> bool;
+    /// Return false for fractional, infinite or NaN floats
+    fn is_integral(self) -> bool;
+    fn is_nan(self) -> bool;
+    /// Floats are truncated towards zero and saturate at the range of i128
+    fn to_i128(self) -> i128;
+    fn from_i128_wrapping(value: i128) -> Self;
+    fn from_i128_saturating(value: i128) -> Self;
+    fn from_f64_saturating(value: f64) -> Self;
+    /// The min and max of an integer type, floats saturate at the range of i128
+    fn bounds() -> (i128, i128);
+    /// Map the value onto 0..1
+    fn normalize(self) -> f64;
+    /// Map a value within 0..1 onto the range of the type
+    fn denormalize(value: f64) -> Self;
+}
+
+macro_rules! integer_convert {
+    ($($ty:ty),*) => {
+        $(
+            impl ArrayConvert for $ty {
+                fn is_float() -> bool {
+                    false
+                }
+                #[inline]
+                fn is_integral(self) -> bool {
+                    true
+                }
+                #[inline]
+                fn is_nan(self) -> bool {
+                    false
+                }
+                #[inline]
+                fn to_i128(self) -> i128 {
+                    self as i128
+                }
+                #[inline]
+                fn from_i128_wrapping(value: i128) -> Self {
+                    value as Self
+                }
+                #[inline]
+                fn from_i128_saturating(value: i128) -> Self {
+                    value.clamp(Self::MIN as i128, Self::MAX as i128) as Self
+                }
+                #[inline]
+                fn from_f64_saturating(value: f64) -> Self {
+                    value as Self
+                }
+                #[inline]
+                fn bounds() -> (i128, i128) {
+                    (Self::MIN as i128, Self::MAX as i128)
+                }
+                #[inline]
+                fn normalize(self) -> f64 {
+                    (self as f64 - Self::MIN as f64) / (Self::MAX as f64 - Self::MIN as f64)
+                }
+                #[inline]
+                fn denormalize(value: f64) -> Self {
+                    (value.clamp(0.0, 1.0) * (Self::MAX as f64 - Self::MIN as f64) + Self::MIN as f64)
+                        .round() as Self
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! float_convert {
+    ($($ty:ty),*) => {
+        $(
+            impl ArrayConvert for $ty {
+                fn is_float() -> bool {
+                    true
+                }
+                #[inline]
+                fn is_integral(self) -> bool {
+                    self.is_finite() && self.fract() == 0.0
+                }
+                #[inline]
+                fn is_nan(self) -> bool {
+                    <$ty>::is_nan(self)
+                }
+                #[inline]
+                fn to_i128(self) -> i128 {
+                    self as i128
+                }
+                #[inline]
+                fn from_i128_wrapping(value: i128) -> Self {
+                    value as Self
+                }
+                #[inline]
+                fn from_i128_saturating(value: i128) -> Self {
+                    value as Self
+                }
+                #[inline]
+                fn from_f64_saturating(value: f64) -> Self {
+                    if value.is_finite() {
+                        value.clamp(Self::MIN as f64, Self::MAX as f64) as Self
+                    } else {
+                        value as Self
+                    }
+                }
+                #[inline]
+                fn bounds() -> (i128, i128) {
+                    (Self::MIN as i128, Self::MAX as i128)
+                }
+                #[inline]
+                fn normalize(self) -> f64 {
+                    if <$ty>::is_nan(self) {
+                        0.0
+                    } else {
+                        (self as f64).clamp(0.0, 1.0)
+                    }
+                }
+                #[inline]
+                fn denormalize(value: f64) -> Self {
+                    value.clamp(0.0, 1.0) as Self
+                }
+            }
+        )*
+    };
+}
+
+integer_convert!(u8, i8, u16, i16, u32, i32, u64, i64, usize, isize);
+float_convert!(f32, f64);
+
+fn convert_saturating<T: ArrayConvert, U: ArrayConvert>(item: T) -> U {
+    if T::is_float() {
+        U::from_f64_saturating(item.to_f64())
+    } else {
+        U::from_i128_saturating(item.to_i128())
+    }
+}
+
+fn convert_wrapping<T: ArrayConvert, U: ArrayConvert>(item: T) -> U {
+    if T::is_float() && U::is_float() {
+        U::from_f64_saturating(item.to_f64())
+    } else {
+        U::from_i128_wrapping(item.to_i128())
+    }
+}
+
+/// Integers are rescaled between the ranges of the types exactly,
+/// going through a float would lose the low bits of 64-bit integers
+fn convert_normalizing<T: ArrayConvert, U: ArrayConvert>(item: T) -> U {
+    if T::is_float() || U::is_float() {
+        return U::denormalize(item.normalize());
+    }
+    let (from_min, from_max) = T::bounds();
+    let (to_min, to_max) = U::bounds();
+    // the ranges of 64-bit integers are below 2^64, so that their product fits into u128
+    let offset = (item.to_i128() - from_min) as u128;
+    let from_range = (from_max - from_min) as u128;
+    let to_range = (to_max - to_min) as u128;
+    let scaled = (offset * to_range + from_range / 2) / from_range;
+    U::from_i128_saturating(to_min + scaled as i128)
+}
+
+fn convert_lossless<T: ArrayConvert, U: ArrayConvert>(item: T) -> Option<U> {
+    let converted = convert_saturating::<T, U>(item);
+    let is_exact = if T::is_float() && U::is_float() {
+        converted.to_f64() == item.to_f64() || (item.is_nan() && converted.is_nan())
+    } else {
+        item.is_integral() && converted.to_i128() == item.to_i128()
+    };
+    is_exact.then_some(converted)
+}
+
+impl<T> ArrayBox<T>
+where
+    T: ArrayConvert,
+{
+    /// Create a new array with my items converted to another number type,
+    /// return None if a lossless conversion is not possible.
+    pub fn convert<U: ArrayConvert>(&self, conversion: ArrayConversion) -> Option<ArrayBox<U>> {
+        let items = self.to_slice().iter().copied();
+        let vector = match conversion {
+            ArrayConversion::Saturating => items.map(convert_saturating).collect(),
+            ArrayConversion::Wrapping => items.map(convert_wrapping).collect(),
+            ArrayConversion::Normalizing => items.map(convert_normalizing).collect(),
+            ArrayConversion::Lossless => items.map(convert_lossless).collect::<Option<_>>()?,
+        };
+        Some(ArrayBox::from_vector(vector))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn convert<T: ArrayConvert, U: ArrayConvert>(
+        items: Vec<T>,
+        conversion: ArrayConversion,
+    ) -> Option<Vec<U>> {
+        ArrayBox::from_vector(items)
+            .convert::<U>(conversion)
+            .map(|array| array.to_vector())
+    }
+
+    #[test]
+    fn saturating_and_wrapping() {
+        let items = vec![-1i32, 300, 42];
+        assert_eq!(
+            convert::<_, u8>(items.clone(), ArrayConversion::Saturating),
+            Some(vec![0, 255, 42])
+        );
+        assert_eq!(
+            convert::<_, u8>(items, ArrayConversion::Wrapping),
+            Some(vec![255, 44, 42])
+        );
+        assert_eq!(
+            convert::<_, i8>(vec![1.9f32, -300.0, f32::NAN], ArrayConversion::Saturating),
+            Some(vec![1, -128, 0])
+        );
+        assert_eq!(
+            convert::<_, f32>(vec![1e300f64], ArrayConversion::Saturating),
+            Some(vec![f32::MAX])
+        );
+    }
+
+    #[test]
+    fn normalizing() {
+        assert_eq!(
+            convert::<_, f32>(vec![0u8, 51, 255], ArrayConversion::Normalizing),
+            Some(vec![0.0, 0.2, 1.0])
+        );
+        assert_eq!(
+            convert::<_, u8>(vec![-1.0f64, 0.2, 2.0], ArrayConversion::Normalizing),
+            Some(vec![0, 51, 255])
+        );
+        assert_eq!(
+            convert::<_, u16>(vec![0u8, 1, 255], ArrayConversion::Normalizing),
+            Some(vec![0, 257, 65535])
+        );
+        assert_eq!(
+            convert::<_, u64>(vec![0u64, 1, u64::MAX - 1], ArrayConversion::Normalizing),
+            Some(vec![0, 1, u64::MAX - 1])
+        );
+        assert_eq!(
+            convert::<_, u64>(vec![i64::MIN, -1, i64::MAX], ArrayConversion::Normalizing),
+            Some(vec![0, (1 << 63) - 1, u64::MAX])
+        );
+        assert_eq!(
+            convert::<_, u8>(vec![0u16, 128, 65535], ArrayConversion::Normalizing),
+            Some(vec![0, 0, 255])
+        );
+    }
+
+    #[test]
+    fn lossless() {
+        assert_eq!(
+            convert::<_, f64>(vec![1u64, 1 << 52], ArrayConversion::Lossless),
+            Some(vec![1.0, (1u64 << 52) as f64])
+        );
+        assert_eq!(
+            convert::<_, f32>(vec![u64::MAX], ArrayConversion::Lossless),
+            None
+        );
+        assert_eq!(
+            convert::<_, i64>(vec![2f64.powi(63)], ArrayConversion::Lossless),
+            None
+        );
+        assert_eq!(
+            convert::<_, u8>(vec![1.5f32], ArrayConversion::Lossless),
+            None
+        );
+        assert_eq!(
+            convert::<_, i16>(vec![-5i8], ArrayConversion::Lossless),
+            Some(vec![-5])
+        );
+    }
+}
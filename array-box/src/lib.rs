@@ -1,3 +1,9 @@
 mod array_box;
+mod conversion;
+mod numeric;
+mod parallel;
 
 pub use crate::array_box::*;
+pub use crate::conversion::*;
+pub use crate::numeric::*;
+pub use crate::parallel::*;
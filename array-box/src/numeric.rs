@@ -0,0 +1,337 @@
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+
+use crate::{for_each_chunk_mut, for_each_chunk_zip, ArrayBox};
+
+/// The min of [`ArrayBox::clamp`] is greater than its max or one of them is NaN
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct InvalidBounds;
+
+impl Display for InvalidBounds {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "The min must not be greater than max")
+    }
+}
+
+impl std::error::Error for InvalidBounds {}
+
+/// A number that can be stored in an [`ArrayBox`] and processed by the numeric kernels.
+/// Integer arithmetic saturates at the bounds of the type instead of wrapping around,
+/// which is what image processing expects from adding or scaling pixel values.
+/// Sums are accumulated in `i128` or `u128` for integers, so that even 64-bit items are summed exactly.
+pub trait ArrayNumber: Copy + PartialOrd + Send + Sync {
+    /// The type that the sums of items are accumulated in
+    type Sum: Copy + Default;
+
+    fn saturating_add(self, other: Self) -> Self;
+    fn saturating_sub(self, other: Self) -> Self;
+    fn saturating_mul(self, other: Self) -> Self;
+    fn minimum(self, other: Self) -> Self;
+    fn maximum(self, other: Self) -> Self;
+    fn to_f64(self) -> f64;
+    fn to_sum(self) -> Self::Sum;
+    fn product_to_sum(self, other: Self) -> Self::Sum;
+    fn add_sums(sum: Self::Sum, other: Self::Sum) -> Self::Sum;
+    fn sum_to_f64(sum: Self::Sum) -> f64;
+}
+
+macro_rules! integer_number {
+    ($($ty:ty => $sum:ty),*) => {
+        $(
+            impl ArrayNumber for $ty {
+                type Sum = $sum;
+
+                #[inline]
+                fn saturating_add(self, other: Self) -> Self {
+                    <$ty>::saturating_add(self, other)
+                }
+                #[inline]
+                fn saturating_sub(self, other: Self) -> Self {
+                    <$ty>::saturating_sub(self, other)
+                }
+                #[inline]
+                fn saturating_mul(self, other: Self) -> Self {
+                    <$ty>::saturating_mul(self, other)
+                }
+                #[inline]
+                fn minimum(self, other: Self) -> Self {
+                    Ord::min(self, other)
+                }
+                #[inline]
+                fn maximum(self, other: Self) -> Self {
+                    Ord::max(self, other)
+                }
+                #[inline]
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+                #[inline]
+                fn to_sum(self) -> $sum {
+                    self as $sum
+                }
+                #[inline]
+                fn product_to_sum(self, other: Self) -> $sum {
+                    // the product of two 64-bit integers always fits
+                    (self as $sum) * (other as $sum)
+                }
+                #[inline]
+                fn add_sums(sum: $sum, other: $sum) -> $sum {
+                    sum.saturating_add(other)
+                }
+                #[inline]
+                fn sum_to_f64(sum: $sum) -> f64 {
+                    sum as f64
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! float_number {
+    ($($ty:ty),*) => {
+        $(
+            impl ArrayNumber for $ty {
+                type Sum = f64;
+
+                #[inline]
+                fn saturating_add(self, other: Self) -> Self {
+                    self + other
+                }
+                #[inline]
+                fn saturating_sub(self, other: Self) -> Self {
+                    self - other
+                }
+                #[inline]
+                fn saturating_mul(self, other: Self) -> Self {
+                    self * other
+                }
+                #[inline]
+                fn minimum(self, other: Self) -> Self {
+                    <$ty>::min(self, other)
+                }
+                #[inline]
+                fn maximum(self, other: Self) -> Self {
+                    <$ty>::max(self, other)
+                }
+                #[inline]
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+                #[inline]
+                fn to_sum(self) -> f64 {
+                    self as f64
+                }
+                #[inline]
+                fn product_to_sum(self, other: Self) -> f64 {
+                    self as f64 * other as f64
+                }
+                #[inline]
+                fn add_sums(sum: f64, other: f64) -> f64 {
+                    sum + other
+                }
+                #[inline]
+                fn sum_to_f64(sum: f64) -> f64 {
+                    sum
+                }
+            }
+        )*
+    };
+}
+
+integer_number!(
+    u8 => u128,
+    i8 => i128,
+    u16 => u128,
+    i16 => i128,
+    u32 => u128,
+    i32 => i128,
+    u64 => u128,
+    i64 => i128,
+    usize => u128,
+    isize => i128
+);
+float_number!(f32, f64);
+
+/// The amount of independent partial sums of the reductions,
+/// it lets the compiler vectorize the loops over floating point numbers.
+const LANES: usize = 8;
+
+/// Element-wise operations mutate me in place and panic if the other array has a different length,
+/// large arrays are split between the shared workers when the `parallel` feature is enabled.
+/// Reductions are accumulated as [`ArrayNumber::Sum`] and returned as `f64`.
+impl<T> ArrayBox<T>
+where
+    T: ArrayNumber,
+{
+    pub fn add(&mut self, another_array: &ArrayBox<T>) {
+        self.zip_with(another_array, T::saturating_add);
+    }
+
+    pub fn sub(&mut self, another_array: &ArrayBox<T>) {
+        self.zip_with(another_array, T::saturating_sub);
+    }
+
+    pub fn mul(&mut self, another_array: &ArrayBox<T>) {
+        self.zip_with(another_array, T::saturating_mul);
+    }
+
+    pub fn add_scalar(&mut self, scalar: T) {
+        self.map(|item| item.saturating_add(scalar));
+    }
+
+    pub fn sub_scalar(&mut self, scalar: T) {
+        self.map(|item| item.saturating_sub(scalar));
+    }
+
+    pub fn scale(&mut self, factor: T) {
+        self.map(|item| item.saturating_mul(factor));
+    }
+
+    /// Limit all items to the range between min and max.
+    /// Fails if min is greater than max or one of them is NaN
+    pub fn clamp(&mut self, min: T, max: T) -> Result<(), InvalidBounds> {
+        if !matches!(
+            min.partial_cmp(&max),
+            Some(Ordering::Less | Ordering::Equal)
+        ) {
+            return Err(InvalidBounds);
+        }
+        self.map(|item| item.maximum(min).minimum(max));
+        Ok(())
+    }
+
+    pub fn sum(&self) -> f64 {
+        T::sum_to_f64(reduce::<T>(
+            self.to_slice().iter().map(|item| item.to_sum()),
+        ))
+    }
+
+    /// Return the mean of all items or None if I am empty
+    pub fn mean(&self) -> Option<f64> {
+        if self.length == 0 {
+            None
+        } else {
+            Some(self.sum() / self.length as f64)
+        }
+    }
+
+    /// Return the smallest item or None if I am empty
+    pub fn min(&self) -> Option<T> {
+        self.to_slice().iter().copied().reduce(T::minimum)
+    }
+
+    /// Return the largest item or None if I am empty
+    pub fn max(&self) -> Option<T> {
+        self.to_slice().iter().copied().reduce(T::maximum)
+    }
+
+    /// Return the sum of the products of the items of both arrays.
+    /// Panics if the other array has a different length
+    pub fn dot(&self, another_array: &ArrayBox<T>) -> f64 {
+        assert_eq!(
+            self.length, another_array.length,
+            "Arrays must have the same length"
+        );
+        T::sum_to_f64(reduce::<T>(
+            self.to_slice()
+                .iter()
+                .zip(another_array.to_slice())
+                .map(|(left, right)| left.product_to_sum(*right)),
+        ))
+    }
+
+    fn map(&mut self, op: impl Fn(T) -> T + Send + Sync) {
+        for_each_chunk_mut(self.to_slice(), 1, |items| {
+            for item in items {
+                *item = op(*item);
+            }
+        });
+    }
+
+    fn zip_with(&mut self, another_array: &ArrayBox<T>, op: impl Fn(T, T) -> T + Send + Sync) {
+        assert_eq!(
+            self.length, another_array.length,
+            "Arrays must have the same length"
+        );
+        for_each_chunk_zip(
+            another_array.to_slice(),
+            1,
+            self.to_slice(),
+            1,
+            |others, items| {
+                for (item, other) in items.iter_mut().zip(others) {
+                    *item = op(*item, *other);
+                }
+            },
+        )
+        .expect("Arrays must have the same length");
+    }
+}
+
+/// Add up the terms in `LANES` partial sums.
+fn reduce<T: ArrayNumber>(terms: impl Iterator<Item = T::Sum>) -> T::Sum {
+    let mut sums = [T::Sum::default(); LANES];
+    for (index, term) in terms.enumerate() {
+        let lane = &mut sums[index % LANES];
+        *lane = T::add_sums(*lane, term);
+    }
+    sums.into_iter().fold(T::Sum::default(), T::add_sums)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn element_wise() {
+        let mut array = ArrayBox::<u8>::from_vector(vec![10, 200, 30]);
+        array.add(&ArrayBox::from_vector(vec![1, 100, 3]));
+        assert_eq!(array.to_slice(), &[11, 255, 33]);
+
+        array.sub_scalar(20);
+        assert_eq!(array.to_slice(), &[0, 235, 13]);
+
+        array.scale(2);
+        array.clamp(5, 100).unwrap();
+        assert_eq!(array.to_slice(), &[5, 100, 26]);
+        assert_eq!(array.clamp(100, 5), Err(InvalidBounds));
+        assert_eq!(
+            ArrayBox::<f32>::new().clamp(f32::NAN, 1.0),
+            Err(InvalidBounds)
+        );
+
+        // large enough to be split between the workers
+        let mut large =
+            ArrayBox::<u16>::from_vector((0..100_000).map(|item| item as u16).collect());
+        large.mul(&ArrayBox::from_vector(vec![2; 100_000]));
+        large.clamp(0, 1000).unwrap();
+        assert!(large
+            .to_slice()
+            .iter()
+            .enumerate()
+            .all(|(index, item)| *item == (index as u16).saturating_mul(2).min(1000)));
+    }
+
+    #[test]
+    fn reductions() {
+        let array = ArrayBox::<f32>::from_vector((1..=10).map(|item| item as f32).collect());
+        assert_eq!(array.sum(), 55.0);
+        assert_eq!(array.mean(), Some(5.5));
+        assert_eq!(array.min(), Some(1.0));
+        assert_eq!(array.max(), Some(10.0));
+        assert_eq!(array.dot(&array), 385.0);
+
+        let large = ArrayBox::<u64>::from_vector(vec![u64::MAX, 1, u64::MAX]);
+        assert_eq!(large.sum(), (2 * u64::MAX as u128 + 1) as f64);
+        let large = ArrayBox::<i64>::from_vector(vec![i64::MAX, 1, -i64::MAX]);
+        assert_eq!(large.sum(), 1.0);
+        assert_eq!(
+            large.dot(&large),
+            (2 * (i64::MAX as i128).pow(2) + 1) as f64
+        );
+
+        let empty = ArrayBox::<i32>::new();
+        assert_eq!(empty.mean(), None);
+        assert_eq!(empty.min(), None);
+    }
+}
@@ -0,0 +1,204 @@
+use std::fmt::{Display, Formatter};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::OnceLock;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+#[cfg(feature = "parallel")]
+use rayon::{ThreadPool, ThreadPoolBuilder};
+#[cfg(feature = "parallel")]
+use std::sync::{Arc, RwLock};
+
+/// The amount of worker threads, 0 stands for the amount of available cores
+static PARALLELISM: AtomicUsize = AtomicUsize::new(0);
+/// The amount of available cores is only asked once, it is a system call on every platform
+static AVAILABLE_PARALLELISM: OnceLock<usize> = OnceLock::new();
+/// Arrays with fewer items are processed on the calling thread
+static PARALLEL_THRESHOLD: AtomicUsize = AtomicUsize::new(DEFAULT_PARALLEL_THRESHOLD);
+/// The workers are only created by the first bulk operation that needs them
+#[cfg(feature = "parallel")]
+static POOL: RwLock<Option<Arc<ThreadPool>>> = RwLock::new(None);
+
+/// 4096 pixels of 4 bytes, below that the work is cheaper than waking up the workers
+pub const DEFAULT_PARALLEL_THRESHOLD: usize = 16 * 1024;
+
+/// The source and the destination of [`for_each_chunk_zip`] consist of different amounts of units
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct LengthMismatch {
+    /// The amount of units of the destination
+    pub expected: usize,
+    /// The amount of units of the source
+    pub actual: usize,
+}
+
+impl Display for LengthMismatch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Expected {} units, but got {}",
+            self.expected, self.actual
+        )
+    }
+}
+
+impl std::error::Error for LengthMismatch {}
+
+/// Return the amount of worker threads that bulk array operations are split between
+pub fn parallelism() -> usize {
+    match PARALLELISM.load(Ordering::Relaxed) {
+        0 => *AVAILABLE_PARALLELISM.get_or_init(|| {
+            std::thread::available_parallelism()
+                .map(|threads| threads.get())
+                .unwrap_or(1)
+        }),
+        threads => threads,
+    }
+}
+
+/// Set the amount of worker threads, 0 resets it to the amount of available cores
+/// and 1 processes all arrays on the calling thread.
+/// The current workers finish their work and are replaced by a new pool when needed.
+pub fn set_parallelism(threads: usize) {
+    #[cfg(feature = "parallel")]
+    let mut pool = POOL.write().unwrap_or_else(|error| error.into_inner());
+    PARALLELISM.store(threads, Ordering::Relaxed);
+    #[cfg(feature = "parallel")]
+    pool.take();
+}
+
+/// Return the amount of array items from which bulk operations are split between the workers
+pub fn parallel_threshold() -> usize {
+    PARALLEL_THRESHOLD.load(Ordering::Relaxed)
+}
+
+pub fn set_parallel_threshold(items: usize) {
+    PARALLEL_THRESHOLD.store(items, Ordering::Relaxed);
+}
+
+#[cfg(feature = "parallel")]
+fn pool() -> Option<Arc<ThreadPool>> {
+    if let Some(pool) = POOL
+        .read()
+        .unwrap_or_else(|error| error.into_inner())
+        .as_ref()
+    {
+        return Some(pool.clone());
+    }
+
+    let mut pool = POOL.write().unwrap_or_else(|error| error.into_inner());
+    if pool.is_none() {
+        // without a pool the work is done on the calling thread
+        *pool = ThreadPoolBuilder::new()
+            .num_threads(parallelism())
+            .thread_name(|index| format!("boxer-worker-{}", index))
+            .build()
+            .ok()
+            .map(Arc::new);
+    }
+    pool.clone()
+}
+
+/// Return the workers and the length of the chunks in units or None if a given amount of items
+/// should be processed on the calling thread
+#[cfg(feature = "parallel")]
+fn chunk_units(items: usize, units: usize) -> Option<(Arc<ThreadPool>, usize)> {
+    let threads = parallelism();
+    if threads < 2 || units < 2 || items < parallel_threshold() {
+        return None;
+    }
+    pool().map(|pool| (pool, units.div_ceil(threads)))
+}
+
+/// Apply an operation to the chunks of items on the workers of a shared pool.
+/// Chunks are a multiple of a given unit long, for example the bytes of a pixel,
+/// only the last chunk may be shorter.
+/// Without the `parallel` feature all items are processed on the calling thread.
+#[cfg_attr(not(feature = "parallel"), allow(unused_variables))]
+pub fn for_each_chunk_mut<T: Send>(
+    items: &mut [T],
+    unit: usize,
+    op: impl Fn(&mut [T]) + Send + Sync,
+) {
+    #[cfg(feature = "parallel")]
+    {
+        let unit = unit.max(1);
+        if let Some((pool, units)) = chunk_units(items.len(), items.len().div_ceil(unit)) {
+            pool.install(|| items.par_chunks_mut(units * unit).for_each(&op));
+            return;
+        }
+    }
+
+    op(items)
+}
+
+/// Apply an operation to the corresponding chunks of a source and a destination
+/// on the workers of a shared pool, both are split into the same amount of units.
+/// Fails if the source and the destination consist of different amounts of units,
+/// the last unit of both may be shorter.
+pub fn for_each_chunk_zip<S: Sync, T: Send>(
+    source: &[S],
+    source_unit: usize,
+    destination: &mut [T],
+    destination_unit: usize,
+    op: impl Fn(&[S], &mut [T]) + Send + Sync,
+) -> Result<(), LengthMismatch> {
+    let (source_unit, destination_unit) = (source_unit.max(1), destination_unit.max(1));
+    let units = destination.len().div_ceil(destination_unit);
+    let source_units = source.len().div_ceil(source_unit);
+    if source_units != units {
+        return Err(LengthMismatch {
+            expected: units,
+            actual: source_units,
+        });
+    }
+
+    #[cfg(feature = "parallel")]
+    if let Some((pool, units)) = chunk_units(destination.len(), units) {
+        pool.install(|| {
+            source
+                .par_chunks(units * source_unit)
+                .zip(destination.par_chunks_mut(units * destination_unit))
+                .for_each(|(source, destination)| op(source, destination))
+        });
+        return Ok(());
+    }
+
+    op(source, destination);
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn chunks() {
+        let mut items = vec![0u32; 100_000];
+        for_each_chunk_mut(&mut items, 3, |chunk| {
+            for (index, item) in chunk.iter_mut().enumerate() {
+                *item = (index % 3) as u32;
+            }
+        });
+        assert!(items
+            .iter()
+            .enumerate()
+            .all(|(index, item)| *item == (index % 3) as u32));
+
+        let mut destination = vec![0u8; 50_000];
+        let copy_pixels = |source: &[u32], destination: &mut [u8]| {
+            for (source, destination) in source.chunks(3).zip(destination.chunks_mut(2)) {
+                destination.copy_from_slice(&[source[1] as u8, source[2] as u8]);
+            }
+        };
+        for_each_chunk_zip(&items[..75_000], 3, &mut destination, 2, copy_pixels).unwrap();
+        assert!(destination.chunks(2).all(|pixel| pixel == [1, 2]));
+
+        assert_eq!(
+            for_each_chunk_zip(&items[..74_997], 3, &mut destination, 2, copy_pixels),
+            Err(LengthMismatch {
+                expected: 25_000,
+                actual: 24_999
+            })
+        );
+    }
+}
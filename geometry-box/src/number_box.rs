@@ -1,5 +1,6 @@
 #[derive(Debug, Copy, Clone, Default)]
 #[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct U128Box {
     pub high: u64,
     pub low: u64,
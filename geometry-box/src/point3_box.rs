@@ -1,5 +1,6 @@
 #[derive(Debug, Copy, Clone, Default)]
 #[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Point3Box<T>
 where
     T: From<u8> + Default + Copy,
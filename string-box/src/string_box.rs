@@ -190,6 +190,21 @@ impl StringBox {
     }
 }
 
+/// A string box is serialized as its UTF-8 string, the origin of the string is not preserved.
+#[cfg(feature = "serde")]
+impl serde::Serialize for StringBox {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for StringBox {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(Self::from_string)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
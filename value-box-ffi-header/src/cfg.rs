@@ -0,0 +1,217 @@
+use std::collections::BTreeSet;
+
+use syn::punctuated::Punctuated;
+use syn::{Attribute, Expr, ExprLit, Lit, Meta, Token};
+
+use crate::{HeaderError, Result};
+
+/// A `#[cfg(...)]` condition under which an item is compiled.
+/// Only cargo features are kept, `test` is always disabled.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Cfg {
+    True,
+    False,
+    Feature(String),
+    Not(Box<Cfg>),
+    All(Vec<Cfg>),
+    Any(Vec<Cfg>),
+}
+
+impl Cfg {
+    /// The conjunction of all `#[cfg(...)]` attributes.
+    pub fn from_attributes(attributes: &[Attribute]) -> Result<Self> {
+        let mut conditions = vec![];
+        for attribute in attributes {
+            if attribute.path().is_ident("cfg") {
+                let meta = attribute
+                    .parse_args::<Meta>()
+                    .map_err(|error| HeaderError::UnsupportedCfg(error.to_string()))?;
+                conditions.push(Self::from_meta(&meta)?);
+            }
+        }
+        Ok(Self::All(conditions).simplify())
+    }
+
+    fn from_meta(meta: &Meta) -> Result<Self> {
+        match meta {
+            Meta::Path(path) if path.is_ident("test") => Ok(Self::False),
+            Meta::NameValue(name_value) if name_value.path.is_ident("feature") => {
+                match &name_value.value {
+                    Expr::Lit(ExprLit {
+                        lit: Lit::Str(feature),
+                        ..
+                    }) => Ok(Self::Feature(feature.value())),
+                    _ => Err(Self::unsupported(meta)),
+                }
+            }
+            Meta::List(list) => {
+                let nested = list
+                    .parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)
+                    .map_err(|_| Self::unsupported(meta))?
+                    .iter()
+                    .map(Self::from_meta)
+                    .collect::<Result<Vec<_>>>()?;
+
+                if list.path.is_ident("all") {
+                    Ok(Self::All(nested))
+                } else if list.path.is_ident("any") {
+                    Ok(Self::Any(nested))
+                } else if list.path.is_ident("not") && nested.len() == 1 {
+                    Ok(Self::Not(Box::new(nested.into_iter().next().unwrap())))
+                } else {
+                    Err(Self::unsupported(meta))
+                }
+            }
+            _ => Err(Self::unsupported(meta)),
+        }
+    }
+
+    fn unsupported(meta: &Meta) -> HeaderError {
+        HeaderError::UnsupportedCfg(
+            meta.path()
+                .get_ident()
+                .map(|ident| ident.to_string())
+                .unwrap_or_default(),
+        )
+    }
+
+    pub fn and(self, other: Cfg) -> Self {
+        Self::All(vec![self, other]).simplify()
+    }
+
+    /// Replace features with their known state, when the enabled features are given.
+    pub fn resolve(&self, features: Option<&BTreeSet<String>>) -> Self {
+        let Some(features) = features else {
+            return self.clone();
+        };
+        match self {
+            Self::Feature(feature) if features.contains(feature) => Self::True,
+            Self::Feature(_) => Self::False,
+            Self::Not(cfg) => Self::Not(Box::new(cfg.resolve(Some(features)))),
+            Self::All(cfgs) => {
+                Self::All(cfgs.iter().map(|cfg| cfg.resolve(Some(features))).collect())
+            }
+            Self::Any(cfgs) => {
+                Self::Any(cfgs.iter().map(|cfg| cfg.resolve(Some(features))).collect())
+            }
+            _ => self.clone(),
+        }
+        .simplify()
+    }
+
+    pub fn simplify(self) -> Self {
+        match self {
+            Self::Not(cfg) => match cfg.simplify() {
+                Self::True => Self::False,
+                Self::False => Self::True,
+                Self::Not(cfg) => *cfg,
+                cfg => Self::Not(Box::new(cfg)),
+            },
+            Self::All(cfgs) => {
+                let mut simplified = vec![];
+                for cfg in cfgs.into_iter().map(Self::simplify) {
+                    match cfg {
+                        Self::True => {}
+                        Self::False => return Self::False,
+                        Self::All(nested) => simplified.extend(nested),
+                        cfg => simplified.push(cfg),
+                    }
+                }
+                simplified.dedup();
+                match simplified.len() {
+                    0 => Self::True,
+                    1 => simplified.pop().unwrap(),
+                    _ => Self::All(simplified),
+                }
+            }
+            Self::Any(cfgs) => {
+                let mut simplified = vec![];
+                for cfg in cfgs.into_iter().map(Self::simplify) {
+                    match cfg {
+                        Self::True => return Self::True,
+                        Self::False => {}
+                        Self::Any(nested) => simplified.extend(nested),
+                        cfg => simplified.push(cfg),
+                    }
+                }
+                simplified.dedup();
+                match simplified.len() {
+                    0 => Self::False,
+                    1 => simplified.pop().unwrap(),
+                    _ => Self::Any(simplified),
+                }
+            }
+            cfg => cfg,
+        }
+    }
+
+    /// The condition of a preprocessor `#if`, each feature is expected to be `#define`d
+    /// as `VALUE_BOX_FFI_FEATURE_<NAME>`.
+    pub fn to_c_condition(&self) -> String {
+        match self {
+            Self::True => "1".to_string(),
+            Self::False => "0".to_string(),
+            Self::Feature(feature) => format!("defined({})", feature_define(feature)),
+            Self::Not(cfg) => format!("!{}", cfg.to_nested_c_condition()),
+            Self::All(cfgs) => Self::join_c_conditions(cfgs, " && "),
+            Self::Any(cfgs) => Self::join_c_conditions(cfgs, " || "),
+        }
+    }
+
+    fn to_nested_c_condition(&self) -> String {
+        match self {
+            Self::All(_) | Self::Any(_) => format!("({})", self.to_c_condition()),
+            _ => self.to_c_condition(),
+        }
+    }
+
+    fn join_c_conditions(cfgs: &[Cfg], separator: &str) -> String {
+        cfgs.iter()
+            .map(Self::to_nested_c_condition)
+            .collect::<Vec<_>>()
+            .join(separator)
+    }
+}
+
+/// The name of the preprocessor define that enables a given cargo feature.
+pub fn feature_define(feature: &str) -> String {
+    format!(
+        "VALUE_BOX_FFI_FEATURE_{}",
+        feature.to_uppercase().replace('-', "_")
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn feature_conditions() -> Result<()> {
+        let item: syn::ItemFn = parse_quote! {
+            #[cfg(feature = "array-box")]
+            #[cfg(not(feature = "phlow"))]
+            fn function() {}
+        };
+        let cfg = Cfg::from_attributes(&item.attrs)?;
+        assert_eq!(
+            cfg.to_c_condition(),
+            "defined(VALUE_BOX_FFI_FEATURE_ARRAY_BOX) && !defined(VALUE_BOX_FFI_FEATURE_PHLOW)"
+        );
+
+        let features = BTreeSet::from(["array-box".to_string()]);
+        assert_eq!(cfg.resolve(Some(&features)), Cfg::True);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_disabled() -> Result<()> {
+        let item: syn::ItemMod = parse_quote! {
+            #[cfg(test)]
+            mod test {}
+        };
+        assert_eq!(Cfg::from_attributes(&item.attrs)?, Cfg::False);
+        Ok(())
+    }
+}
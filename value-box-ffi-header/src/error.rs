@@ -0,0 +1,20 @@
+use std::path::PathBuf;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum HeaderError {
+    #[error("Could not read {0}")]
+    IOError(PathBuf, #[source] std::io::Error),
+    #[error("Could not parse {0}")]
+    ParseError(PathBuf, #[source] syn::Error),
+    #[error("Could not find module {module} in {directory}")]
+    ModuleNotFound { module: String, directory: PathBuf },
+    #[error("Unsupported cfg attribute: {0}")]
+    UnsupportedCfg(String),
+    #[error("Could not expand macro {name}: {reason}")]
+    MacroError { name: String, reason: String },
+    #[error("Type `{ty}` used by {function} can not be represented in C")]
+    UnsupportedType { function: String, ty: String },
+}
+
+pub type Result<T> = core::result::Result<T, HeaderError>;
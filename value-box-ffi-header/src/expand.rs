@@ -0,0 +1,293 @@
+use std::collections::HashMap;
+
+use proc_macro2::{Delimiter, Group, Ident, Spacing, TokenStream, TokenTree};
+
+use crate::{HeaderError, Result};
+
+/// A minimal `macro_rules!` expander that is able to expand
+/// macros generating ffi functions, such as `array_ffi!`.
+/// Only rules without repetitions are supported.
+#[derive(Debug, Clone)]
+pub struct MacroRules {
+    name: String,
+    rules: Vec<MacroRule>,
+}
+
+#[derive(Debug, Clone)]
+struct MacroRule {
+    matcher: Vec<Matcher>,
+    transcriber: TokenStream,
+}
+
+#[derive(Debug, Clone)]
+enum Matcher {
+    Token(String),
+    Variable { name: String, fragment: String },
+}
+
+impl MacroRules {
+    pub fn parse(name: &str, body: TokenStream) -> Result<Self> {
+        let mut rules = vec![];
+        let mut tokens = body.into_iter().peekable();
+
+        while let Some(token) = tokens.next() {
+            let matcher = match token {
+                TokenTree::Group(group) => group.stream(),
+                _ => return Err(Self::error(name, "expected a matcher")),
+            };
+            Self::expect_punct(name, tokens.next(), '=')?;
+            Self::expect_punct(name, tokens.next(), '>')?;
+            let transcriber = match tokens.next() {
+                Some(TokenTree::Group(group)) => group.stream(),
+                _ => return Err(Self::error(name, "expected a transcriber")),
+            };
+            if matches!(tokens.peek(), Some(TokenTree::Punct(punct)) if punct.as_char() == ';') {
+                tokens.next();
+            }
+
+            // rules with repetitions or nested groups are never used to expand ffi functions
+            if let Some(matcher) = Self::parse_matcher(matcher) {
+                rules.push(MacroRule {
+                    matcher,
+                    transcriber,
+                });
+            }
+        }
+
+        Ok(Self {
+            name: name.to_string(),
+            rules,
+        })
+    }
+
+    fn parse_matcher(matcher: TokenStream) -> Option<Vec<Matcher>> {
+        let mut parsed = vec![];
+        let mut tokens = matcher.into_iter();
+
+        while let Some(token) = tokens.next() {
+            match token {
+                TokenTree::Punct(punct) if punct.as_char() == '$' => {
+                    let name = match tokens.next()? {
+                        TokenTree::Ident(ident) => ident.to_string(),
+                        _ => return None,
+                    };
+                    match tokens.next()? {
+                        TokenTree::Punct(punct) if punct.as_char() == ':' => {}
+                        _ => return None,
+                    }
+                    let fragment = match tokens.next()? {
+                        TokenTree::Ident(ident) => ident.to_string(),
+                        _ => return None,
+                    };
+                    parsed.push(Matcher::Variable { name, fragment });
+                }
+                TokenTree::Group(_) => return None,
+                token => parsed.push(Matcher::Token(token.to_string())),
+            }
+        }
+        Some(parsed)
+    }
+
+    /// Macros without any supported rule can not be expanded.
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    pub fn expand(&self, input: TokenStream) -> Result<TokenStream> {
+        let input = input.into_iter().collect::<Vec<_>>();
+        self.rules
+            .iter()
+            .find_map(|rule| rule.bind(&input))
+            .map(|(rule, bindings)| transcribe(rule.transcriber.clone(), &bindings))
+            .ok_or_else(|| Self::error(&self.name, "no rule matches the invocation"))
+    }
+
+    fn expect_punct(name: &str, token: Option<TokenTree>, expected: char) -> Result<()> {
+        match token {
+            Some(TokenTree::Punct(punct)) if punct.as_char() == expected => Ok(()),
+            _ => Err(Self::error(name, &format!("expected `{}`", expected))),
+        }
+    }
+
+    fn error(name: &str, reason: &str) -> HeaderError {
+        HeaderError::MacroError {
+            name: name.to_string(),
+            reason: reason.to_string(),
+        }
+    }
+}
+
+impl MacroRule {
+    fn bind(&self, input: &[TokenTree]) -> Option<(&Self, HashMap<String, TokenStream>)> {
+        let mut bindings = HashMap::new();
+        let mut position = 0;
+
+        for (index, matcher) in self.matcher.iter().enumerate() {
+            match matcher {
+                Matcher::Token(token) => {
+                    if input.get(position)?.to_string() != *token {
+                        return None;
+                    }
+                    position += 1;
+                }
+                Matcher::Variable { name, fragment } => {
+                    let end = if fragment == "ident" {
+                        match input.get(position)? {
+                            TokenTree::Ident(_) => position + 1,
+                            _ => return None,
+                        }
+                    } else {
+                        // take everything up to the next literal token of the matcher
+                        match self.matcher.get(index + 1) {
+                            Some(Matcher::Token(next)) => {
+                                position
+                                    + input[position..]
+                                        .iter()
+                                        .position(|token| token.to_string() == *next)?
+                            }
+                            Some(Matcher::Variable { .. }) => return None,
+                            None => input.len(),
+                        }
+                    };
+                    if end <= position {
+                        return None;
+                    }
+                    bindings.insert(name.clone(), input[position..end].iter().cloned().collect());
+                    position = end;
+                }
+            }
+        }
+
+        if position == input.len() {
+            Some((self, bindings))
+        } else {
+            None
+        }
+    }
+}
+
+fn transcribe(transcriber: TokenStream, bindings: &HashMap<String, TokenStream>) -> TokenStream {
+    let mut output = TokenStream::new();
+    let mut tokens = transcriber.into_iter().peekable();
+
+    while let Some(token) = tokens.next() {
+        match token {
+            TokenTree::Punct(ref punct) if punct.as_char() == '$' => {
+                let binding = match tokens.peek() {
+                    Some(TokenTree::Ident(ident)) => bindings.get(&ident.to_string()),
+                    _ => None,
+                };
+                let is_crate =
+                    matches!(tokens.peek(), Some(TokenTree::Ident(ident)) if ident == "crate");
+                match binding {
+                    Some(binding) => {
+                        output.extend(binding.clone());
+                        tokens.next();
+                    }
+                    // `$crate` refers to the crate being crawled
+                    None if is_crate => {}
+                    None => output.extend([token]),
+                }
+            }
+            TokenTree::Group(group) => {
+                let mut transcribed =
+                    Group::new(group.delimiter(), transcribe(group.stream(), bindings));
+                transcribed.set_span(group.span());
+                output.extend([TokenTree::Group(transcribed)]);
+            }
+            token => output.extend([token]),
+        }
+    }
+    output
+}
+
+/// Expand the body of a `paste::paste!` invocation by concatenating
+/// the identifiers within `[< ... >]` into a single identifier.
+pub fn paste(tokens: TokenStream) -> TokenStream {
+    tokens
+        .into_iter()
+        .map(|token| match token {
+            TokenTree::Group(group) => {
+                if group.delimiter() == Delimiter::Bracket {
+                    if let Some(ident) = paste_ident(&group) {
+                        return TokenTree::Ident(ident);
+                    }
+                }
+                let mut pasted = Group::new(group.delimiter(), paste(group.stream()));
+                pasted.set_span(group.span());
+                TokenTree::Group(pasted)
+            }
+            token => token,
+        })
+        .collect()
+}
+
+fn paste_ident(group: &Group) -> Option<Ident> {
+    let tokens = group.stream().into_iter().collect::<Vec<_>>();
+    let (first, rest) = tokens.split_first()?;
+    let (last, segments) = rest.split_last()?;
+
+    let is_punct = |token: &TokenTree, expected: char| matches!(token, TokenTree::Punct(punct) if punct.as_char() == expected && punct.spacing() == Spacing::Alone);
+    if !is_punct(first, '<') || !is_punct(last, '>') {
+        return None;
+    }
+
+    let mut ident = String::new();
+    for segment in segments {
+        match segment {
+            TokenTree::Ident(segment) => {
+                let segment = segment.to_string();
+                ident.push_str(segment.strip_prefix("r#").unwrap_or(&segment));
+            }
+            TokenTree::Literal(segment) => ident.push_str(&segment.to_string()),
+            TokenTree::Group(segment) if segment.delimiter() == Delimiter::None => {
+                for token in segment.stream() {
+                    ident.push_str(&token.to_string());
+                }
+            }
+            _ => return None,
+        }
+    }
+    Some(Ident::new(&ident, group.span()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::str::FromStr;
+
+    fn tokens(source: &str) -> TokenStream {
+        TokenStream::from_str(source).unwrap()
+    }
+
+    fn normalize(tokens: TokenStream) -> String {
+        tokens.to_string().replace(' ', "")
+    }
+
+    #[test]
+    fn expand_and_paste() -> Result<()> {
+        let rules = MacroRules::parse(
+            "getter",
+            tokens(
+                "($ty:ident) => { getter!($ty, $ty); };
+                 ($ty:path, $name:ident) => { paste::paste! { fn [<get_ $name>]() -> $crate::Box<$ty> {} } };",
+            ),
+        )?;
+
+        let first = rules.expand(tokens("u8"))?;
+        assert_eq!(normalize(first), normalize(tokens("getter!(u8, u8);")));
+
+        let second = rules.expand(tokens("std::ffi::c_int, int"))?;
+        let pasted = match second.into_iter().last() {
+            Some(TokenTree::Group(group)) => paste(group.stream()),
+            _ => unreachable!(),
+        };
+        assert_eq!(
+            normalize(pasted),
+            normalize(tokens("fn get_int() -> crate::Box<std::ffi::c_int> {}"))
+        );
+
+        assert!(rules.expand(tokens("u8, u8, u8")).is_err());
+        Ok(())
+    }
+}
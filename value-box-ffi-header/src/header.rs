@@ -0,0 +1,216 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+
+use crate::cfg::Cfg;
+use crate::source::FfiSource;
+use crate::types::TypeMapper;
+use crate::Result;
+
+const HEADER_GUARD: &str = "VALUE_BOX_FFI_H";
+
+struct CFunction {
+    cfg: Cfg,
+    docs: Vec<String>,
+    declaration: String,
+}
+
+/// Render the C header of all functions in the source
+/// that are enabled by the given features, or of all functions guarded by
+/// `#if defined(VALUE_BOX_FFI_FEATURE_<NAME>)` when the features are not known.
+pub fn render_header(source: &FfiSource, features: Option<&BTreeSet<String>>) -> Result<String> {
+    let mut types = TypeMapper::new(source);
+    let mut functions = vec![];
+
+    for function in &source.functions {
+        let cfg = function.cfg.resolve(features);
+        if cfg == Cfg::False {
+            continue;
+        }
+
+        let arguments = function
+            .arguments
+            .iter()
+            .map(|(name, ty)| Ok(declarator(&types.c_type(ty, &function.name)?, name)))
+            .collect::<Result<Vec<_>>>()?;
+        let arguments = if arguments.is_empty() {
+            "void".to_string()
+        } else {
+            arguments.join(", ")
+        };
+        let output = match &function.output {
+            Some(ty) => types.c_type(ty, &function.name)?,
+            None => "void".to_string(),
+        };
+
+        functions.push(CFunction {
+            cfg,
+            docs: function.docs.clone(),
+            declaration: format!("{}({});", declarator(&output, &function.name), arguments),
+        });
+    }
+
+    // the constants of the enums passed as their representation are needed to call the functions
+    for name in &source.convertible_enums {
+        if source.enums.contains_key(name) {
+            types.enums.insert(name.clone());
+        }
+    }
+
+    let mut header = String::new();
+    writeln!(
+        header,
+        "/* Generated by value-box-ffi-header, do not edit manually. */"
+    )
+    .unwrap();
+    writeln!(header).unwrap();
+    writeln!(header, "#ifndef {}", HEADER_GUARD).unwrap();
+    writeln!(header, "#define {}", HEADER_GUARD).unwrap();
+    writeln!(header).unwrap();
+    writeln!(header, "#include <stdbool.h>").unwrap();
+    writeln!(header, "#include <stddef.h>").unwrap();
+    writeln!(header, "#include <stdint.h>").unwrap();
+
+    if features.is_none() {
+        writeln!(header).unwrap();
+        writeln!(
+            header,
+            "/* Define VALUE_BOX_FFI_FEATURE_<NAME> for every cargo feature the library is built with, */"
+        )
+        .unwrap();
+        writeln!(
+            header,
+            "/* for example VALUE_BOX_FFI_FEATURE_ARRAY_BOX for the `array-box` feature. */"
+        )
+        .unwrap();
+    }
+
+    for name in &types.enums {
+        let ffi_enum = &source.enums[name];
+        writeln!(header).unwrap();
+        writeln!(header, "enum {{").unwrap();
+        for (variant, value) in &ffi_enum.variants {
+            writeln!(header, "    {}_{} = {},", name, variant, value).unwrap();
+        }
+        writeln!(header, "}};").unwrap();
+        writeln!(
+            header,
+            "typedef {} {};",
+            enum_representation(&ffi_enum.representation),
+            name
+        )
+        .unwrap();
+    }
+
+    if !types.newtypes.is_empty() {
+        writeln!(header).unwrap();
+        for (name, c_type) in &types.newtypes {
+            writeln!(header, "typedef {} {};", c_type, name).unwrap();
+        }
+    }
+
+    writeln!(header).unwrap();
+    for name in &types.opaque_types {
+        writeln!(header, "typedef struct {} {};", name, name).unwrap();
+    }
+
+    // function pointers come before the structs that may have them as fields
+    if !types.function_pointers.is_empty() {
+        writeln!(header).unwrap();
+        for typedef in types.function_pointers.values() {
+            writeln!(header, "{}", typedef).unwrap();
+        }
+    }
+
+    let mut written_structs = BTreeSet::new();
+    for name in types.structs.keys() {
+        write_struct(&mut header, name, &types.structs, &mut written_structs);
+    }
+
+    writeln!(header).unwrap();
+    writeln!(header, "#ifdef __cplusplus").unwrap();
+    writeln!(header, "extern \"C\" {{").unwrap();
+    writeln!(header, "#endif").unwrap();
+
+    let mut current_cfg = Cfg::True;
+    for function in functions {
+        if function.cfg != current_cfg {
+            if current_cfg != Cfg::True {
+                writeln!(header).unwrap();
+                writeln!(header, "#endif").unwrap();
+            }
+            if function.cfg != Cfg::True {
+                writeln!(header).unwrap();
+                writeln!(header, "#if {}", function.cfg.to_c_condition()).unwrap();
+            }
+            current_cfg = function.cfg;
+        }
+        writeln!(header).unwrap();
+        for line in &function.docs {
+            writeln!(header, "// {}", line).unwrap();
+        }
+        writeln!(header, "{}", function.declaration).unwrap();
+    }
+    if current_cfg != Cfg::True {
+        writeln!(header).unwrap();
+        writeln!(header, "#endif").unwrap();
+    }
+
+    writeln!(header).unwrap();
+    writeln!(header, "#ifdef __cplusplus").unwrap();
+    writeln!(header, "}}").unwrap();
+    writeln!(header, "#endif").unwrap();
+    writeln!(header).unwrap();
+    writeln!(header, "#endif /* {} */", HEADER_GUARD).unwrap();
+
+    Ok(header)
+}
+
+/// Declare a variable or a function of a given C type, for example `uint8_t *data`.
+/// Write the definition of a struct after the definitions of the structs it has as fields.
+fn write_struct(
+    header: &mut String,
+    name: &str,
+    structs: &BTreeMap<String, Vec<(String, String)>>,
+    written: &mut BTreeSet<String>,
+) {
+    if !written.insert(name.to_string()) {
+        return;
+    }
+    let fields = &structs[name];
+    for (_, ty) in fields {
+        if structs.contains_key(ty) {
+            write_struct(header, ty, structs, written);
+        }
+    }
+
+    writeln!(header).unwrap();
+    writeln!(header, "typedef struct {} {{", name).unwrap();
+    for (field, ty) in fields {
+        writeln!(header, "    {};", declarator(ty, field)).unwrap();
+    }
+    writeln!(header, "}} {};", name).unwrap();
+}
+
+fn declarator(ty: &str, name: &str) -> String {
+    if ty.ends_with('*') {
+        format!("{}{}", ty, name)
+    } else {
+        format!("{} {}", ty, name)
+    }
+}
+
+fn enum_representation(representation: &str) -> &'static str {
+    match representation {
+        "u8" => "uint8_t",
+        "i8" => "int8_t",
+        "u16" => "uint16_t",
+        "i16" => "int16_t",
+        "u32" => "uint32_t",
+        "i32" => "int32_t",
+        "u64" => "uint64_t",
+        "i64" => "int64_t",
+        "usize" => "size_t",
+        "isize" => "intptr_t",
+        _ => "int",
+    }
+}
@@ -0,0 +1,79 @@
+mod cfg;
+mod error;
+mod expand;
+mod header;
+mod source;
+mod types;
+
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+
+pub use cfg::feature_define;
+pub use error::{HeaderError, Result};
+
+use crate::cfg::Cfg;
+use crate::source::FfiSource;
+
+/// The crates whose `#[repr(C)]` types may appear in the signatures of the ffi functions.
+const TYPE_CRATES: [&str; 4] = ["value-box", "array-box", "geometry-box", "string-box"];
+
+/// Generates a C header describing every function exported by `value-box-ffi`
+/// by reading the sources of the workspace, without compiling them.
+#[derive(Debug, Clone)]
+pub struct HeaderGenerator {
+    workspace: PathBuf,
+    features: Option<BTreeSet<String>>,
+}
+
+impl HeaderGenerator {
+    /// Create a generator for the workspace of the boxes crates.
+    /// By default the declarations are guarded by the feature they depend on.
+    pub fn new(workspace: impl Into<PathBuf>) -> Self {
+        Self {
+            workspace: workspace.into(),
+            features: None,
+        }
+    }
+
+    /// Only declare the functions available with the given cargo features,
+    /// without feature guards.
+    pub fn with_features(mut self, features: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.features = Some(features.into_iter().map(Into::into).collect());
+        self
+    }
+
+    pub fn workspace(&self) -> &Path {
+        self.workspace.as_path()
+    }
+
+    pub fn generate(&self) -> Result<String> {
+        header::render_header(&self.source()?, self.features.as_ref())
+    }
+
+    /// Return the names of all functions exported with `#[no_mangle]`, whatever their ABI,
+    /// that are available with the given features or with any features if they are not known.
+    pub fn exported_symbols(&self) -> Result<BTreeSet<String>> {
+        Ok(self
+            .source()?
+            .symbols
+            .into_iter()
+            .filter(|(_, cfg)| cfg.resolve(self.features.as_ref()) != Cfg::False)
+            .map(|(name, _)| name)
+            .collect())
+    }
+
+    fn source(&self) -> Result<FfiSource> {
+        let mut source = FfiSource::default();
+        for type_crate in TYPE_CRATES {
+            source.collect_types(&self.workspace.join(type_crate).join("src"))?;
+        }
+        source.crawl_crate(
+            &self
+                .workspace
+                .join("value-box-ffi")
+                .join("src")
+                .join("lib.rs"),
+        )?;
+        Ok(source)
+    }
+}
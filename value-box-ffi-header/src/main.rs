@@ -0,0 +1,61 @@
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use value_box_ffi_header::HeaderGenerator;
+
+const USAGE: &str = "Usage: value-box-ffi-header [--workspace <directory>] [--features <feature,...>] [--output <file>]";
+
+fn main() -> ExitCode {
+    let mut workspace = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("..");
+    let mut features = None;
+    let mut output = None;
+
+    let mut arguments = std::env::args().skip(1);
+    while let Some(argument) = arguments.next() {
+        let value = match argument.as_str() {
+            "--workspace" | "--features" | "--output" => arguments.next(),
+            _ => None,
+        };
+        let Some(value) = value else {
+            eprintln!("{}", USAGE);
+            return ExitCode::FAILURE;
+        };
+        match argument.as_str() {
+            "--workspace" => workspace = PathBuf::from(value),
+            "--features" => {
+                features = Some(
+                    value
+                        .split(',')
+                        .filter(|feature| !feature.is_empty())
+                        .map(str::to_string)
+                        .collect::<Vec<_>>(),
+                )
+            }
+            _ => output = Some(PathBuf::from(value)),
+        }
+    }
+
+    let mut generator = HeaderGenerator::new(workspace);
+    if let Some(features) = features {
+        generator = generator.with_features(features);
+    }
+
+    let header = match generator.generate() {
+        Ok(header) => header,
+        Err(error) => {
+            eprintln!("Could not generate the header: {}", error);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match output {
+        Some(output) => {
+            if let Err(error) = std::fs::write(&output, header) {
+                eprintln!("Could not write {}: {}", output.display(), error);
+                return ExitCode::FAILURE;
+            }
+        }
+        None => print!("{}", header),
+    }
+    ExitCode::SUCCESS
+}
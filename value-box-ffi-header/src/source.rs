@@ -0,0 +1,434 @@
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use proc_macro2::TokenStream;
+use syn::{Attribute, Expr, ExprLit, FnArg, Item, ItemFn, Lit, Meta, Pat, ReturnType, Type};
+
+use crate::cfg::Cfg;
+use crate::expand::{paste, MacroRules};
+use crate::{HeaderError, Result};
+
+/// An `extern "C"` function exported with `#[no_mangle]`.
+#[derive(Clone)]
+pub struct FfiFunction {
+    pub name: String,
+    pub docs: Vec<String>,
+    pub cfg: Cfg,
+    pub arguments: Vec<(String, Type)>,
+    pub output: Option<Type>,
+}
+
+/// A `#[repr(C)]` struct, possibly generic over its field types.
+#[derive(Clone)]
+pub struct FfiStruct {
+    pub generics: Vec<String>,
+    pub fields: Vec<(String, Type)>,
+}
+
+/// A fieldless enum with a primitive representation, for example `#[repr(u8)]`.
+#[derive(Debug, Clone)]
+pub struct FfiEnum {
+    pub representation: String,
+    pub variants: Vec<(String, i64)>,
+}
+
+/// Everything that is declared by the crates contributing to the ffi.
+#[derive(Default)]
+pub struct FfiSource {
+    pub functions: Vec<FfiFunction>,
+    pub structs: HashMap<String, FfiStruct>,
+    pub enums: HashMap<String, FfiEnum>,
+    /// `#[repr(transparent)]` structs and the type of their only field
+    pub newtypes: HashMap<String, Type>,
+    /// Enums implementing `TryFrom`, which the foreign side passes as their representation
+    pub convertible_enums: HashSet<String>,
+    pub aliases: HashMap<String, Type>,
+    /// Every function exported with `#[no_mangle]` whatever its ABI
+    pub symbols: Vec<(String, Cfg)>,
+    macros: HashMap<String, MacroRules>,
+}
+
+impl FfiSource {
+    /// Collect the exported functions of a crate by following its module tree
+    /// starting from the given root file (`lib.rs`).
+    pub fn crawl_crate(&mut self, root: &Path) -> Result<()> {
+        let directory = root.parent().unwrap_or(Path::new("")).to_path_buf();
+        self.collect_exported_macros(&directory)?;
+        let file = parse_file(root)?;
+        self.crawl_items(file.items, &directory, &Cfg::True)
+    }
+
+    /// Collect the `#[macro_export]` macros of all files within a source directory up front,
+    /// they can be invoked anywhere in the crate, also before the module that defines them.
+    fn collect_exported_macros(&mut self, directory: &Path) -> Result<()> {
+        for path in source_files(directory)? {
+            if path.is_dir() {
+                self.collect_exported_macros(&path)?;
+            } else {
+                self.collect_exported_macros_of(parse_file(&path)?.items)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn collect_exported_macros_of(&mut self, items: Vec<Item>) -> Result<()> {
+        for item in items {
+            match item {
+                Item::Macro(item_macro)
+                    if item_macro.mac.path.is_ident("macro_rules")
+                        && item_macro
+                            .attrs
+                            .iter()
+                            .any(|attribute| attribute.path().is_ident("macro_export")) =>
+                {
+                    if let Some(ident) = item_macro.ident {
+                        let rules = MacroRules::parse(&ident.to_string(), item_macro.mac.tokens)?;
+                        if !rules.is_empty() {
+                            self.macros.insert(ident.to_string(), rules);
+                        }
+                    }
+                }
+                Item::Mod(module) => {
+                    if let Some((_, items)) = module.content {
+                        self.collect_exported_macros_of(items)?;
+                    }
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// Collect the type declarations of all files within a source directory.
+    pub fn collect_types(&mut self, directory: &Path) -> Result<()> {
+        for path in source_files(directory)? {
+            if path.is_dir() {
+                self.collect_types(&path)?;
+            } else {
+                for item in parse_file(&path)?.items {
+                    self.collect_type(item)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn crawl_items(&mut self, items: Vec<Item>, directory: &Path, cfg: &Cfg) -> Result<()> {
+        for item in items {
+            self.crawl_item(item, directory, cfg)?;
+        }
+        Ok(())
+    }
+
+    fn crawl_item(&mut self, item: Item, directory: &Path, cfg: &Cfg) -> Result<()> {
+        let item_cfg = match item_attributes(&item) {
+            Some(attributes) => cfg.clone().and(Cfg::from_attributes(attributes)?),
+            None => cfg.clone(),
+        };
+        if item_cfg == Cfg::False {
+            return Ok(());
+        }
+
+        match item {
+            Item::Fn(function) => {
+                if is_no_mangle(&function) {
+                    self.symbols
+                        .push((function.sig.ident.to_string(), item_cfg.clone()));
+                }
+                if is_no_mangle(&function) && is_extern_c(&function) {
+                    self.functions.push(ffi_function(function, item_cfg));
+                }
+            }
+            Item::Mod(module) => {
+                let name = module.ident.to_string();
+                match module.content {
+                    Some((_, items)) => {
+                        self.crawl_items(items, &directory.join(&name), &item_cfg)?
+                    }
+                    None => {
+                        let (path, module_directory) = find_module(directory, &name)?;
+                        let file = parse_file(&path)?;
+                        self.crawl_items(file.items, &module_directory, &item_cfg)?;
+                    }
+                }
+            }
+            Item::Macro(item_macro) => {
+                let name = item_macro
+                    .mac
+                    .path
+                    .segments
+                    .last()
+                    .map(|segment| segment.ident.to_string())
+                    .unwrap_or_default();
+
+                if name == "macro_rules" {
+                    if let Some(ident) = item_macro.ident {
+                        let rules = MacroRules::parse(&ident.to_string(), item_macro.mac.tokens)?;
+                        if !rules.is_empty() {
+                            self.macros.insert(ident.to_string(), rules);
+                        }
+                    }
+                } else if name == "paste" {
+                    self.crawl_tokens(&name, paste(item_macro.mac.tokens), directory, &item_cfg)?;
+                } else if let Some(rules) = self.macros.get(&name) {
+                    let expanded = rules.expand(item_macro.mac.tokens)?;
+                    self.crawl_tokens(&name, expanded, directory, &item_cfg)?;
+                }
+            }
+            item => self.collect_type(item)?,
+        }
+        Ok(())
+    }
+
+    fn crawl_tokens(
+        &mut self,
+        name: &str,
+        tokens: TokenStream,
+        directory: &Path,
+        cfg: &Cfg,
+    ) -> Result<()> {
+        let file = syn::parse2::<syn::File>(tokens).map_err(|error| HeaderError::MacroError {
+            name: name.to_string(),
+            reason: error.to_string(),
+        })?;
+        self.crawl_items(file.items, directory, cfg)
+    }
+
+    fn collect_type(&mut self, item: Item) -> Result<()> {
+        match item {
+            Item::Type(alias) => {
+                self.aliases.insert(alias.ident.to_string(), *alias.ty);
+            }
+            Item::Struct(item_struct)
+                if representation(&item_struct.attrs).as_deref() == Some("C") =>
+            {
+                let fields = item_struct
+                    .fields
+                    .iter()
+                    .enumerate()
+                    .map(|(index, field)| {
+                        let name = field
+                            .ident
+                            .as_ref()
+                            .map(|ident| ident.to_string())
+                            .unwrap_or_else(|| format!("_{}", index));
+                        (name, field.ty.clone())
+                    })
+                    .collect();
+
+                self.structs.insert(
+                    item_struct.ident.to_string(),
+                    FfiStruct {
+                        generics: item_struct
+                            .generics
+                            .type_params()
+                            .map(|param| param.ident.to_string())
+                            .collect(),
+                        fields,
+                    },
+                );
+            }
+            Item::Struct(item_struct)
+                if representation(&item_struct.attrs).as_deref() == Some("transparent")
+                    && item_struct.fields.len() == 1 =>
+            {
+                if let Some(field) = item_struct.fields.iter().next() {
+                    self.newtypes
+                        .insert(item_struct.ident.to_string(), field.ty.clone());
+                }
+            }
+            Item::Enum(item_enum) => {
+                let representation = representation(&item_enum.attrs);
+                let is_fieldless = item_enum
+                    .variants
+                    .iter()
+                    .all(|variant| variant.fields.is_empty());
+
+                if let (Some(representation), true) = (representation, is_fieldless) {
+                    let mut variants = vec![];
+                    let mut next = 0;
+                    for variant in &item_enum.variants {
+                        if Cfg::from_attributes(&variant.attrs)? == Cfg::False {
+                            continue;
+                        }
+                        let value = match &variant.discriminant {
+                            Some((
+                                _,
+                                Expr::Lit(ExprLit {
+                                    lit: Lit::Int(value),
+                                    ..
+                                }),
+                            )) => value.base10_parse::<i64>().unwrap_or(next),
+                            _ => next,
+                        };
+                        variants.push((variant.ident.to_string(), value));
+                        next = value + 1;
+                    }
+
+                    self.enums.insert(
+                        item_enum.ident.to_string(),
+                        FfiEnum {
+                            representation,
+                            variants,
+                        },
+                    );
+                }
+            }
+            Item::Impl(item_impl) => {
+                let is_try_from = item_impl.trait_.as_ref().is_some_and(|(_, path, _)| {
+                    path.segments
+                        .last()
+                        .is_some_and(|segment| segment.ident == "TryFrom")
+                });
+                if let (true, Type::Path(self_type)) = (is_try_from, item_impl.self_ty.as_ref()) {
+                    if let Some(ident) = self_type.path.get_ident() {
+                        self.convertible_enums.insert(ident.to_string());
+                    }
+                }
+            }
+            Item::Mod(module) => {
+                let cfg = Cfg::from_attributes(&module.attrs)?;
+                if let (Some((_, items)), false) = (module.content, cfg == Cfg::False) {
+                    for item in items {
+                        self.collect_type(item)?;
+                    }
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+/// The Rust files and subdirectories of a directory, sorted by name.
+fn source_files(directory: &Path) -> Result<Vec<PathBuf>> {
+    let mut entries = fs::read_dir(directory)
+        .map_err(|error| HeaderError::IOError(directory.to_path_buf(), error))?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<std::io::Result<Vec<_>>>()
+        .map_err(|error| HeaderError::IOError(directory.to_path_buf(), error))?;
+    entries.retain(|path| {
+        path.is_dir() || path.extension().is_some_and(|extension| extension == "rs")
+    });
+    entries.sort();
+    Ok(entries)
+}
+
+fn parse_file(path: &Path) -> Result<syn::File> {
+    let source = fs::read_to_string(path)
+        .map_err(|error| HeaderError::IOError(path.to_path_buf(), error))?;
+    syn::parse_file(&source).map_err(|error| HeaderError::ParseError(path.to_path_buf(), error))
+}
+
+/// Return the file of a module declared as `mod name;` and the directory of its submodules.
+fn find_module(directory: &Path, name: &str) -> Result<(PathBuf, PathBuf)> {
+    let file = directory.join(format!("{}.rs", name));
+    if file.is_file() {
+        return Ok((file, directory.join(name)));
+    }
+    let file = directory.join(name).join("mod.rs");
+    if file.is_file() {
+        return Ok((file, directory.join(name)));
+    }
+    Err(HeaderError::ModuleNotFound {
+        module: name.to_string(),
+        directory: directory.to_path_buf(),
+    })
+}
+
+fn item_attributes(item: &Item) -> Option<&Vec<Attribute>> {
+    match item {
+        Item::Fn(item) => Some(&item.attrs),
+        Item::Mod(item) => Some(&item.attrs),
+        Item::Macro(item) => Some(&item.attrs),
+        Item::Type(item) => Some(&item.attrs),
+        Item::Struct(item) => Some(&item.attrs),
+        Item::Enum(item) => Some(&item.attrs),
+        _ => None,
+    }
+}
+
+fn is_no_mangle(function: &ItemFn) -> bool {
+    function.attrs.iter().any(|attribute| {
+        attribute.path().is_ident("no_mangle")
+            || (attribute.path().is_ident("unsafe")
+                && attribute
+                    .parse_args::<syn::Path>()
+                    .is_ok_and(|path| path.is_ident("no_mangle")))
+    })
+}
+
+fn is_extern_c(function: &ItemFn) -> bool {
+    function
+        .sig
+        .abi
+        .as_ref()
+        .is_some_and(|abi| abi.name.as_ref().is_none_or(|name| name.value() == "C"))
+}
+
+fn ffi_function(function: ItemFn, cfg: Cfg) -> FfiFunction {
+    let docs = function
+        .attrs
+        .iter()
+        .filter_map(|attribute| match &attribute.meta {
+            Meta::NameValue(doc) if doc.path.is_ident("doc") => match &doc.value {
+                Expr::Lit(ExprLit {
+                    lit: Lit::Str(line),
+                    ..
+                }) => Some(line.value().trim().to_string()),
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect();
+
+    let arguments = function
+        .sig
+        .inputs
+        .iter()
+        .enumerate()
+        .filter_map(|(index, input)| match input {
+            FnArg::Typed(argument) => {
+                let name = match argument.pat.as_ref() {
+                    Pat::Ident(ident) => ident.ident.to_string(),
+                    _ => format!("arg{}", index),
+                };
+                Some((name, argument.ty.as_ref().clone()))
+            }
+            FnArg::Receiver(_) => None,
+        })
+        .collect();
+
+    let output = match function.sig.output {
+        ReturnType::Default => None,
+        ReturnType::Type(_, ty) => Some(*ty),
+    };
+
+    FfiFunction {
+        name: function.sig.ident.to_string(),
+        docs,
+        cfg,
+        arguments,
+        output,
+    }
+}
+
+/// The representation given with `#[repr(...)]`, ignoring alignment modifiers.
+fn representation(attributes: &[Attribute]) -> Option<String> {
+    let mut representation = None;
+    for attribute in attributes {
+        if attribute.path().is_ident("repr") {
+            let _ = attribute.parse_nested_meta(|meta| {
+                if let Some(ident) = meta.path.get_ident() {
+                    let ident = ident.to_string();
+                    if ident != "packed" && ident != "align" {
+                        representation = Some(ident);
+                    }
+                }
+                Ok(())
+            });
+        }
+    }
+    representation
+}
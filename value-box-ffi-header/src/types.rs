@@ -0,0 +1,301 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use quote::ToTokens;
+use syn::{BareFnArg, GenericArgument, PathArguments, ReturnType, Type, TypeBareFn, TypePath};
+
+use crate::source::FfiSource;
+use crate::{HeaderError, Result};
+
+/// Maps Rust types used in the ffi signatures to C types
+/// and remembers the declarations that the header needs.
+pub struct TypeMapper<'source> {
+    source: &'source FfiSource,
+    /// Opaque value box types, such as `ValueBox_PointBox_f32`
+    pub opaque_types: BTreeSet<String>,
+    /// Instantiated `#[repr(C)]` structs and their fields
+    pub structs: BTreeMap<String, Vec<(String, String)>>,
+    pub enums: BTreeSet<String>,
+    /// `#[repr(transparent)]` structs and the C type they are declared as
+    pub newtypes: BTreeMap<String, String>,
+    /// Function pointer types declared with a type alias and their `typedef`
+    pub function_pointers: BTreeMap<String, String>,
+}
+
+impl<'source> TypeMapper<'source> {
+    pub fn new(source: &'source FfiSource) -> Self {
+        Self {
+            source,
+            opaque_types: Default::default(),
+            structs: Default::default(),
+            enums: Default::default(),
+            newtypes: Default::default(),
+            function_pointers: Default::default(),
+        }
+    }
+
+    /// The C type of a given Rust type, `function` is only used for error reporting.
+    pub fn c_type(&mut self, ty: &Type, function: &str) -> Result<String> {
+        self.c_type_with(ty, &HashMap::new())
+            .ok_or_else(|| HeaderError::UnsupportedType {
+                function: function.to_string(),
+                ty: type_to_string(ty),
+            })
+    }
+
+    fn c_type_with(&mut self, ty: &Type, generics: &HashMap<String, Type>) -> Option<String> {
+        match ty {
+            Type::Ptr(pointer) => {
+                let pointee = self.c_type_with(&pointer.elem, generics)?;
+                let is_const = pointer.const_token.is_some();
+                Some(if pointee.ends_with('*') {
+                    format!("{}{}*", pointee, if is_const { "const " } else { "" })
+                } else {
+                    format!("{}{} *", if is_const { "const " } else { "" }, pointee)
+                })
+            }
+            Type::Tuple(tuple) if tuple.elems.is_empty() => Some("void".to_string()),
+            Type::Paren(paren) => self.c_type_with(&paren.elem, generics),
+            Type::Group(group) => self.c_type_with(&group.elem, generics),
+            Type::Path(path) => self.c_path_type(path, generics),
+            _ => None,
+        }
+    }
+
+    fn c_path_type(&mut self, path: &TypePath, generics: &HashMap<String, Type>) -> Option<String> {
+        if path.qself.is_some() {
+            return None;
+        }
+        let segment = path.path.segments.last()?;
+        let name = segment.ident.to_string();
+        let arguments = type_arguments(&segment.arguments);
+
+        if arguments.is_empty() {
+            if let Some(ty) = generics.get(&name) {
+                return self.c_type_with(&ty.clone(), &HashMap::new());
+            }
+            if let Some(primitive) = primitive_type(&name) {
+                return Some(primitive.to_string());
+            }
+        }
+
+        match name.as_str() {
+            "ValueBox" => {
+                let value = arguments.first()?;
+                let resolved = self.resolve_aliases(value);
+                let name = if self.is_void(&resolved) {
+                    "ValueBox".to_string()
+                } else {
+                    format!("ValueBox_{}", self.mangle(&resolved)?)
+                };
+                // make the layout of the boxed value known, when it is representable in C
+                let _ = self.c_type_with(&resolved, generics);
+                self.opaque_types.insert(name.clone());
+                Some(name)
+            }
+            "ValueBoxHandle" => Some("uint64_t".to_string()),
+            _ => {
+                if let Some(ty) = self.source.aliases.get(&name) {
+                    if let Some(function) = bare_function(ty) {
+                        return self.c_function_pointer(&name, &function.clone());
+                    }
+                    return self.c_type_with(&ty.clone(), generics);
+                }
+                if self.source.enums.contains_key(&name) {
+                    self.enums.insert(name.clone());
+                    return Some(name);
+                }
+                if let Some(ty) = self.source.newtypes.get(&name) {
+                    let c_type = self.c_type_with(&ty.clone(), generics)?;
+                    self.newtypes.insert(name.clone(), c_type);
+                    return Some(name);
+                }
+                let ffi_struct = self.source.structs.get(&name)?;
+                let arguments = arguments
+                    .iter()
+                    .map(|argument| self.substitute(argument, generics))
+                    .collect::<Vec<_>>();
+                let struct_generics = ffi_struct
+                    .generics
+                    .iter()
+                    .cloned()
+                    .zip(arguments.iter().cloned())
+                    .collect::<HashMap<_, _>>();
+                if struct_generics.len() != ffi_struct.generics.len() {
+                    return None;
+                }
+
+                let mangled = std::iter::once(Some(name.clone()))
+                    .chain(arguments.iter().map(|argument| self.mangle(argument)))
+                    .collect::<Option<Vec<_>>>()?
+                    .join("_");
+
+                if !self.structs.contains_key(&mangled) {
+                    let fields = ffi_struct
+                        .fields
+                        .iter()
+                        .map(|(field, ty)| {
+                            self.c_type_with(ty, &struct_generics)
+                                .map(|ty| (field.clone(), ty))
+                        })
+                        .collect::<Option<Vec<_>>>()?;
+                    self.structs.insert(mangled.clone(), fields);
+                }
+                Some(mangled)
+            }
+        }
+    }
+
+    /// Function pointers can only be declared through a named type alias,
+    /// for example `pub type Callback = extern "C" fn(*mut c_void);`.
+    /// An alias to an `Option` of a function pointer is a pointer that may be null.
+    fn c_function_pointer(&mut self, name: &str, function: &TypeBareFn) -> Option<String> {
+        if !self.function_pointers.contains_key(name) {
+            let is_extern_c = function
+                .abi
+                .as_ref()
+                .is_some_and(|abi| abi.name.as_ref().is_none_or(|name| name.value() == "C"));
+            if !is_extern_c || function.variadic.is_some() {
+                return None;
+            }
+            let output = match &function.output {
+                ReturnType::Default => "void".to_string(),
+                ReturnType::Type(_, ty) => self.c_type_with(ty, &HashMap::new())?,
+            };
+            let arguments = function
+                .inputs
+                .iter()
+                .map(|BareFnArg { ty, .. }| self.c_type_with(ty, &HashMap::new()))
+                .collect::<Option<Vec<_>>>()?;
+            let arguments = if arguments.is_empty() {
+                "void".to_string()
+            } else {
+                arguments.join(", ")
+            };
+            self.function_pointers.insert(
+                name.to_string(),
+                format!("typedef {} (*{})({});", output, name, arguments),
+            );
+        }
+        Some(name.to_string())
+    }
+
+    fn substitute(&self, ty: &Type, generics: &HashMap<String, Type>) -> Type {
+        match ty {
+            Type::Path(path) if path.path.segments.len() == 1 => path
+                .path
+                .get_ident()
+                .and_then(|ident| generics.get(&ident.to_string()))
+                .cloned()
+                .unwrap_or_else(|| ty.clone()),
+            _ => ty.clone(),
+        }
+    }
+
+    fn resolve_aliases(&self, ty: &Type) -> Type {
+        if let Type::Path(path) = ty {
+            if let Some(ident) = path.path.get_ident() {
+                if let Some(alias) = self.source.aliases.get(&ident.to_string()) {
+                    return self.resolve_aliases(alias);
+                }
+            }
+        }
+        ty.clone()
+    }
+
+    fn is_void(&self, ty: &Type) -> bool {
+        matches!(ty, Type::Path(path) if path.path.segments.last().is_some_and(|segment| segment.ident == "c_void"))
+    }
+
+    /// A C identifier for a Rust type, for example `PointBox_f32` for `PointBox<f32>`.
+    fn mangle(&self, ty: &Type) -> Option<String> {
+        match self.resolve_aliases(ty) {
+            Type::Path(path) if path.qself.is_none() => {
+                let segment = path.path.segments.last()?;
+                std::iter::once(Some(segment.ident.to_string()))
+                    .chain(
+                        type_arguments(&segment.arguments)
+                            .iter()
+                            .map(|argument| self.mangle(argument)),
+                    )
+                    .collect::<Option<Vec<_>>>()
+                    .map(|segments| segments.join("_"))
+            }
+            Type::Ptr(pointer) => self
+                .mangle(&pointer.elem)
+                .map(|name| format!("{}_ptr", name)),
+            _ => None,
+        }
+    }
+}
+
+fn type_arguments(arguments: &PathArguments) -> Vec<Type> {
+    match arguments {
+        PathArguments::AngleBracketed(arguments) => arguments
+            .args
+            .iter()
+            .filter_map(|argument| match argument {
+                GenericArgument::Type(ty) => Some(ty.clone()),
+                _ => None,
+            })
+            .collect(),
+        _ => vec![],
+    }
+}
+
+/// The function pointer of a type alias, either `extern "C" fn(...)` or `Option<extern "C" fn(...)>`.
+fn bare_function(ty: &Type) -> Option<&TypeBareFn> {
+    match ty {
+        Type::BareFn(function) => Some(function),
+        Type::Path(path) if path.qself.is_none() => {
+            let segment = path.path.segments.last()?;
+            if segment.ident != "Option" {
+                return None;
+            }
+            match &segment.arguments {
+                PathArguments::AngleBracketed(arguments) => match arguments.args.first()? {
+                    GenericArgument::Type(Type::BareFn(function)) => Some(function),
+                    _ => None,
+                },
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+fn primitive_type(name: &str) -> Option<&'static str> {
+    Some(match name {
+        "bool" => "bool",
+        "u8" => "uint8_t",
+        "i8" => "int8_t",
+        "u16" => "uint16_t",
+        "i16" => "int16_t",
+        "u32" => "uint32_t",
+        "i32" => "int32_t",
+        "u64" => "uint64_t",
+        "i64" => "int64_t",
+        "usize" => "size_t",
+        "isize" => "intptr_t",
+        "f32" => "float",
+        "f64" => "double",
+        "c_void" => "void",
+        "c_char" => "char",
+        "c_schar" => "signed char",
+        "c_uchar" => "unsigned char",
+        "c_short" => "short",
+        "c_ushort" => "unsigned short",
+        "c_int" => "int",
+        "c_uint" => "unsigned int",
+        "c_long" => "long",
+        "c_ulong" => "unsigned long",
+        "c_longlong" => "long long",
+        "c_ulonglong" => "unsigned long long",
+        "c_float" => "float",
+        "c_double" => "double",
+        _ => return None,
+    })
+}
+
+fn type_to_string(ty: &Type) -> String {
+    ty.to_token_stream().to_string()
+}
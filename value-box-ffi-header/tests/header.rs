@@ -0,0 +1,84 @@
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use value_box_ffi_header::{HeaderGenerator, Result};
+
+fn generator() -> HeaderGenerator {
+    HeaderGenerator::new(PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(".."))
+}
+
+#[test]
+fn checked_in_header_is_up_to_date() -> Result<()> {
+    let generator = generator();
+    let header = generator.generate()?;
+    let path = generator
+        .workspace()
+        .join("value-box-ffi")
+        .join("include")
+        .join("value_box_ffi.h");
+    let checked_in = std::fs::read_to_string(&path).unwrap_or_default();
+
+    assert!(
+        header == checked_in,
+        "{} is outdated, regenerate it with `cargo run -p value-box-ffi-header -- --output value-box-ffi/include/value_box_ffi.h`",
+        path.display()
+    );
+    Ok(())
+}
+
+#[test]
+fn header_with_features() -> Result<()> {
+    let header = generator().with_features(["array-box"]).generate()?;
+
+    assert!(header.contains(
+        "BoxerStatus boxer_array_u8_at_checked(ValueBox_ArrayBox_u8 *array, size_t index, uint8_t *item);"
+    ));
+    assert!(header.contains("typedef struct ArrayBox_c_int {\n    int *data;"));
+    assert!(!header.contains("boxer_point_f32_create"));
+    assert!(!header.contains("VALUE_BOX_FFI_FEATURE_"));
+    Ok(())
+}
+
+#[test]
+fn manifest_describes_every_function() -> Result<()> {
+    let generator = generator().with_features([
+        "value-box",
+        "census",
+        "array-box",
+        "geometry-box",
+        "image-box",
+        "string-box",
+        "serde",
+    ]);
+    let header = generator.generate()?;
+
+    // the name and the amount of arguments of every declared function
+    let declared = header
+        .lines()
+        .filter(|line| !line.starts_with("//") && !line.starts_with("typedef"))
+        .filter(|line| line.ends_with(");"))
+        .filter_map(|line| {
+            let (head, arguments) = line.trim_end_matches(");").split_once('(')?;
+            let name = head.rsplit([' ', '*']).next()?.to_string();
+            let arguments = if arguments == "void" {
+                0
+            } else {
+                arguments.split(", ").count()
+            };
+            Some((name, arguments))
+        })
+        .collect::<BTreeMap<_, _>>();
+
+    let described = value_box_ffi::ffi_manifest()
+        .functions()
+        .iter()
+        .map(|function| (function.name.clone(), function.arguments.len()))
+        .collect::<BTreeMap<_, _>>();
+
+    assert_eq!(declared, described);
+
+    // a `#[no_mangle]` function that is not `extern "C"` is exported but never declared
+    let exported = generator.exported_symbols()?;
+    assert_eq!(exported, declared.into_keys().collect());
+    Ok(())
+}
@@ -0,0 +1,3221 @@
+/* Generated by value-box-ffi-header, do not edit manually. */
+
+#ifndef VALUE_BOX_FFI_H
+#define VALUE_BOX_FFI_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+/* Define VALUE_BOX_FFI_FEATURE_<NAME> for every cargo feature the library is built with, */
+/* for example VALUE_BOX_FFI_FEATURE_ARRAY_BOX for the `array-box` feature. */
+
+enum {
+    ArrayConversion_Saturating = 0,
+    ArrayConversion_Wrapping = 1,
+    ArrayConversion_Normalizing = 2,
+    ArrayConversion_Lossless = 3,
+};
+typedef uint8_t ArrayConversion;
+
+enum {
+    BoxerErrorKind_NullPointer = 0,
+    BoxerErrorKind_NoValue = 1,
+    BoxerErrorKind_InvalidHandle = 2,
+    BoxerErrorKind_WrongType = 3,
+    BoxerErrorKind_WouldBlock = 4,
+    BoxerErrorKind_Panic = 5,
+    BoxerErrorKind_AnyhowError = 6,
+    BoxerErrorKind_IOError = 7,
+    BoxerErrorKind_AnyError = 8,
+    BoxerErrorKind_OutOfBounds = 9,
+    BoxerErrorKind_AlreadyBorrowed = 10,
+    BoxerErrorKind_WrongThread = 11,
+    BoxerErrorKind_SerializationError = 12,
+    BoxerErrorKind_Invalidated = 13,
+    BoxerErrorKind_LengthMismatch = 14,
+    BoxerErrorKind_LossyConversion = 15,
+    BoxerErrorKind_NotShared = 16,
+    BoxerErrorKind_NotWeak = 17,
+    BoxerErrorKind_InvalidArgument = 18,
+};
+typedef uint8_t BoxerErrorKind;
+
+enum {
+    CallbackAffinity_AnyThread = 0,
+    CallbackAffinity_CreatingThread = 1,
+};
+typedef uint8_t CallbackAffinity;
+
+enum {
+    FutureBoxState_Pending = 0,
+    FutureBoxState_Ready = 1,
+    FutureBoxState_Taken = 2,
+};
+typedef uint8_t FutureBoxState;
+
+enum {
+    PixelFormat_Argb32 = 0,
+    PixelFormat_Rgba32 = 1,
+    PixelFormat_Bgra32 = 2,
+    PixelFormat_Abgr32 = 3,
+    PixelFormat_Rgb24 = 4,
+    PixelFormat_Bgr24 = 5,
+    PixelFormat_Gray8 = 6,
+};
+typedef uint8_t PixelFormat;
+
+enum {
+    SerializationFormat_Json = 0,
+    SerializationFormat_Cbor = 1,
+    SerializationFormat_Bincode = 2,
+};
+typedef uint8_t SerializationFormat;
+
+typedef uint8_t BoxerStatus;
+
+typedef struct ValueBox ValueBox;
+typedef struct ValueBox_ArrayBox_c_int ValueBox_ArrayBox_c_int;
+typedef struct ValueBox_ArrayBox_c_uint ValueBox_ArrayBox_c_uint;
+typedef struct ValueBox_ArrayBox_f32 ValueBox_ArrayBox_f32;
+typedef struct ValueBox_ArrayBox_f64 ValueBox_ArrayBox_f64;
+typedef struct ValueBox_ArrayBox_i16 ValueBox_ArrayBox_i16;
+typedef struct ValueBox_ArrayBox_i32 ValueBox_ArrayBox_i32;
+typedef struct ValueBox_ArrayBox_i64 ValueBox_ArrayBox_i64;
+typedef struct ValueBox_ArrayBox_i8 ValueBox_ArrayBox_i8;
+typedef struct ValueBox_ArrayBox_isize ValueBox_ArrayBox_isize;
+typedef struct ValueBox_ArrayBox_u16 ValueBox_ArrayBox_u16;
+typedef struct ValueBox_ArrayBox_u32 ValueBox_ArrayBox_u32;
+typedef struct ValueBox_ArrayBox_u64 ValueBox_ArrayBox_u64;
+typedef struct ValueBox_ArrayBox_u8 ValueBox_ArrayBox_u8;
+typedef struct ValueBox_ArrayBox_usize ValueBox_ArrayBox_usize;
+typedef struct ValueBox_CallbackBox ValueBox_CallbackBox;
+typedef struct ValueBox_FutureBox ValueBox_FutureBox;
+typedef struct ValueBox_ImageBox ValueBox_ImageBox;
+typedef struct ValueBox_Point3Box_f32 ValueBox_Point3Box_f32;
+typedef struct ValueBox_PointBox_f32 ValueBox_PointBox_f32;
+typedef struct ValueBox_PointBox_f64 ValueBox_PointBox_f64;
+typedef struct ValueBox_PointBox_i32 ValueBox_PointBox_i32;
+typedef struct ValueBox_PointBox_u64 ValueBox_PointBox_u64;
+typedef struct ValueBox_Range_usize ValueBox_Range_usize;
+typedef struct ValueBox_SizeBox_f32 ValueBox_SizeBox_f32;
+typedef struct ValueBox_SizeBox_f64 ValueBox_SizeBox_f64;
+typedef struct ValueBox_SizeBox_i32 ValueBox_SizeBox_i32;
+typedef struct ValueBox_SizeBox_u32 ValueBox_SizeBox_u32;
+typedef struct ValueBox_SizeBox_u64 ValueBox_SizeBox_u64;
+typedef struct ValueBox_StringBox ValueBox_StringBox;
+typedef struct ValueBox_U128Box ValueBox_U128Box;
+
+typedef void (*ArrayBoxRelease)(void *);
+typedef void (*CallbackDestructor)(void *);
+typedef void (*CallbackFunction)(void *, void *);
+typedef void (*FutureWakeCallback)(void *);
+
+typedef struct ViewedBuffer {
+    const void *flag;
+} ViewedBuffer;
+
+typedef struct ArrayBox_c_int {
+    int *data;
+    size_t length;
+    size_t capacity;
+    bool owned;
+    bool valid;
+    ArrayBoxRelease release;
+    void *release_data;
+    ViewedBuffer views;
+    ViewedBuffer viewed;
+} ArrayBox_c_int;
+
+typedef struct ArrayBox_c_uint {
+    unsigned int *data;
+    size_t length;
+    size_t capacity;
+    bool owned;
+    bool valid;
+    ArrayBoxRelease release;
+    void *release_data;
+    ViewedBuffer views;
+    ViewedBuffer viewed;
+} ArrayBox_c_uint;
+
+typedef struct ArrayBox_f32 {
+    float *data;
+    size_t length;
+    size_t capacity;
+    bool owned;
+    bool valid;
+    ArrayBoxRelease release;
+    void *release_data;
+    ViewedBuffer views;
+    ViewedBuffer viewed;
+} ArrayBox_f32;
+
+typedef struct ArrayBox_f64 {
+    double *data;
+    size_t length;
+    size_t capacity;
+    bool owned;
+    bool valid;
+    ArrayBoxRelease release;
+    void *release_data;
+    ViewedBuffer views;
+    ViewedBuffer viewed;
+} ArrayBox_f64;
+
+typedef struct ArrayBox_i16 {
+    int16_t *data;
+    size_t length;
+    size_t capacity;
+    bool owned;
+    bool valid;
+    ArrayBoxRelease release;
+    void *release_data;
+    ViewedBuffer views;
+    ViewedBuffer viewed;
+} ArrayBox_i16;
+
+typedef struct ArrayBox_i32 {
+    int32_t *data;
+    size_t length;
+    size_t capacity;
+    bool owned;
+    bool valid;
+    ArrayBoxRelease release;
+    void *release_data;
+    ViewedBuffer views;
+    ViewedBuffer viewed;
+} ArrayBox_i32;
+
+typedef struct ArrayBox_i64 {
+    int64_t *data;
+    size_t length;
+    size_t capacity;
+    bool owned;
+    bool valid;
+    ArrayBoxRelease release;
+    void *release_data;
+    ViewedBuffer views;
+    ViewedBuffer viewed;
+} ArrayBox_i64;
+
+typedef struct ArrayBox_i8 {
+    int8_t *data;
+    size_t length;
+    size_t capacity;
+    bool owned;
+    bool valid;
+    ArrayBoxRelease release;
+    void *release_data;
+    ViewedBuffer views;
+    ViewedBuffer viewed;
+} ArrayBox_i8;
+
+typedef struct ArrayBox_isize {
+    intptr_t *data;
+    size_t length;
+    size_t capacity;
+    bool owned;
+    bool valid;
+    ArrayBoxRelease release;
+    void *release_data;
+    ViewedBuffer views;
+    ViewedBuffer viewed;
+} ArrayBox_isize;
+
+typedef struct ArrayBox_u16 {
+    uint16_t *data;
+    size_t length;
+    size_t capacity;
+    bool owned;
+    bool valid;
+    ArrayBoxRelease release;
+    void *release_data;
+    ViewedBuffer views;
+    ViewedBuffer viewed;
+} ArrayBox_u16;
+
+typedef struct ArrayBox_u32 {
+    uint32_t *data;
+    size_t length;
+    size_t capacity;
+    bool owned;
+    bool valid;
+    ArrayBoxRelease release;
+    void *release_data;
+    ViewedBuffer views;
+    ViewedBuffer viewed;
+} ArrayBox_u32;
+
+typedef struct ArrayBox_u64 {
+    uint64_t *data;
+    size_t length;
+    size_t capacity;
+    bool owned;
+    bool valid;
+    ArrayBoxRelease release;
+    void *release_data;
+    ViewedBuffer views;
+    ViewedBuffer viewed;
+} ArrayBox_u64;
+
+typedef struct ArrayBox_u8 {
+    uint8_t *data;
+    size_t length;
+    size_t capacity;
+    bool owned;
+    bool valid;
+    ArrayBoxRelease release;
+    void *release_data;
+    ViewedBuffer views;
+    ViewedBuffer viewed;
+} ArrayBox_u8;
+
+typedef struct ArrayBox_usize {
+    size_t *data;
+    size_t length;
+    size_t capacity;
+    bool owned;
+    bool valid;
+    ArrayBoxRelease release;
+    void *release_data;
+    ViewedBuffer views;
+    ViewedBuffer viewed;
+} ArrayBox_usize;
+
+typedef struct Point3Box_f32 {
+    float x;
+    float y;
+    float z;
+} Point3Box_f32;
+
+typedef struct PointBox_f32 {
+    float x;
+    float y;
+} PointBox_f32;
+
+typedef struct PointBox_f64 {
+    double x;
+    double y;
+} PointBox_f64;
+
+typedef struct PointBox_i32 {
+    int32_t x;
+    int32_t y;
+} PointBox_i32;
+
+typedef struct PointBox_u64 {
+    uint64_t x;
+    uint64_t y;
+} PointBox_u64;
+
+typedef struct SizeBox_f32 {
+    float width;
+    float height;
+} SizeBox_f32;
+
+typedef struct SizeBox_f64 {
+    double width;
+    double height;
+} SizeBox_f64;
+
+typedef struct SizeBox_i32 {
+    int32_t width;
+    int32_t height;
+} SizeBox_i32;
+
+typedef struct SizeBox_u32 {
+    uint32_t width;
+    uint32_t height;
+} SizeBox_u32;
+
+typedef struct SizeBox_u64 {
+    uint64_t width;
+    uint64_t height;
+} SizeBox_u64;
+
+typedef struct U128Box {
+    uint64_t high;
+    uint64_t low;
+} U128Box;
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+#if defined(VALUE_BOX_FFI_FEATURE_ARRAY_BOX)
+
+// In-place convert argb to rgba
+void boxer_array_u8_argb_to_rgba(ValueBox_ArrayBox_u8 *array);
+
+// In-place convert bgra to argb
+void boxer_array_u8_bgra_to_argb(ValueBox_ArrayBox_u8 *array);
+
+// In-place convert rgba to argb
+void boxer_array_u8_rgba_to_argb(ValueBox_ArrayBox_u8 *array);
+
+// Create a new array with the pixels of a given array converted to another `PixelFormat`,
+// pixels without alpha get a given alpha.
+ValueBox_ArrayBox_u8 *boxer_array_u8_convert_pixels(ValueBox_ArrayBox_u8 *array, uint8_t source_format, uint8_t destination_format, uint8_t alpha);
+
+// In-place convert pixels between two `PixelFormat`s of the same pixel size
+void boxer_array_u8_convert_pixels_in_place(ValueBox_ArrayBox_u8 *array, uint8_t source_format, uint8_t destination_format);
+
+// Convert the pixels of an image whose rows are padded to a stride into another image
+void boxer_array_u8_convert_pixel_rows(ValueBox_ArrayBox_u8 *source, size_t width, size_t height, size_t source_stride, uint8_t source_format, ValueBox_ArrayBox_u8 *destination, size_t destination_stride, uint8_t destination_format, uint8_t alpha);
+
+// In-place multiply the color of pixels of a given `PixelFormat` by their alpha
+void boxer_array_u8_premultiply_alpha(ValueBox_ArrayBox_u8 *array, uint8_t format);
+
+// In-place divide the color of pixels of a given `PixelFormat` by their alpha
+void boxer_array_u8_unpremultiply_alpha(ValueBox_ArrayBox_u8 *array, uint8_t format);
+
+// Same as `boxer_array_u8_convert_pixels_in_place`, but return the status of the conversion
+BoxerStatus boxer_array_u8_convert_pixels_in_place_checked(ValueBox_ArrayBox_u8 *array, uint8_t source_format, uint8_t destination_format);
+
+// Same as `boxer_array_u8_convert_pixel_rows`, but return the status of the conversion
+BoxerStatus boxer_array_u8_convert_pixel_rows_checked(ValueBox_ArrayBox_u8 *source, size_t width, size_t height, size_t source_stride, uint8_t source_format, ValueBox_ArrayBox_u8 *destination, size_t destination_stride, uint8_t destination_format, uint8_t alpha);
+
+// Same as `boxer_array_u8_premultiply_alpha`, but return the status of the operation
+BoxerStatus boxer_array_u8_premultiply_alpha_checked(ValueBox_ArrayBox_u8 *array, uint8_t format);
+
+// Same as `boxer_array_u8_unpremultiply_alpha`, but return the status of the operation
+BoxerStatus boxer_array_u8_unpremultiply_alpha_checked(ValueBox_ArrayBox_u8 *array, uint8_t format);
+
+// Set the amount of worker threads of bulk array operations,
+// 0 resets it to the amount of available cores and 1 disables the workers
+void boxer_set_parallelism(size_t threads);
+
+size_t boxer_get_parallelism(void);
+
+// Set the amount of array items from which bulk operations are split between the workers
+void boxer_set_parallel_threshold(size_t items);
+
+size_t boxer_get_parallel_threshold(void);
+
+ValueBox_ArrayBox_u8 *boxer_array_u8_create(void);
+
+ValueBox_ArrayBox_u8 *boxer_array_u8_create_with(uint8_t element, size_t amount);
+
+ValueBox_ArrayBox_u8 *boxer_array_u8_create_from_data(uint8_t *data, size_t amount);
+
+void boxer_array_u8_copy_into(ValueBox_ArrayBox_u8 *src, ValueBox_ArrayBox_u8 *dst);
+
+void boxer_array_u8_copy_into_data(ValueBox_ArrayBox_u8 *src, uint8_t *data, size_t amount);
+
+size_t boxer_array_u8_get_length(ValueBox_ArrayBox_u8 *array);
+
+size_t boxer_array_u8_get_capacity(ValueBox_ArrayBox_u8 *array);
+
+uint8_t *boxer_array_u8_get_data(ValueBox_ArrayBox_u8 *array);
+
+uint8_t boxer_array_u8_at(ValueBox_ArrayBox_u8 *array, size_t index);
+
+void boxer_array_u8_at_put(ValueBox_ArrayBox_u8 *array, size_t index, uint8_t item);
+
+void boxer_array_u8_push(ValueBox_ArrayBox_u8 *array, uint8_t item);
+
+uint8_t boxer_array_u8_pop(ValueBox_ArrayBox_u8 *array);
+
+void boxer_array_u8_insert(ValueBox_ArrayBox_u8 *array, size_t index, uint8_t item);
+
+uint8_t boxer_array_u8_remove(ValueBox_ArrayBox_u8 *array, size_t index);
+
+void boxer_array_u8_truncate(ValueBox_ArrayBox_u8 *array, size_t length);
+
+void boxer_array_u8_resize(ValueBox_ArrayBox_u8 *array, size_t length, uint8_t item);
+
+void boxer_array_u8_reserve(ValueBox_ArrayBox_u8 *array, size_t additional);
+
+void boxer_array_u8_extend_from_slice(ValueBox_ArrayBox_u8 *array, const uint8_t *data, size_t amount);
+
+BoxerStatus boxer_array_u8_pop_checked(ValueBox_ArrayBox_u8 *array, uint8_t *item);
+
+BoxerStatus boxer_array_u8_insert_checked(ValueBox_ArrayBox_u8 *array, size_t index, uint8_t item);
+
+BoxerStatus boxer_array_u8_remove_checked(ValueBox_ArrayBox_u8 *array, size_t index, uint8_t *item);
+
+void boxer_array_u8_copy_range_into(ValueBox_ArrayBox_u8 *src, size_t start, size_t end, ValueBox_ArrayBox_u8 *dst, size_t index);
+
+void boxer_array_u8_fill(ValueBox_ArrayBox_u8 *array, size_t start, size_t end, uint8_t item);
+
+void boxer_array_u8_reverse(ValueBox_ArrayBox_u8 *array);
+
+void boxer_array_u8_rotate_left(ValueBox_ArrayBox_u8 *array, size_t amount);
+
+void boxer_array_u8_rotate_right(ValueBox_ArrayBox_u8 *array, size_t amount);
+
+// Create an array that views the items within a range of a given array without copying them.
+// The array can not be resized while the view exists,
+// the view becomes invalid once the array is dropped or invalidated.
+ValueBox_ArrayBox_u8 *boxer_array_u8_sub_array(ValueBox_ArrayBox_u8 *array, size_t start, size_t end);
+
+BoxerStatus boxer_array_u8_copy_range_into_checked(ValueBox_ArrayBox_u8 *src, size_t start, size_t end, ValueBox_ArrayBox_u8 *dst, size_t index);
+
+BoxerStatus boxer_array_u8_fill_checked(ValueBox_ArrayBox_u8 *array, size_t start, size_t end, uint8_t item);
+
+BoxerStatus boxer_array_u8_rotate_left_checked(ValueBox_ArrayBox_u8 *array, size_t amount);
+
+BoxerStatus boxer_array_u8_rotate_right_checked(ValueBox_ArrayBox_u8 *array, size_t amount);
+
+BoxerStatus boxer_array_u8_copy_into_checked(ValueBox_ArrayBox_u8 *src, ValueBox_ArrayBox_u8 *dst);
+
+BoxerStatus boxer_array_u8_copy_into_data_checked(ValueBox_ArrayBox_u8 *src, uint8_t *data, size_t amount);
+
+BoxerStatus boxer_array_u8_get_length_checked(ValueBox_ArrayBox_u8 *array, size_t *length);
+
+BoxerStatus boxer_array_u8_get_capacity_checked(ValueBox_ArrayBox_u8 *array, size_t *capacity);
+
+BoxerStatus boxer_array_u8_get_data_checked(ValueBox_ArrayBox_u8 *array, uint8_t **data);
+
+BoxerStatus boxer_array_u8_at_checked(ValueBox_ArrayBox_u8 *array, size_t index, uint8_t *item);
+
+BoxerStatus boxer_array_u8_at_put_checked(ValueBox_ArrayBox_u8 *array, size_t index, uint8_t item);
+
+ValueBox_ArrayBox_u8 *boxer_array_u8_create_from_foreign_data(uint8_t *data, size_t amount, ArrayBoxRelease release, void *release_data);
+
+// Invalidate the array when its foreign data is freed or moved,
+// accessing the data of the array fails afterwards.
+void boxer_array_u8_invalidate(ValueBox_ArrayBox_u8 *array);
+
+bool boxer_array_u8_is_valid(ValueBox_ArrayBox_u8 *array);
+
+void boxer_array_u8_drop(ValueBox_ArrayBox_u8 *array);
+
+ValueBox_ArrayBox_i8 *boxer_array_i8_create(void);
+
+ValueBox_ArrayBox_i8 *boxer_array_i8_create_with(int8_t element, size_t amount);
+
+ValueBox_ArrayBox_i8 *boxer_array_i8_create_from_data(int8_t *data, size_t amount);
+
+void boxer_array_i8_copy_into(ValueBox_ArrayBox_i8 *src, ValueBox_ArrayBox_i8 *dst);
+
+void boxer_array_i8_copy_into_data(ValueBox_ArrayBox_i8 *src, int8_t *data, size_t amount);
+
+size_t boxer_array_i8_get_length(ValueBox_ArrayBox_i8 *array);
+
+size_t boxer_array_i8_get_capacity(ValueBox_ArrayBox_i8 *array);
+
+int8_t *boxer_array_i8_get_data(ValueBox_ArrayBox_i8 *array);
+
+int8_t boxer_array_i8_at(ValueBox_ArrayBox_i8 *array, size_t index);
+
+void boxer_array_i8_at_put(ValueBox_ArrayBox_i8 *array, size_t index, int8_t item);
+
+void boxer_array_i8_push(ValueBox_ArrayBox_i8 *array, int8_t item);
+
+int8_t boxer_array_i8_pop(ValueBox_ArrayBox_i8 *array);
+
+void boxer_array_i8_insert(ValueBox_ArrayBox_i8 *array, size_t index, int8_t item);
+
+int8_t boxer_array_i8_remove(ValueBox_ArrayBox_i8 *array, size_t index);
+
+void boxer_array_i8_truncate(ValueBox_ArrayBox_i8 *array, size_t length);
+
+void boxer_array_i8_resize(ValueBox_ArrayBox_i8 *array, size_t length, int8_t item);
+
+void boxer_array_i8_reserve(ValueBox_ArrayBox_i8 *array, size_t additional);
+
+void boxer_array_i8_extend_from_slice(ValueBox_ArrayBox_i8 *array, const int8_t *data, size_t amount);
+
+BoxerStatus boxer_array_i8_pop_checked(ValueBox_ArrayBox_i8 *array, int8_t *item);
+
+BoxerStatus boxer_array_i8_insert_checked(ValueBox_ArrayBox_i8 *array, size_t index, int8_t item);
+
+BoxerStatus boxer_array_i8_remove_checked(ValueBox_ArrayBox_i8 *array, size_t index, int8_t *item);
+
+void boxer_array_i8_copy_range_into(ValueBox_ArrayBox_i8 *src, size_t start, size_t end, ValueBox_ArrayBox_i8 *dst, size_t index);
+
+void boxer_array_i8_fill(ValueBox_ArrayBox_i8 *array, size_t start, size_t end, int8_t item);
+
+void boxer_array_i8_reverse(ValueBox_ArrayBox_i8 *array);
+
+void boxer_array_i8_rotate_left(ValueBox_ArrayBox_i8 *array, size_t amount);
+
+void boxer_array_i8_rotate_right(ValueBox_ArrayBox_i8 *array, size_t amount);
+
+// Create an array that views the items within a range of a given array without copying them.
+// The array can not be resized while the view exists,
+// the view becomes invalid once the array is dropped or invalidated.
+ValueBox_ArrayBox_i8 *boxer_array_i8_sub_array(ValueBox_ArrayBox_i8 *array, size_t start, size_t end);
+
+BoxerStatus boxer_array_i8_copy_range_into_checked(ValueBox_ArrayBox_i8 *src, size_t start, size_t end, ValueBox_ArrayBox_i8 *dst, size_t index);
+
+BoxerStatus boxer_array_i8_fill_checked(ValueBox_ArrayBox_i8 *array, size_t start, size_t end, int8_t item);
+
+BoxerStatus boxer_array_i8_rotate_left_checked(ValueBox_ArrayBox_i8 *array, size_t amount);
+
+BoxerStatus boxer_array_i8_rotate_right_checked(ValueBox_ArrayBox_i8 *array, size_t amount);
+
+BoxerStatus boxer_array_i8_copy_into_checked(ValueBox_ArrayBox_i8 *src, ValueBox_ArrayBox_i8 *dst);
+
+BoxerStatus boxer_array_i8_copy_into_data_checked(ValueBox_ArrayBox_i8 *src, int8_t *data, size_t amount);
+
+BoxerStatus boxer_array_i8_get_length_checked(ValueBox_ArrayBox_i8 *array, size_t *length);
+
+BoxerStatus boxer_array_i8_get_capacity_checked(ValueBox_ArrayBox_i8 *array, size_t *capacity);
+
+BoxerStatus boxer_array_i8_get_data_checked(ValueBox_ArrayBox_i8 *array, int8_t **data);
+
+BoxerStatus boxer_array_i8_at_checked(ValueBox_ArrayBox_i8 *array, size_t index, int8_t *item);
+
+BoxerStatus boxer_array_i8_at_put_checked(ValueBox_ArrayBox_i8 *array, size_t index, int8_t item);
+
+ValueBox_ArrayBox_i8 *boxer_array_i8_create_from_foreign_data(int8_t *data, size_t amount, ArrayBoxRelease release, void *release_data);
+
+// Invalidate the array when its foreign data is freed or moved,
+// accessing the data of the array fails afterwards.
+void boxer_array_i8_invalidate(ValueBox_ArrayBox_i8 *array);
+
+bool boxer_array_i8_is_valid(ValueBox_ArrayBox_i8 *array);
+
+void boxer_array_i8_drop(ValueBox_ArrayBox_i8 *array);
+
+ValueBox_ArrayBox_u16 *boxer_array_u16_create(void);
+
+ValueBox_ArrayBox_u16 *boxer_array_u16_create_with(uint16_t element, size_t amount);
+
+ValueBox_ArrayBox_u16 *boxer_array_u16_create_from_data(uint16_t *data, size_t amount);
+
+void boxer_array_u16_copy_into(ValueBox_ArrayBox_u16 *src, ValueBox_ArrayBox_u16 *dst);
+
+void boxer_array_u16_copy_into_data(ValueBox_ArrayBox_u16 *src, uint16_t *data, size_t amount);
+
+size_t boxer_array_u16_get_length(ValueBox_ArrayBox_u16 *array);
+
+size_t boxer_array_u16_get_capacity(ValueBox_ArrayBox_u16 *array);
+
+uint16_t *boxer_array_u16_get_data(ValueBox_ArrayBox_u16 *array);
+
+uint16_t boxer_array_u16_at(ValueBox_ArrayBox_u16 *array, size_t index);
+
+void boxer_array_u16_at_put(ValueBox_ArrayBox_u16 *array, size_t index, uint16_t item);
+
+void boxer_array_u16_push(ValueBox_ArrayBox_u16 *array, uint16_t item);
+
+uint16_t boxer_array_u16_pop(ValueBox_ArrayBox_u16 *array);
+
+void boxer_array_u16_insert(ValueBox_ArrayBox_u16 *array, size_t index, uint16_t item);
+
+uint16_t boxer_array_u16_remove(ValueBox_ArrayBox_u16 *array, size_t index);
+
+void boxer_array_u16_truncate(ValueBox_ArrayBox_u16 *array, size_t length);
+
+void boxer_array_u16_resize(ValueBox_ArrayBox_u16 *array, size_t length, uint16_t item);
+
+void boxer_array_u16_reserve(ValueBox_ArrayBox_u16 *array, size_t additional);
+
+void boxer_array_u16_extend_from_slice(ValueBox_ArrayBox_u16 *array, const uint16_t *data, size_t amount);
+
+BoxerStatus boxer_array_u16_pop_checked(ValueBox_ArrayBox_u16 *array, uint16_t *item);
+
+BoxerStatus boxer_array_u16_insert_checked(ValueBox_ArrayBox_u16 *array, size_t index, uint16_t item);
+
+BoxerStatus boxer_array_u16_remove_checked(ValueBox_ArrayBox_u16 *array, size_t index, uint16_t *item);
+
+void boxer_array_u16_copy_range_into(ValueBox_ArrayBox_u16 *src, size_t start, size_t end, ValueBox_ArrayBox_u16 *dst, size_t index);
+
+void boxer_array_u16_fill(ValueBox_ArrayBox_u16 *array, size_t start, size_t end, uint16_t item);
+
+void boxer_array_u16_reverse(ValueBox_ArrayBox_u16 *array);
+
+void boxer_array_u16_rotate_left(ValueBox_ArrayBox_u16 *array, size_t amount);
+
+void boxer_array_u16_rotate_right(ValueBox_ArrayBox_u16 *array, size_t amount);
+
+// Create an array that views the items within a range of a given array without copying them.
+// The array can not be resized while the view exists,
+// the view becomes invalid once the array is dropped or invalidated.
+ValueBox_ArrayBox_u16 *boxer_array_u16_sub_array(ValueBox_ArrayBox_u16 *array, size_t start, size_t end);
+
+BoxerStatus boxer_array_u16_copy_range_into_checked(ValueBox_ArrayBox_u16 *src, size_t start, size_t end, ValueBox_ArrayBox_u16 *dst, size_t index);
+
+BoxerStatus boxer_array_u16_fill_checked(ValueBox_ArrayBox_u16 *array, size_t start, size_t end, uint16_t item);
+
+BoxerStatus boxer_array_u16_rotate_left_checked(ValueBox_ArrayBox_u16 *array, size_t amount);
+
+BoxerStatus boxer_array_u16_rotate_right_checked(ValueBox_ArrayBox_u16 *array, size_t amount);
+
+BoxerStatus boxer_array_u16_copy_into_checked(ValueBox_ArrayBox_u16 *src, ValueBox_ArrayBox_u16 *dst);
+
+BoxerStatus boxer_array_u16_copy_into_data_checked(ValueBox_ArrayBox_u16 *src, uint16_t *data, size_t amount);
+
+BoxerStatus boxer_array_u16_get_length_checked(ValueBox_ArrayBox_u16 *array, size_t *length);
+
+BoxerStatus boxer_array_u16_get_capacity_checked(ValueBox_ArrayBox_u16 *array, size_t *capacity);
+
+BoxerStatus boxer_array_u16_get_data_checked(ValueBox_ArrayBox_u16 *array, uint16_t **data);
+
+BoxerStatus boxer_array_u16_at_checked(ValueBox_ArrayBox_u16 *array, size_t index, uint16_t *item);
+
+BoxerStatus boxer_array_u16_at_put_checked(ValueBox_ArrayBox_u16 *array, size_t index, uint16_t item);
+
+ValueBox_ArrayBox_u16 *boxer_array_u16_create_from_foreign_data(uint16_t *data, size_t amount, ArrayBoxRelease release, void *release_data);
+
+// Invalidate the array when its foreign data is freed or moved,
+// accessing the data of the array fails afterwards.
+void boxer_array_u16_invalidate(ValueBox_ArrayBox_u16 *array);
+
+bool boxer_array_u16_is_valid(ValueBox_ArrayBox_u16 *array);
+
+void boxer_array_u16_drop(ValueBox_ArrayBox_u16 *array);
+
+ValueBox_ArrayBox_i16 *boxer_array_i16_create(void);
+
+ValueBox_ArrayBox_i16 *boxer_array_i16_create_with(int16_t element, size_t amount);
+
+ValueBox_ArrayBox_i16 *boxer_array_i16_create_from_data(int16_t *data, size_t amount);
+
+void boxer_array_i16_copy_into(ValueBox_ArrayBox_i16 *src, ValueBox_ArrayBox_i16 *dst);
+
+void boxer_array_i16_copy_into_data(ValueBox_ArrayBox_i16 *src, int16_t *data, size_t amount);
+
+size_t boxer_array_i16_get_length(ValueBox_ArrayBox_i16 *array);
+
+size_t boxer_array_i16_get_capacity(ValueBox_ArrayBox_i16 *array);
+
+int16_t *boxer_array_i16_get_data(ValueBox_ArrayBox_i16 *array);
+
+int16_t boxer_array_i16_at(ValueBox_ArrayBox_i16 *array, size_t index);
+
+void boxer_array_i16_at_put(ValueBox_ArrayBox_i16 *array, size_t index, int16_t item);
+
+void boxer_array_i16_push(ValueBox_ArrayBox_i16 *array, int16_t item);
+
+int16_t boxer_array_i16_pop(ValueBox_ArrayBox_i16 *array);
+
+void boxer_array_i16_insert(ValueBox_ArrayBox_i16 *array, size_t index, int16_t item);
+
+int16_t boxer_array_i16_remove(ValueBox_ArrayBox_i16 *array, size_t index);
+
+void boxer_array_i16_truncate(ValueBox_ArrayBox_i16 *array, size_t length);
+
+void boxer_array_i16_resize(ValueBox_ArrayBox_i16 *array, size_t length, int16_t item);
+
+void boxer_array_i16_reserve(ValueBox_ArrayBox_i16 *array, size_t additional);
+
+void boxer_array_i16_extend_from_slice(ValueBox_ArrayBox_i16 *array, const int16_t *data, size_t amount);
+
+BoxerStatus boxer_array_i16_pop_checked(ValueBox_ArrayBox_i16 *array, int16_t *item);
+
+BoxerStatus boxer_array_i16_insert_checked(ValueBox_ArrayBox_i16 *array, size_t index, int16_t item);
+
+BoxerStatus boxer_array_i16_remove_checked(ValueBox_ArrayBox_i16 *array, size_t index, int16_t *item);
+
+void boxer_array_i16_copy_range_into(ValueBox_ArrayBox_i16 *src, size_t start, size_t end, ValueBox_ArrayBox_i16 *dst, size_t index);
+
+void boxer_array_i16_fill(ValueBox_ArrayBox_i16 *array, size_t start, size_t end, int16_t item);
+
+void boxer_array_i16_reverse(ValueBox_ArrayBox_i16 *array);
+
+void boxer_array_i16_rotate_left(ValueBox_ArrayBox_i16 *array, size_t amount);
+
+void boxer_array_i16_rotate_right(ValueBox_ArrayBox_i16 *array, size_t amount);
+
+// Create an array that views the items within a range of a given array without copying them.
+// The array can not be resized while the view exists,
+// the view becomes invalid once the array is dropped or invalidated.
+ValueBox_ArrayBox_i16 *boxer_array_i16_sub_array(ValueBox_ArrayBox_i16 *array, size_t start, size_t end);
+
+BoxerStatus boxer_array_i16_copy_range_into_checked(ValueBox_ArrayBox_i16 *src, size_t start, size_t end, ValueBox_ArrayBox_i16 *dst, size_t index);
+
+BoxerStatus boxer_array_i16_fill_checked(ValueBox_ArrayBox_i16 *array, size_t start, size_t end, int16_t item);
+
+BoxerStatus boxer_array_i16_rotate_left_checked(ValueBox_ArrayBox_i16 *array, size_t amount);
+
+BoxerStatus boxer_array_i16_rotate_right_checked(ValueBox_ArrayBox_i16 *array, size_t amount);
+
+BoxerStatus boxer_array_i16_copy_into_checked(ValueBox_ArrayBox_i16 *src, ValueBox_ArrayBox_i16 *dst);
+
+BoxerStatus boxer_array_i16_copy_into_data_checked(ValueBox_ArrayBox_i16 *src, int16_t *data, size_t amount);
+
+BoxerStatus boxer_array_i16_get_length_checked(ValueBox_ArrayBox_i16 *array, size_t *length);
+
+BoxerStatus boxer_array_i16_get_capacity_checked(ValueBox_ArrayBox_i16 *array, size_t *capacity);
+
+BoxerStatus boxer_array_i16_get_data_checked(ValueBox_ArrayBox_i16 *array, int16_t **data);
+
+BoxerStatus boxer_array_i16_at_checked(ValueBox_ArrayBox_i16 *array, size_t index, int16_t *item);
+
+BoxerStatus boxer_array_i16_at_put_checked(ValueBox_ArrayBox_i16 *array, size_t index, int16_t item);
+
+ValueBox_ArrayBox_i16 *boxer_array_i16_create_from_foreign_data(int16_t *data, size_t amount, ArrayBoxRelease release, void *release_data);
+
+// Invalidate the array when its foreign data is freed or moved,
+// accessing the data of the array fails afterwards.
+void boxer_array_i16_invalidate(ValueBox_ArrayBox_i16 *array);
+
+bool boxer_array_i16_is_valid(ValueBox_ArrayBox_i16 *array);
+
+void boxer_array_i16_drop(ValueBox_ArrayBox_i16 *array);
+
+ValueBox_ArrayBox_u32 *boxer_array_u32_create(void);
+
+ValueBox_ArrayBox_u32 *boxer_array_u32_create_with(uint32_t element, size_t amount);
+
+ValueBox_ArrayBox_u32 *boxer_array_u32_create_from_data(uint32_t *data, size_t amount);
+
+void boxer_array_u32_copy_into(ValueBox_ArrayBox_u32 *src, ValueBox_ArrayBox_u32 *dst);
+
+void boxer_array_u32_copy_into_data(ValueBox_ArrayBox_u32 *src, uint32_t *data, size_t amount);
+
+size_t boxer_array_u32_get_length(ValueBox_ArrayBox_u32 *array);
+
+size_t boxer_array_u32_get_capacity(ValueBox_ArrayBox_u32 *array);
+
+uint32_t *boxer_array_u32_get_data(ValueBox_ArrayBox_u32 *array);
+
+uint32_t boxer_array_u32_at(ValueBox_ArrayBox_u32 *array, size_t index);
+
+void boxer_array_u32_at_put(ValueBox_ArrayBox_u32 *array, size_t index, uint32_t item);
+
+void boxer_array_u32_push(ValueBox_ArrayBox_u32 *array, uint32_t item);
+
+uint32_t boxer_array_u32_pop(ValueBox_ArrayBox_u32 *array);
+
+void boxer_array_u32_insert(ValueBox_ArrayBox_u32 *array, size_t index, uint32_t item);
+
+uint32_t boxer_array_u32_remove(ValueBox_ArrayBox_u32 *array, size_t index);
+
+void boxer_array_u32_truncate(ValueBox_ArrayBox_u32 *array, size_t length);
+
+void boxer_array_u32_resize(ValueBox_ArrayBox_u32 *array, size_t length, uint32_t item);
+
+void boxer_array_u32_reserve(ValueBox_ArrayBox_u32 *array, size_t additional);
+
+void boxer_array_u32_extend_from_slice(ValueBox_ArrayBox_u32 *array, const uint32_t *data, size_t amount);
+
+BoxerStatus boxer_array_u32_pop_checked(ValueBox_ArrayBox_u32 *array, uint32_t *item);
+
+BoxerStatus boxer_array_u32_insert_checked(ValueBox_ArrayBox_u32 *array, size_t index, uint32_t item);
+
+BoxerStatus boxer_array_u32_remove_checked(ValueBox_ArrayBox_u32 *array, size_t index, uint32_t *item);
+
+void boxer_array_u32_copy_range_into(ValueBox_ArrayBox_u32 *src, size_t start, size_t end, ValueBox_ArrayBox_u32 *dst, size_t index);
+
+void boxer_array_u32_fill(ValueBox_ArrayBox_u32 *array, size_t start, size_t end, uint32_t item);
+
+void boxer_array_u32_reverse(ValueBox_ArrayBox_u32 *array);
+
+void boxer_array_u32_rotate_left(ValueBox_ArrayBox_u32 *array, size_t amount);
+
+void boxer_array_u32_rotate_right(ValueBox_ArrayBox_u32 *array, size_t amount);
+
+// Create an array that views the items within a range of a given array without copying them.
+// The array can not be resized while the view exists,
+// the view becomes invalid once the array is dropped or invalidated.
+ValueBox_ArrayBox_u32 *boxer_array_u32_sub_array(ValueBox_ArrayBox_u32 *array, size_t start, size_t end);
+
+BoxerStatus boxer_array_u32_copy_range_into_checked(ValueBox_ArrayBox_u32 *src, size_t start, size_t end, ValueBox_ArrayBox_u32 *dst, size_t index);
+
+BoxerStatus boxer_array_u32_fill_checked(ValueBox_ArrayBox_u32 *array, size_t start, size_t end, uint32_t item);
+
+BoxerStatus boxer_array_u32_rotate_left_checked(ValueBox_ArrayBox_u32 *array, size_t amount);
+
+BoxerStatus boxer_array_u32_rotate_right_checked(ValueBox_ArrayBox_u32 *array, size_t amount);
+
+BoxerStatus boxer_array_u32_copy_into_checked(ValueBox_ArrayBox_u32 *src, ValueBox_ArrayBox_u32 *dst);
+
+BoxerStatus boxer_array_u32_copy_into_data_checked(ValueBox_ArrayBox_u32 *src, uint32_t *data, size_t amount);
+
+BoxerStatus boxer_array_u32_get_length_checked(ValueBox_ArrayBox_u32 *array, size_t *length);
+
+BoxerStatus boxer_array_u32_get_capacity_checked(ValueBox_ArrayBox_u32 *array, size_t *capacity);
+
+BoxerStatus boxer_array_u32_get_data_checked(ValueBox_ArrayBox_u32 *array, uint32_t **data);
+
+BoxerStatus boxer_array_u32_at_checked(ValueBox_ArrayBox_u32 *array, size_t index, uint32_t *item);
+
+BoxerStatus boxer_array_u32_at_put_checked(ValueBox_ArrayBox_u32 *array, size_t index, uint32_t item);
+
+ValueBox_ArrayBox_u32 *boxer_array_u32_create_from_foreign_data(uint32_t *data, size_t amount, ArrayBoxRelease release, void *release_data);
+
+// Invalidate the array when its foreign data is freed or moved,
+// accessing the data of the array fails afterwards.
+void boxer_array_u32_invalidate(ValueBox_ArrayBox_u32 *array);
+
+bool boxer_array_u32_is_valid(ValueBox_ArrayBox_u32 *array);
+
+void boxer_array_u32_drop(ValueBox_ArrayBox_u32 *array);
+
+ValueBox_ArrayBox_i32 *boxer_array_i32_create(void);
+
+ValueBox_ArrayBox_i32 *boxer_array_i32_create_with(int32_t element, size_t amount);
+
+ValueBox_ArrayBox_i32 *boxer_array_i32_create_from_data(int32_t *data, size_t amount);
+
+void boxer_array_i32_copy_into(ValueBox_ArrayBox_i32 *src, ValueBox_ArrayBox_i32 *dst);
+
+void boxer_array_i32_copy_into_data(ValueBox_ArrayBox_i32 *src, int32_t *data, size_t amount);
+
+size_t boxer_array_i32_get_length(ValueBox_ArrayBox_i32 *array);
+
+size_t boxer_array_i32_get_capacity(ValueBox_ArrayBox_i32 *array);
+
+int32_t *boxer_array_i32_get_data(ValueBox_ArrayBox_i32 *array);
+
+int32_t boxer_array_i32_at(ValueBox_ArrayBox_i32 *array, size_t index);
+
+void boxer_array_i32_at_put(ValueBox_ArrayBox_i32 *array, size_t index, int32_t item);
+
+void boxer_array_i32_push(ValueBox_ArrayBox_i32 *array, int32_t item);
+
+int32_t boxer_array_i32_pop(ValueBox_ArrayBox_i32 *array);
+
+void boxer_array_i32_insert(ValueBox_ArrayBox_i32 *array, size_t index, int32_t item);
+
+int32_t boxer_array_i32_remove(ValueBox_ArrayBox_i32 *array, size_t index);
+
+void boxer_array_i32_truncate(ValueBox_ArrayBox_i32 *array, size_t length);
+
+void boxer_array_i32_resize(ValueBox_ArrayBox_i32 *array, size_t length, int32_t item);
+
+void boxer_array_i32_reserve(ValueBox_ArrayBox_i32 *array, size_t additional);
+
+void boxer_array_i32_extend_from_slice(ValueBox_ArrayBox_i32 *array, const int32_t *data, size_t amount);
+
+BoxerStatus boxer_array_i32_pop_checked(ValueBox_ArrayBox_i32 *array, int32_t *item);
+
+BoxerStatus boxer_array_i32_insert_checked(ValueBox_ArrayBox_i32 *array, size_t index, int32_t item);
+
+BoxerStatus boxer_array_i32_remove_checked(ValueBox_ArrayBox_i32 *array, size_t index, int32_t *item);
+
+void boxer_array_i32_copy_range_into(ValueBox_ArrayBox_i32 *src, size_t start, size_t end, ValueBox_ArrayBox_i32 *dst, size_t index);
+
+void boxer_array_i32_fill(ValueBox_ArrayBox_i32 *array, size_t start, size_t end, int32_t item);
+
+void boxer_array_i32_reverse(ValueBox_ArrayBox_i32 *array);
+
+void boxer_array_i32_rotate_left(ValueBox_ArrayBox_i32 *array, size_t amount);
+
+void boxer_array_i32_rotate_right(ValueBox_ArrayBox_i32 *array, size_t amount);
+
+// Create an array that views the items within a range of a given array without copying them.
+// The array can not be resized while the view exists,
+// the view becomes invalid once the array is dropped or invalidated.
+ValueBox_ArrayBox_i32 *boxer_array_i32_sub_array(ValueBox_ArrayBox_i32 *array, size_t start, size_t end);
+
+BoxerStatus boxer_array_i32_copy_range_into_checked(ValueBox_ArrayBox_i32 *src, size_t start, size_t end, ValueBox_ArrayBox_i32 *dst, size_t index);
+
+BoxerStatus boxer_array_i32_fill_checked(ValueBox_ArrayBox_i32 *array, size_t start, size_t end, int32_t item);
+
+BoxerStatus boxer_array_i32_rotate_left_checked(ValueBox_ArrayBox_i32 *array, size_t amount);
+
+BoxerStatus boxer_array_i32_rotate_right_checked(ValueBox_ArrayBox_i32 *array, size_t amount);
+
+BoxerStatus boxer_array_i32_copy_into_checked(ValueBox_ArrayBox_i32 *src, ValueBox_ArrayBox_i32 *dst);
+
+BoxerStatus boxer_array_i32_copy_into_data_checked(ValueBox_ArrayBox_i32 *src, int32_t *data, size_t amount);
+
+BoxerStatus boxer_array_i32_get_length_checked(ValueBox_ArrayBox_i32 *array, size_t *length);
+
+BoxerStatus boxer_array_i32_get_capacity_checked(ValueBox_ArrayBox_i32 *array, size_t *capacity);
+
+BoxerStatus boxer_array_i32_get_data_checked(ValueBox_ArrayBox_i32 *array, int32_t **data);
+
+BoxerStatus boxer_array_i32_at_checked(ValueBox_ArrayBox_i32 *array, size_t index, int32_t *item);
+
+BoxerStatus boxer_array_i32_at_put_checked(ValueBox_ArrayBox_i32 *array, size_t index, int32_t item);
+
+ValueBox_ArrayBox_i32 *boxer_array_i32_create_from_foreign_data(int32_t *data, size_t amount, ArrayBoxRelease release, void *release_data);
+
+// Invalidate the array when its foreign data is freed or moved,
+// accessing the data of the array fails afterwards.
+void boxer_array_i32_invalidate(ValueBox_ArrayBox_i32 *array);
+
+bool boxer_array_i32_is_valid(ValueBox_ArrayBox_i32 *array);
+
+void boxer_array_i32_drop(ValueBox_ArrayBox_i32 *array);
+
+ValueBox_ArrayBox_u64 *boxer_array_u64_create(void);
+
+ValueBox_ArrayBox_u64 *boxer_array_u64_create_with(uint64_t element, size_t amount);
+
+ValueBox_ArrayBox_u64 *boxer_array_u64_create_from_data(uint64_t *data, size_t amount);
+
+void boxer_array_u64_copy_into(ValueBox_ArrayBox_u64 *src, ValueBox_ArrayBox_u64 *dst);
+
+void boxer_array_u64_copy_into_data(ValueBox_ArrayBox_u64 *src, uint64_t *data, size_t amount);
+
+size_t boxer_array_u64_get_length(ValueBox_ArrayBox_u64 *array);
+
+size_t boxer_array_u64_get_capacity(ValueBox_ArrayBox_u64 *array);
+
+uint64_t *boxer_array_u64_get_data(ValueBox_ArrayBox_u64 *array);
+
+uint64_t boxer_array_u64_at(ValueBox_ArrayBox_u64 *array, size_t index);
+
+void boxer_array_u64_at_put(ValueBox_ArrayBox_u64 *array, size_t index, uint64_t item);
+
+void boxer_array_u64_push(ValueBox_ArrayBox_u64 *array, uint64_t item);
+
+uint64_t boxer_array_u64_pop(ValueBox_ArrayBox_u64 *array);
+
+void boxer_array_u64_insert(ValueBox_ArrayBox_u64 *array, size_t index, uint64_t item);
+
+uint64_t boxer_array_u64_remove(ValueBox_ArrayBox_u64 *array, size_t index);
+
+void boxer_array_u64_truncate(ValueBox_ArrayBox_u64 *array, size_t length);
+
+void boxer_array_u64_resize(ValueBox_ArrayBox_u64 *array, size_t length, uint64_t item);
+
+void boxer_array_u64_reserve(ValueBox_ArrayBox_u64 *array, size_t additional);
+
+void boxer_array_u64_extend_from_slice(ValueBox_ArrayBox_u64 *array, const uint64_t *data, size_t amount);
+
+BoxerStatus boxer_array_u64_pop_checked(ValueBox_ArrayBox_u64 *array, uint64_t *item);
+
+BoxerStatus boxer_array_u64_insert_checked(ValueBox_ArrayBox_u64 *array, size_t index, uint64_t item);
+
+BoxerStatus boxer_array_u64_remove_checked(ValueBox_ArrayBox_u64 *array, size_t index, uint64_t *item);
+
+void boxer_array_u64_copy_range_into(ValueBox_ArrayBox_u64 *src, size_t start, size_t end, ValueBox_ArrayBox_u64 *dst, size_t index);
+
+void boxer_array_u64_fill(ValueBox_ArrayBox_u64 *array, size_t start, size_t end, uint64_t item);
+
+void boxer_array_u64_reverse(ValueBox_ArrayBox_u64 *array);
+
+void boxer_array_u64_rotate_left(ValueBox_ArrayBox_u64 *array, size_t amount);
+
+void boxer_array_u64_rotate_right(ValueBox_ArrayBox_u64 *array, size_t amount);
+
+// Create an array that views the items within a range of a given array without copying them.
+// The array can not be resized while the view exists,
+// the view becomes invalid once the array is dropped or invalidated.
+ValueBox_ArrayBox_u64 *boxer_array_u64_sub_array(ValueBox_ArrayBox_u64 *array, size_t start, size_t end);
+
+BoxerStatus boxer_array_u64_copy_range_into_checked(ValueBox_ArrayBox_u64 *src, size_t start, size_t end, ValueBox_ArrayBox_u64 *dst, size_t index);
+
+BoxerStatus boxer_array_u64_fill_checked(ValueBox_ArrayBox_u64 *array, size_t start, size_t end, uint64_t item);
+
+BoxerStatus boxer_array_u64_rotate_left_checked(ValueBox_ArrayBox_u64 *array, size_t amount);
+
+BoxerStatus boxer_array_u64_rotate_right_checked(ValueBox_ArrayBox_u64 *array, size_t amount);
+
+BoxerStatus boxer_array_u64_copy_into_checked(ValueBox_ArrayBox_u64 *src, ValueBox_ArrayBox_u64 *dst);
+
+BoxerStatus boxer_array_u64_copy_into_data_checked(ValueBox_ArrayBox_u64 *src, uint64_t *data, size_t amount);
+
+BoxerStatus boxer_array_u64_get_length_checked(ValueBox_ArrayBox_u64 *array, size_t *length);
+
+BoxerStatus boxer_array_u64_get_capacity_checked(ValueBox_ArrayBox_u64 *array, size_t *capacity);
+
+BoxerStatus boxer_array_u64_get_data_checked(ValueBox_ArrayBox_u64 *array, uint64_t **data);
+
+BoxerStatus boxer_array_u64_at_checked(ValueBox_ArrayBox_u64 *array, size_t index, uint64_t *item);
+
+BoxerStatus boxer_array_u64_at_put_checked(ValueBox_ArrayBox_u64 *array, size_t index, uint64_t item);
+
+ValueBox_ArrayBox_u64 *boxer_array_u64_create_from_foreign_data(uint64_t *data, size_t amount, ArrayBoxRelease release, void *release_data);
+
+// Invalidate the array when its foreign data is freed or moved,
+// accessing the data of the array fails afterwards.
+void boxer_array_u64_invalidate(ValueBox_ArrayBox_u64 *array);
+
+bool boxer_array_u64_is_valid(ValueBox_ArrayBox_u64 *array);
+
+void boxer_array_u64_drop(ValueBox_ArrayBox_u64 *array);
+
+ValueBox_ArrayBox_i64 *boxer_array_i64_create(void);
+
+ValueBox_ArrayBox_i64 *boxer_array_i64_create_with(int64_t element, size_t amount);
+
+ValueBox_ArrayBox_i64 *boxer_array_i64_create_from_data(int64_t *data, size_t amount);
+
+void boxer_array_i64_copy_into(ValueBox_ArrayBox_i64 *src, ValueBox_ArrayBox_i64 *dst);
+
+void boxer_array_i64_copy_into_data(ValueBox_ArrayBox_i64 *src, int64_t *data, size_t amount);
+
+size_t boxer_array_i64_get_length(ValueBox_ArrayBox_i64 *array);
+
+size_t boxer_array_i64_get_capacity(ValueBox_ArrayBox_i64 *array);
+
+int64_t *boxer_array_i64_get_data(ValueBox_ArrayBox_i64 *array);
+
+int64_t boxer_array_i64_at(ValueBox_ArrayBox_i64 *array, size_t index);
+
+void boxer_array_i64_at_put(ValueBox_ArrayBox_i64 *array, size_t index, int64_t item);
+
+void boxer_array_i64_push(ValueBox_ArrayBox_i64 *array, int64_t item);
+
+int64_t boxer_array_i64_pop(ValueBox_ArrayBox_i64 *array);
+
+void boxer_array_i64_insert(ValueBox_ArrayBox_i64 *array, size_t index, int64_t item);
+
+int64_t boxer_array_i64_remove(ValueBox_ArrayBox_i64 *array, size_t index);
+
+void boxer_array_i64_truncate(ValueBox_ArrayBox_i64 *array, size_t length);
+
+void boxer_array_i64_resize(ValueBox_ArrayBox_i64 *array, size_t length, int64_t item);
+
+void boxer_array_i64_reserve(ValueBox_ArrayBox_i64 *array, size_t additional);
+
+void boxer_array_i64_extend_from_slice(ValueBox_ArrayBox_i64 *array, const int64_t *data, size_t amount);
+
+BoxerStatus boxer_array_i64_pop_checked(ValueBox_ArrayBox_i64 *array, int64_t *item);
+
+BoxerStatus boxer_array_i64_insert_checked(ValueBox_ArrayBox_i64 *array, size_t index, int64_t item);
+
+BoxerStatus boxer_array_i64_remove_checked(ValueBox_ArrayBox_i64 *array, size_t index, int64_t *item);
+
+void boxer_array_i64_copy_range_into(ValueBox_ArrayBox_i64 *src, size_t start, size_t end, ValueBox_ArrayBox_i64 *dst, size_t index);
+
+void boxer_array_i64_fill(ValueBox_ArrayBox_i64 *array, size_t start, size_t end, int64_t item);
+
+void boxer_array_i64_reverse(ValueBox_ArrayBox_i64 *array);
+
+void boxer_array_i64_rotate_left(ValueBox_ArrayBox_i64 *array, size_t amount);
+
+void boxer_array_i64_rotate_right(ValueBox_ArrayBox_i64 *array, size_t amount);
+
+// Create an array that views the items within a range of a given array without copying them.
+// The array can not be resized while the view exists,
+// the view becomes invalid once the array is dropped or invalidated.
+ValueBox_ArrayBox_i64 *boxer_array_i64_sub_array(ValueBox_ArrayBox_i64 *array, size_t start, size_t end);
+
+BoxerStatus boxer_array_i64_copy_range_into_checked(ValueBox_ArrayBox_i64 *src, size_t start, size_t end, ValueBox_ArrayBox_i64 *dst, size_t index);
+
+BoxerStatus boxer_array_i64_fill_checked(ValueBox_ArrayBox_i64 *array, size_t start, size_t end, int64_t item);
+
+BoxerStatus boxer_array_i64_rotate_left_checked(ValueBox_ArrayBox_i64 *array, size_t amount);
+
+BoxerStatus boxer_array_i64_rotate_right_checked(ValueBox_ArrayBox_i64 *array, size_t amount);
+
+BoxerStatus boxer_array_i64_copy_into_checked(ValueBox_ArrayBox_i64 *src, ValueBox_ArrayBox_i64 *dst);
+
+BoxerStatus boxer_array_i64_copy_into_data_checked(ValueBox_ArrayBox_i64 *src, int64_t *data, size_t amount);
+
+BoxerStatus boxer_array_i64_get_length_checked(ValueBox_ArrayBox_i64 *array, size_t *length);
+
+BoxerStatus boxer_array_i64_get_capacity_checked(ValueBox_ArrayBox_i64 *array, size_t *capacity);
+
+BoxerStatus boxer_array_i64_get_data_checked(ValueBox_ArrayBox_i64 *array, int64_t **data);
+
+BoxerStatus boxer_array_i64_at_checked(ValueBox_ArrayBox_i64 *array, size_t index, int64_t *item);
+
+BoxerStatus boxer_array_i64_at_put_checked(ValueBox_ArrayBox_i64 *array, size_t index, int64_t item);
+
+ValueBox_ArrayBox_i64 *boxer_array_i64_create_from_foreign_data(int64_t *data, size_t amount, ArrayBoxRelease release, void *release_data);
+
+// Invalidate the array when its foreign data is freed or moved,
+// accessing the data of the array fails afterwards.
+void boxer_array_i64_invalidate(ValueBox_ArrayBox_i64 *array);
+
+bool boxer_array_i64_is_valid(ValueBox_ArrayBox_i64 *array);
+
+void boxer_array_i64_drop(ValueBox_ArrayBox_i64 *array);
+
+ValueBox_ArrayBox_isize *boxer_array_isize_create(void);
+
+ValueBox_ArrayBox_isize *boxer_array_isize_create_with(intptr_t element, size_t amount);
+
+ValueBox_ArrayBox_isize *boxer_array_isize_create_from_data(intptr_t *data, size_t amount);
+
+void boxer_array_isize_copy_into(ValueBox_ArrayBox_isize *src, ValueBox_ArrayBox_isize *dst);
+
+void boxer_array_isize_copy_into_data(ValueBox_ArrayBox_isize *src, intptr_t *data, size_t amount);
+
+size_t boxer_array_isize_get_length(ValueBox_ArrayBox_isize *array);
+
+size_t boxer_array_isize_get_capacity(ValueBox_ArrayBox_isize *array);
+
+intptr_t *boxer_array_isize_get_data(ValueBox_ArrayBox_isize *array);
+
+intptr_t boxer_array_isize_at(ValueBox_ArrayBox_isize *array, size_t index);
+
+void boxer_array_isize_at_put(ValueBox_ArrayBox_isize *array, size_t index, intptr_t item);
+
+void boxer_array_isize_push(ValueBox_ArrayBox_isize *array, intptr_t item);
+
+intptr_t boxer_array_isize_pop(ValueBox_ArrayBox_isize *array);
+
+void boxer_array_isize_insert(ValueBox_ArrayBox_isize *array, size_t index, intptr_t item);
+
+intptr_t boxer_array_isize_remove(ValueBox_ArrayBox_isize *array, size_t index);
+
+void boxer_array_isize_truncate(ValueBox_ArrayBox_isize *array, size_t length);
+
+void boxer_array_isize_resize(ValueBox_ArrayBox_isize *array, size_t length, intptr_t item);
+
+void boxer_array_isize_reserve(ValueBox_ArrayBox_isize *array, size_t additional);
+
+void boxer_array_isize_extend_from_slice(ValueBox_ArrayBox_isize *array, const intptr_t *data, size_t amount);
+
+BoxerStatus boxer_array_isize_pop_checked(ValueBox_ArrayBox_isize *array, intptr_t *item);
+
+BoxerStatus boxer_array_isize_insert_checked(ValueBox_ArrayBox_isize *array, size_t index, intptr_t item);
+
+BoxerStatus boxer_array_isize_remove_checked(ValueBox_ArrayBox_isize *array, size_t index, intptr_t *item);
+
+void boxer_array_isize_copy_range_into(ValueBox_ArrayBox_isize *src, size_t start, size_t end, ValueBox_ArrayBox_isize *dst, size_t index);
+
+void boxer_array_isize_fill(ValueBox_ArrayBox_isize *array, size_t start, size_t end, intptr_t item);
+
+void boxer_array_isize_reverse(ValueBox_ArrayBox_isize *array);
+
+void boxer_array_isize_rotate_left(ValueBox_ArrayBox_isize *array, size_t amount);
+
+void boxer_array_isize_rotate_right(ValueBox_ArrayBox_isize *array, size_t amount);
+
+// Create an array that views the items within a range of a given array without copying them.
+// The array can not be resized while the view exists,
+// the view becomes invalid once the array is dropped or invalidated.
+ValueBox_ArrayBox_isize *boxer_array_isize_sub_array(ValueBox_ArrayBox_isize *array, size_t start, size_t end);
+
+BoxerStatus boxer_array_isize_copy_range_into_checked(ValueBox_ArrayBox_isize *src, size_t start, size_t end, ValueBox_ArrayBox_isize *dst, size_t index);
+
+BoxerStatus boxer_array_isize_fill_checked(ValueBox_ArrayBox_isize *array, size_t start, size_t end, intptr_t item);
+
+BoxerStatus boxer_array_isize_rotate_left_checked(ValueBox_ArrayBox_isize *array, size_t amount);
+
+BoxerStatus boxer_array_isize_rotate_right_checked(ValueBox_ArrayBox_isize *array, size_t amount);
+
+BoxerStatus boxer_array_isize_copy_into_checked(ValueBox_ArrayBox_isize *src, ValueBox_ArrayBox_isize *dst);
+
+BoxerStatus boxer_array_isize_copy_into_data_checked(ValueBox_ArrayBox_isize *src, intptr_t *data, size_t amount);
+
+BoxerStatus boxer_array_isize_get_length_checked(ValueBox_ArrayBox_isize *array, size_t *length);
+
+BoxerStatus boxer_array_isize_get_capacity_checked(ValueBox_ArrayBox_isize *array, size_t *capacity);
+
+BoxerStatus boxer_array_isize_get_data_checked(ValueBox_ArrayBox_isize *array, intptr_t **data);
+
+BoxerStatus boxer_array_isize_at_checked(ValueBox_ArrayBox_isize *array, size_t index, intptr_t *item);
+
+BoxerStatus boxer_array_isize_at_put_checked(ValueBox_ArrayBox_isize *array, size_t index, intptr_t item);
+
+ValueBox_ArrayBox_isize *boxer_array_isize_create_from_foreign_data(intptr_t *data, size_t amount, ArrayBoxRelease release, void *release_data);
+
+// Invalidate the array when its foreign data is freed or moved,
+// accessing the data of the array fails afterwards.
+void boxer_array_isize_invalidate(ValueBox_ArrayBox_isize *array);
+
+bool boxer_array_isize_is_valid(ValueBox_ArrayBox_isize *array);
+
+void boxer_array_isize_drop(ValueBox_ArrayBox_isize *array);
+
+ValueBox_ArrayBox_usize *boxer_array_usize_create(void);
+
+ValueBox_ArrayBox_usize *boxer_array_usize_create_with(size_t element, size_t amount);
+
+ValueBox_ArrayBox_usize *boxer_array_usize_create_from_data(size_t *data, size_t amount);
+
+void boxer_array_usize_copy_into(ValueBox_ArrayBox_usize *src, ValueBox_ArrayBox_usize *dst);
+
+void boxer_array_usize_copy_into_data(ValueBox_ArrayBox_usize *src, size_t *data, size_t amount);
+
+size_t boxer_array_usize_get_length(ValueBox_ArrayBox_usize *array);
+
+size_t boxer_array_usize_get_capacity(ValueBox_ArrayBox_usize *array);
+
+size_t *boxer_array_usize_get_data(ValueBox_ArrayBox_usize *array);
+
+size_t boxer_array_usize_at(ValueBox_ArrayBox_usize *array, size_t index);
+
+void boxer_array_usize_at_put(ValueBox_ArrayBox_usize *array, size_t index, size_t item);
+
+void boxer_array_usize_push(ValueBox_ArrayBox_usize *array, size_t item);
+
+size_t boxer_array_usize_pop(ValueBox_ArrayBox_usize *array);
+
+void boxer_array_usize_insert(ValueBox_ArrayBox_usize *array, size_t index, size_t item);
+
+size_t boxer_array_usize_remove(ValueBox_ArrayBox_usize *array, size_t index);
+
+void boxer_array_usize_truncate(ValueBox_ArrayBox_usize *array, size_t length);
+
+void boxer_array_usize_resize(ValueBox_ArrayBox_usize *array, size_t length, size_t item);
+
+void boxer_array_usize_reserve(ValueBox_ArrayBox_usize *array, size_t additional);
+
+void boxer_array_usize_extend_from_slice(ValueBox_ArrayBox_usize *array, const size_t *data, size_t amount);
+
+BoxerStatus boxer_array_usize_pop_checked(ValueBox_ArrayBox_usize *array, size_t *item);
+
+BoxerStatus boxer_array_usize_insert_checked(ValueBox_ArrayBox_usize *array, size_t index, size_t item);
+
+BoxerStatus boxer_array_usize_remove_checked(ValueBox_ArrayBox_usize *array, size_t index, size_t *item);
+
+void boxer_array_usize_copy_range_into(ValueBox_ArrayBox_usize *src, size_t start, size_t end, ValueBox_ArrayBox_usize *dst, size_t index);
+
+void boxer_array_usize_fill(ValueBox_ArrayBox_usize *array, size_t start, size_t end, size_t item);
+
+void boxer_array_usize_reverse(ValueBox_ArrayBox_usize *array);
+
+void boxer_array_usize_rotate_left(ValueBox_ArrayBox_usize *array, size_t amount);
+
+void boxer_array_usize_rotate_right(ValueBox_ArrayBox_usize *array, size_t amount);
+
+// Create an array that views the items within a range of a given array without copying them.
+// The array can not be resized while the view exists,
+// the view becomes invalid once the array is dropped or invalidated.
+ValueBox_ArrayBox_usize *boxer_array_usize_sub_array(ValueBox_ArrayBox_usize *array, size_t start, size_t end);
+
+BoxerStatus boxer_array_usize_copy_range_into_checked(ValueBox_ArrayBox_usize *src, size_t start, size_t end, ValueBox_ArrayBox_usize *dst, size_t index);
+
+BoxerStatus boxer_array_usize_fill_checked(ValueBox_ArrayBox_usize *array, size_t start, size_t end, size_t item);
+
+BoxerStatus boxer_array_usize_rotate_left_checked(ValueBox_ArrayBox_usize *array, size_t amount);
+
+BoxerStatus boxer_array_usize_rotate_right_checked(ValueBox_ArrayBox_usize *array, size_t amount);
+
+BoxerStatus boxer_array_usize_copy_into_checked(ValueBox_ArrayBox_usize *src, ValueBox_ArrayBox_usize *dst);
+
+BoxerStatus boxer_array_usize_copy_into_data_checked(ValueBox_ArrayBox_usize *src, size_t *data, size_t amount);
+
+BoxerStatus boxer_array_usize_get_length_checked(ValueBox_ArrayBox_usize *array, size_t *length);
+
+BoxerStatus boxer_array_usize_get_capacity_checked(ValueBox_ArrayBox_usize *array, size_t *capacity);
+
+BoxerStatus boxer_array_usize_get_data_checked(ValueBox_ArrayBox_usize *array, size_t **data);
+
+BoxerStatus boxer_array_usize_at_checked(ValueBox_ArrayBox_usize *array, size_t index, size_t *item);
+
+BoxerStatus boxer_array_usize_at_put_checked(ValueBox_ArrayBox_usize *array, size_t index, size_t item);
+
+ValueBox_ArrayBox_usize *boxer_array_usize_create_from_foreign_data(size_t *data, size_t amount, ArrayBoxRelease release, void *release_data);
+
+// Invalidate the array when its foreign data is freed or moved,
+// accessing the data of the array fails afterwards.
+void boxer_array_usize_invalidate(ValueBox_ArrayBox_usize *array);
+
+bool boxer_array_usize_is_valid(ValueBox_ArrayBox_usize *array);
+
+void boxer_array_usize_drop(ValueBox_ArrayBox_usize *array);
+
+ValueBox_ArrayBox_c_int *boxer_array_int_create(void);
+
+ValueBox_ArrayBox_c_int *boxer_array_int_create_with(int element, size_t amount);
+
+ValueBox_ArrayBox_c_int *boxer_array_int_create_from_data(int *data, size_t amount);
+
+void boxer_array_int_copy_into(ValueBox_ArrayBox_c_int *src, ValueBox_ArrayBox_c_int *dst);
+
+void boxer_array_int_copy_into_data(ValueBox_ArrayBox_c_int *src, int *data, size_t amount);
+
+size_t boxer_array_int_get_length(ValueBox_ArrayBox_c_int *array);
+
+size_t boxer_array_int_get_capacity(ValueBox_ArrayBox_c_int *array);
+
+int *boxer_array_int_get_data(ValueBox_ArrayBox_c_int *array);
+
+int boxer_array_int_at(ValueBox_ArrayBox_c_int *array, size_t index);
+
+void boxer_array_int_at_put(ValueBox_ArrayBox_c_int *array, size_t index, int item);
+
+void boxer_array_int_push(ValueBox_ArrayBox_c_int *array, int item);
+
+int boxer_array_int_pop(ValueBox_ArrayBox_c_int *array);
+
+void boxer_array_int_insert(ValueBox_ArrayBox_c_int *array, size_t index, int item);
+
+int boxer_array_int_remove(ValueBox_ArrayBox_c_int *array, size_t index);
+
+void boxer_array_int_truncate(ValueBox_ArrayBox_c_int *array, size_t length);
+
+void boxer_array_int_resize(ValueBox_ArrayBox_c_int *array, size_t length, int item);
+
+void boxer_array_int_reserve(ValueBox_ArrayBox_c_int *array, size_t additional);
+
+void boxer_array_int_extend_from_slice(ValueBox_ArrayBox_c_int *array, const int *data, size_t amount);
+
+BoxerStatus boxer_array_int_pop_checked(ValueBox_ArrayBox_c_int *array, int *item);
+
+BoxerStatus boxer_array_int_insert_checked(ValueBox_ArrayBox_c_int *array, size_t index, int item);
+
+BoxerStatus boxer_array_int_remove_checked(ValueBox_ArrayBox_c_int *array, size_t index, int *item);
+
+void boxer_array_int_copy_range_into(ValueBox_ArrayBox_c_int *src, size_t start, size_t end, ValueBox_ArrayBox_c_int *dst, size_t index);
+
+void boxer_array_int_fill(ValueBox_ArrayBox_c_int *array, size_t start, size_t end, int item);
+
+void boxer_array_int_reverse(ValueBox_ArrayBox_c_int *array);
+
+void boxer_array_int_rotate_left(ValueBox_ArrayBox_c_int *array, size_t amount);
+
+void boxer_array_int_rotate_right(ValueBox_ArrayBox_c_int *array, size_t amount);
+
+// Create an array that views the items within a range of a given array without copying them.
+// The array can not be resized while the view exists,
+// the view becomes invalid once the array is dropped or invalidated.
+ValueBox_ArrayBox_c_int *boxer_array_int_sub_array(ValueBox_ArrayBox_c_int *array, size_t start, size_t end);
+
+BoxerStatus boxer_array_int_copy_range_into_checked(ValueBox_ArrayBox_c_int *src, size_t start, size_t end, ValueBox_ArrayBox_c_int *dst, size_t index);
+
+BoxerStatus boxer_array_int_fill_checked(ValueBox_ArrayBox_c_int *array, size_t start, size_t end, int item);
+
+BoxerStatus boxer_array_int_rotate_left_checked(ValueBox_ArrayBox_c_int *array, size_t amount);
+
+BoxerStatus boxer_array_int_rotate_right_checked(ValueBox_ArrayBox_c_int *array, size_t amount);
+
+BoxerStatus boxer_array_int_copy_into_checked(ValueBox_ArrayBox_c_int *src, ValueBox_ArrayBox_c_int *dst);
+
+BoxerStatus boxer_array_int_copy_into_data_checked(ValueBox_ArrayBox_c_int *src, int *data, size_t amount);
+
+BoxerStatus boxer_array_int_get_length_checked(ValueBox_ArrayBox_c_int *array, size_t *length);
+
+BoxerStatus boxer_array_int_get_capacity_checked(ValueBox_ArrayBox_c_int *array, size_t *capacity);
+
+BoxerStatus boxer_array_int_get_data_checked(ValueBox_ArrayBox_c_int *array, int **data);
+
+BoxerStatus boxer_array_int_at_checked(ValueBox_ArrayBox_c_int *array, size_t index, int *item);
+
+BoxerStatus boxer_array_int_at_put_checked(ValueBox_ArrayBox_c_int *array, size_t index, int item);
+
+ValueBox_ArrayBox_c_int *boxer_array_int_create_from_foreign_data(int *data, size_t amount, ArrayBoxRelease release, void *release_data);
+
+// Invalidate the array when its foreign data is freed or moved,
+// accessing the data of the array fails afterwards.
+void boxer_array_int_invalidate(ValueBox_ArrayBox_c_int *array);
+
+bool boxer_array_int_is_valid(ValueBox_ArrayBox_c_int *array);
+
+void boxer_array_int_drop(ValueBox_ArrayBox_c_int *array);
+
+ValueBox_ArrayBox_c_uint *boxer_array_uint_create(void);
+
+ValueBox_ArrayBox_c_uint *boxer_array_uint_create_with(unsigned int element, size_t amount);
+
+ValueBox_ArrayBox_c_uint *boxer_array_uint_create_from_data(unsigned int *data, size_t amount);
+
+void boxer_array_uint_copy_into(ValueBox_ArrayBox_c_uint *src, ValueBox_ArrayBox_c_uint *dst);
+
+void boxer_array_uint_copy_into_data(ValueBox_ArrayBox_c_uint *src, unsigned int *data, size_t amount);
+
+size_t boxer_array_uint_get_length(ValueBox_ArrayBox_c_uint *array);
+
+size_t boxer_array_uint_get_capacity(ValueBox_ArrayBox_c_uint *array);
+
+unsigned int *boxer_array_uint_get_data(ValueBox_ArrayBox_c_uint *array);
+
+unsigned int boxer_array_uint_at(ValueBox_ArrayBox_c_uint *array, size_t index);
+
+void boxer_array_uint_at_put(ValueBox_ArrayBox_c_uint *array, size_t index, unsigned int item);
+
+void boxer_array_uint_push(ValueBox_ArrayBox_c_uint *array, unsigned int item);
+
+unsigned int boxer_array_uint_pop(ValueBox_ArrayBox_c_uint *array);
+
+void boxer_array_uint_insert(ValueBox_ArrayBox_c_uint *array, size_t index, unsigned int item);
+
+unsigned int boxer_array_uint_remove(ValueBox_ArrayBox_c_uint *array, size_t index);
+
+void boxer_array_uint_truncate(ValueBox_ArrayBox_c_uint *array, size_t length);
+
+void boxer_array_uint_resize(ValueBox_ArrayBox_c_uint *array, size_t length, unsigned int item);
+
+void boxer_array_uint_reserve(ValueBox_ArrayBox_c_uint *array, size_t additional);
+
+void boxer_array_uint_extend_from_slice(ValueBox_ArrayBox_c_uint *array, const unsigned int *data, size_t amount);
+
+BoxerStatus boxer_array_uint_pop_checked(ValueBox_ArrayBox_c_uint *array, unsigned int *item);
+
+BoxerStatus boxer_array_uint_insert_checked(ValueBox_ArrayBox_c_uint *array, size_t index, unsigned int item);
+
+BoxerStatus boxer_array_uint_remove_checked(ValueBox_ArrayBox_c_uint *array, size_t index, unsigned int *item);
+
+void boxer_array_uint_copy_range_into(ValueBox_ArrayBox_c_uint *src, size_t start, size_t end, ValueBox_ArrayBox_c_uint *dst, size_t index);
+
+void boxer_array_uint_fill(ValueBox_ArrayBox_c_uint *array, size_t start, size_t end, unsigned int item);
+
+void boxer_array_uint_reverse(ValueBox_ArrayBox_c_uint *array);
+
+void boxer_array_uint_rotate_left(ValueBox_ArrayBox_c_uint *array, size_t amount);
+
+void boxer_array_uint_rotate_right(ValueBox_ArrayBox_c_uint *array, size_t amount);
+
+// Create an array that views the items within a range of a given array without copying them.
+// The array can not be resized while the view exists,
+// the view becomes invalid once the array is dropped or invalidated.
+ValueBox_ArrayBox_c_uint *boxer_array_uint_sub_array(ValueBox_ArrayBox_c_uint *array, size_t start, size_t end);
+
+BoxerStatus boxer_array_uint_copy_range_into_checked(ValueBox_ArrayBox_c_uint *src, size_t start, size_t end, ValueBox_ArrayBox_c_uint *dst, size_t index);
+
+BoxerStatus boxer_array_uint_fill_checked(ValueBox_ArrayBox_c_uint *array, size_t start, size_t end, unsigned int item);
+
+BoxerStatus boxer_array_uint_rotate_left_checked(ValueBox_ArrayBox_c_uint *array, size_t amount);
+
+BoxerStatus boxer_array_uint_rotate_right_checked(ValueBox_ArrayBox_c_uint *array, size_t amount);
+
+BoxerStatus boxer_array_uint_copy_into_checked(ValueBox_ArrayBox_c_uint *src, ValueBox_ArrayBox_c_uint *dst);
+
+BoxerStatus boxer_array_uint_copy_into_data_checked(ValueBox_ArrayBox_c_uint *src, unsigned int *data, size_t amount);
+
+BoxerStatus boxer_array_uint_get_length_checked(ValueBox_ArrayBox_c_uint *array, size_t *length);
+
+BoxerStatus boxer_array_uint_get_capacity_checked(ValueBox_ArrayBox_c_uint *array, size_t *capacity);
+
+BoxerStatus boxer_array_uint_get_data_checked(ValueBox_ArrayBox_c_uint *array, unsigned int **data);
+
+BoxerStatus boxer_array_uint_at_checked(ValueBox_ArrayBox_c_uint *array, size_t index, unsigned int *item);
+
+BoxerStatus boxer_array_uint_at_put_checked(ValueBox_ArrayBox_c_uint *array, size_t index, unsigned int item);
+
+ValueBox_ArrayBox_c_uint *boxer_array_uint_create_from_foreign_data(unsigned int *data, size_t amount, ArrayBoxRelease release, void *release_data);
+
+// Invalidate the array when its foreign data is freed or moved,
+// accessing the data of the array fails afterwards.
+void boxer_array_uint_invalidate(ValueBox_ArrayBox_c_uint *array);
+
+bool boxer_array_uint_is_valid(ValueBox_ArrayBox_c_uint *array);
+
+void boxer_array_uint_drop(ValueBox_ArrayBox_c_uint *array);
+
+ValueBox_ArrayBox_f32 *boxer_array_f32_create(void);
+
+ValueBox_ArrayBox_f32 *boxer_array_f32_create_with(float element, size_t amount);
+
+ValueBox_ArrayBox_f32 *boxer_array_f32_create_from_data(float *data, size_t amount);
+
+void boxer_array_f32_copy_into(ValueBox_ArrayBox_f32 *src, ValueBox_ArrayBox_f32 *dst);
+
+void boxer_array_f32_copy_into_data(ValueBox_ArrayBox_f32 *src, float *data, size_t amount);
+
+size_t boxer_array_f32_get_length(ValueBox_ArrayBox_f32 *array);
+
+size_t boxer_array_f32_get_capacity(ValueBox_ArrayBox_f32 *array);
+
+float *boxer_array_f32_get_data(ValueBox_ArrayBox_f32 *array);
+
+float boxer_array_f32_at(ValueBox_ArrayBox_f32 *array, size_t index);
+
+void boxer_array_f32_at_put(ValueBox_ArrayBox_f32 *array, size_t index, float item);
+
+void boxer_array_f32_push(ValueBox_ArrayBox_f32 *array, float item);
+
+float boxer_array_f32_pop(ValueBox_ArrayBox_f32 *array);
+
+void boxer_array_f32_insert(ValueBox_ArrayBox_f32 *array, size_t index, float item);
+
+float boxer_array_f32_remove(ValueBox_ArrayBox_f32 *array, size_t index);
+
+void boxer_array_f32_truncate(ValueBox_ArrayBox_f32 *array, size_t length);
+
+void boxer_array_f32_resize(ValueBox_ArrayBox_f32 *array, size_t length, float item);
+
+void boxer_array_f32_reserve(ValueBox_ArrayBox_f32 *array, size_t additional);
+
+void boxer_array_f32_extend_from_slice(ValueBox_ArrayBox_f32 *array, const float *data, size_t amount);
+
+BoxerStatus boxer_array_f32_pop_checked(ValueBox_ArrayBox_f32 *array, float *item);
+
+BoxerStatus boxer_array_f32_insert_checked(ValueBox_ArrayBox_f32 *array, size_t index, float item);
+
+BoxerStatus boxer_array_f32_remove_checked(ValueBox_ArrayBox_f32 *array, size_t index, float *item);
+
+void boxer_array_f32_copy_range_into(ValueBox_ArrayBox_f32 *src, size_t start, size_t end, ValueBox_ArrayBox_f32 *dst, size_t index);
+
+void boxer_array_f32_fill(ValueBox_ArrayBox_f32 *array, size_t start, size_t end, float item);
+
+void boxer_array_f32_reverse(ValueBox_ArrayBox_f32 *array);
+
+void boxer_array_f32_rotate_left(ValueBox_ArrayBox_f32 *array, size_t amount);
+
+void boxer_array_f32_rotate_right(ValueBox_ArrayBox_f32 *array, size_t amount);
+
+// Create an array that views the items within a range of a given array without copying them.
+// The array can not be resized while the view exists,
+// the view becomes invalid once the array is dropped or invalidated.
+ValueBox_ArrayBox_f32 *boxer_array_f32_sub_array(ValueBox_ArrayBox_f32 *array, size_t start, size_t end);
+
+BoxerStatus boxer_array_f32_copy_range_into_checked(ValueBox_ArrayBox_f32 *src, size_t start, size_t end, ValueBox_ArrayBox_f32 *dst, size_t index);
+
+BoxerStatus boxer_array_f32_fill_checked(ValueBox_ArrayBox_f32 *array, size_t start, size_t end, float item);
+
+BoxerStatus boxer_array_f32_rotate_left_checked(ValueBox_ArrayBox_f32 *array, size_t amount);
+
+BoxerStatus boxer_array_f32_rotate_right_checked(ValueBox_ArrayBox_f32 *array, size_t amount);
+
+BoxerStatus boxer_array_f32_copy_into_checked(ValueBox_ArrayBox_f32 *src, ValueBox_ArrayBox_f32 *dst);
+
+BoxerStatus boxer_array_f32_copy_into_data_checked(ValueBox_ArrayBox_f32 *src, float *data, size_t amount);
+
+BoxerStatus boxer_array_f32_get_length_checked(ValueBox_ArrayBox_f32 *array, size_t *length);
+
+BoxerStatus boxer_array_f32_get_capacity_checked(ValueBox_ArrayBox_f32 *array, size_t *capacity);
+
+BoxerStatus boxer_array_f32_get_data_checked(ValueBox_ArrayBox_f32 *array, float **data);
+
+BoxerStatus boxer_array_f32_at_checked(ValueBox_ArrayBox_f32 *array, size_t index, float *item);
+
+BoxerStatus boxer_array_f32_at_put_checked(ValueBox_ArrayBox_f32 *array, size_t index, float item);
+
+ValueBox_ArrayBox_f32 *boxer_array_f32_create_from_foreign_data(float *data, size_t amount, ArrayBoxRelease release, void *release_data);
+
+// Invalidate the array when its foreign data is freed or moved,
+// accessing the data of the array fails afterwards.
+void boxer_array_f32_invalidate(ValueBox_ArrayBox_f32 *array);
+
+bool boxer_array_f32_is_valid(ValueBox_ArrayBox_f32 *array);
+
+void boxer_array_f32_drop(ValueBox_ArrayBox_f32 *array);
+
+ValueBox_ArrayBox_f64 *boxer_array_f64_create(void);
+
+ValueBox_ArrayBox_f64 *boxer_array_f64_create_with(double element, size_t amount);
+
+ValueBox_ArrayBox_f64 *boxer_array_f64_create_from_data(double *data, size_t amount);
+
+void boxer_array_f64_copy_into(ValueBox_ArrayBox_f64 *src, ValueBox_ArrayBox_f64 *dst);
+
+void boxer_array_f64_copy_into_data(ValueBox_ArrayBox_f64 *src, double *data, size_t amount);
+
+size_t boxer_array_f64_get_length(ValueBox_ArrayBox_f64 *array);
+
+size_t boxer_array_f64_get_capacity(ValueBox_ArrayBox_f64 *array);
+
+double *boxer_array_f64_get_data(ValueBox_ArrayBox_f64 *array);
+
+double boxer_array_f64_at(ValueBox_ArrayBox_f64 *array, size_t index);
+
+void boxer_array_f64_at_put(ValueBox_ArrayBox_f64 *array, size_t index, double item);
+
+void boxer_array_f64_push(ValueBox_ArrayBox_f64 *array, double item);
+
+double boxer_array_f64_pop(ValueBox_ArrayBox_f64 *array);
+
+void boxer_array_f64_insert(ValueBox_ArrayBox_f64 *array, size_t index, double item);
+
+double boxer_array_f64_remove(ValueBox_ArrayBox_f64 *array, size_t index);
+
+void boxer_array_f64_truncate(ValueBox_ArrayBox_f64 *array, size_t length);
+
+void boxer_array_f64_resize(ValueBox_ArrayBox_f64 *array, size_t length, double item);
+
+void boxer_array_f64_reserve(ValueBox_ArrayBox_f64 *array, size_t additional);
+
+void boxer_array_f64_extend_from_slice(ValueBox_ArrayBox_f64 *array, const double *data, size_t amount);
+
+BoxerStatus boxer_array_f64_pop_checked(ValueBox_ArrayBox_f64 *array, double *item);
+
+BoxerStatus boxer_array_f64_insert_checked(ValueBox_ArrayBox_f64 *array, size_t index, double item);
+
+BoxerStatus boxer_array_f64_remove_checked(ValueBox_ArrayBox_f64 *array, size_t index, double *item);
+
+void boxer_array_f64_copy_range_into(ValueBox_ArrayBox_f64 *src, size_t start, size_t end, ValueBox_ArrayBox_f64 *dst, size_t index);
+
+void boxer_array_f64_fill(ValueBox_ArrayBox_f64 *array, size_t start, size_t end, double item);
+
+void boxer_array_f64_reverse(ValueBox_ArrayBox_f64 *array);
+
+void boxer_array_f64_rotate_left(ValueBox_ArrayBox_f64 *array, size_t amount);
+
+void boxer_array_f64_rotate_right(ValueBox_ArrayBox_f64 *array, size_t amount);
+
+// Create an array that views the items within a range of a given array without copying them.
+// The array can not be resized while the view exists,
+// the view becomes invalid once the array is dropped or invalidated.
+ValueBox_ArrayBox_f64 *boxer_array_f64_sub_array(ValueBox_ArrayBox_f64 *array, size_t start, size_t end);
+
+BoxerStatus boxer_array_f64_copy_range_into_checked(ValueBox_ArrayBox_f64 *src, size_t start, size_t end, ValueBox_ArrayBox_f64 *dst, size_t index);
+
+BoxerStatus boxer_array_f64_fill_checked(ValueBox_ArrayBox_f64 *array, size_t start, size_t end, double item);
+
+BoxerStatus boxer_array_f64_rotate_left_checked(ValueBox_ArrayBox_f64 *array, size_t amount);
+
+BoxerStatus boxer_array_f64_rotate_right_checked(ValueBox_ArrayBox_f64 *array, size_t amount);
+
+BoxerStatus boxer_array_f64_copy_into_checked(ValueBox_ArrayBox_f64 *src, ValueBox_ArrayBox_f64 *dst);
+
+BoxerStatus boxer_array_f64_copy_into_data_checked(ValueBox_ArrayBox_f64 *src, double *data, size_t amount);
+
+BoxerStatus boxer_array_f64_get_length_checked(ValueBox_ArrayBox_f64 *array, size_t *length);
+
+BoxerStatus boxer_array_f64_get_capacity_checked(ValueBox_ArrayBox_f64 *array, size_t *capacity);
+
+BoxerStatus boxer_array_f64_get_data_checked(ValueBox_ArrayBox_f64 *array, double **data);
+
+BoxerStatus boxer_array_f64_at_checked(ValueBox_ArrayBox_f64 *array, size_t index, double *item);
+
+BoxerStatus boxer_array_f64_at_put_checked(ValueBox_ArrayBox_f64 *array, size_t index, double item);
+
+ValueBox_ArrayBox_f64 *boxer_array_f64_create_from_foreign_data(double *data, size_t amount, ArrayBoxRelease release, void *release_data);
+
+// Invalidate the array when its foreign data is freed or moved,
+// accessing the data of the array fails afterwards.
+void boxer_array_f64_invalidate(ValueBox_ArrayBox_f64 *array);
+
+bool boxer_array_f64_is_valid(ValueBox_ArrayBox_f64 *array);
+
+void boxer_array_f64_drop(ValueBox_ArrayBox_f64 *array);
+
+void boxer_array_u8_add(ValueBox_ArrayBox_u8 *array, ValueBox_ArrayBox_u8 *another_array);
+
+void boxer_array_u8_sub(ValueBox_ArrayBox_u8 *array, ValueBox_ArrayBox_u8 *another_array);
+
+void boxer_array_u8_mul(ValueBox_ArrayBox_u8 *array, ValueBox_ArrayBox_u8 *another_array);
+
+void boxer_array_u8_add_scalar(ValueBox_ArrayBox_u8 *array, uint8_t scalar);
+
+void boxer_array_u8_sub_scalar(ValueBox_ArrayBox_u8 *array, uint8_t scalar);
+
+void boxer_array_u8_scale(ValueBox_ArrayBox_u8 *array, uint8_t factor);
+
+void boxer_array_u8_clamp(ValueBox_ArrayBox_u8 *array, uint8_t min, uint8_t max);
+
+double boxer_array_u8_sum(ValueBox_ArrayBox_u8 *array);
+
+double boxer_array_u8_mean(ValueBox_ArrayBox_u8 *array);
+
+uint8_t boxer_array_u8_min(ValueBox_ArrayBox_u8 *array);
+
+uint8_t boxer_array_u8_max(ValueBox_ArrayBox_u8 *array);
+
+double boxer_array_u8_dot(ValueBox_ArrayBox_u8 *array, ValueBox_ArrayBox_u8 *another_array);
+
+BoxerStatus boxer_array_u8_add_checked(ValueBox_ArrayBox_u8 *array, ValueBox_ArrayBox_u8 *another_array);
+
+BoxerStatus boxer_array_u8_sub_checked(ValueBox_ArrayBox_u8 *array, ValueBox_ArrayBox_u8 *another_array);
+
+BoxerStatus boxer_array_u8_mul_checked(ValueBox_ArrayBox_u8 *array, ValueBox_ArrayBox_u8 *another_array);
+
+BoxerStatus boxer_array_u8_clamp_checked(ValueBox_ArrayBox_u8 *array, uint8_t min, uint8_t max);
+
+BoxerStatus boxer_array_u8_mean_checked(ValueBox_ArrayBox_u8 *array, double *mean);
+
+BoxerStatus boxer_array_u8_min_checked(ValueBox_ArrayBox_u8 *array, uint8_t *min);
+
+BoxerStatus boxer_array_u8_max_checked(ValueBox_ArrayBox_u8 *array, uint8_t *max);
+
+BoxerStatus boxer_array_u8_dot_checked(ValueBox_ArrayBox_u8 *array, ValueBox_ArrayBox_u8 *another_array, double *dot);
+
+void boxer_array_i8_add(ValueBox_ArrayBox_i8 *array, ValueBox_ArrayBox_i8 *another_array);
+
+void boxer_array_i8_sub(ValueBox_ArrayBox_i8 *array, ValueBox_ArrayBox_i8 *another_array);
+
+void boxer_array_i8_mul(ValueBox_ArrayBox_i8 *array, ValueBox_ArrayBox_i8 *another_array);
+
+void boxer_array_i8_add_scalar(ValueBox_ArrayBox_i8 *array, int8_t scalar);
+
+void boxer_array_i8_sub_scalar(ValueBox_ArrayBox_i8 *array, int8_t scalar);
+
+void boxer_array_i8_scale(ValueBox_ArrayBox_i8 *array, int8_t factor);
+
+void boxer_array_i8_clamp(ValueBox_ArrayBox_i8 *array, int8_t min, int8_t max);
+
+double boxer_array_i8_sum(ValueBox_ArrayBox_i8 *array);
+
+double boxer_array_i8_mean(ValueBox_ArrayBox_i8 *array);
+
+int8_t boxer_array_i8_min(ValueBox_ArrayBox_i8 *array);
+
+int8_t boxer_array_i8_max(ValueBox_ArrayBox_i8 *array);
+
+double boxer_array_i8_dot(ValueBox_ArrayBox_i8 *array, ValueBox_ArrayBox_i8 *another_array);
+
+BoxerStatus boxer_array_i8_add_checked(ValueBox_ArrayBox_i8 *array, ValueBox_ArrayBox_i8 *another_array);
+
+BoxerStatus boxer_array_i8_sub_checked(ValueBox_ArrayBox_i8 *array, ValueBox_ArrayBox_i8 *another_array);
+
+BoxerStatus boxer_array_i8_mul_checked(ValueBox_ArrayBox_i8 *array, ValueBox_ArrayBox_i8 *another_array);
+
+BoxerStatus boxer_array_i8_clamp_checked(ValueBox_ArrayBox_i8 *array, int8_t min, int8_t max);
+
+BoxerStatus boxer_array_i8_mean_checked(ValueBox_ArrayBox_i8 *array, double *mean);
+
+BoxerStatus boxer_array_i8_min_checked(ValueBox_ArrayBox_i8 *array, int8_t *min);
+
+BoxerStatus boxer_array_i8_max_checked(ValueBox_ArrayBox_i8 *array, int8_t *max);
+
+BoxerStatus boxer_array_i8_dot_checked(ValueBox_ArrayBox_i8 *array, ValueBox_ArrayBox_i8 *another_array, double *dot);
+
+void boxer_array_u16_add(ValueBox_ArrayBox_u16 *array, ValueBox_ArrayBox_u16 *another_array);
+
+void boxer_array_u16_sub(ValueBox_ArrayBox_u16 *array, ValueBox_ArrayBox_u16 *another_array);
+
+void boxer_array_u16_mul(ValueBox_ArrayBox_u16 *array, ValueBox_ArrayBox_u16 *another_array);
+
+void boxer_array_u16_add_scalar(ValueBox_ArrayBox_u16 *array, uint16_t scalar);
+
+void boxer_array_u16_sub_scalar(ValueBox_ArrayBox_u16 *array, uint16_t scalar);
+
+void boxer_array_u16_scale(ValueBox_ArrayBox_u16 *array, uint16_t factor);
+
+void boxer_array_u16_clamp(ValueBox_ArrayBox_u16 *array, uint16_t min, uint16_t max);
+
+double boxer_array_u16_sum(ValueBox_ArrayBox_u16 *array);
+
+double boxer_array_u16_mean(ValueBox_ArrayBox_u16 *array);
+
+uint16_t boxer_array_u16_min(ValueBox_ArrayBox_u16 *array);
+
+uint16_t boxer_array_u16_max(ValueBox_ArrayBox_u16 *array);
+
+double boxer_array_u16_dot(ValueBox_ArrayBox_u16 *array, ValueBox_ArrayBox_u16 *another_array);
+
+BoxerStatus boxer_array_u16_add_checked(ValueBox_ArrayBox_u16 *array, ValueBox_ArrayBox_u16 *another_array);
+
+BoxerStatus boxer_array_u16_sub_checked(ValueBox_ArrayBox_u16 *array, ValueBox_ArrayBox_u16 *another_array);
+
+BoxerStatus boxer_array_u16_mul_checked(ValueBox_ArrayBox_u16 *array, ValueBox_ArrayBox_u16 *another_array);
+
+BoxerStatus boxer_array_u16_clamp_checked(ValueBox_ArrayBox_u16 *array, uint16_t min, uint16_t max);
+
+BoxerStatus boxer_array_u16_mean_checked(ValueBox_ArrayBox_u16 *array, double *mean);
+
+BoxerStatus boxer_array_u16_min_checked(ValueBox_ArrayBox_u16 *array, uint16_t *min);
+
+BoxerStatus boxer_array_u16_max_checked(ValueBox_ArrayBox_u16 *array, uint16_t *max);
+
+BoxerStatus boxer_array_u16_dot_checked(ValueBox_ArrayBox_u16 *array, ValueBox_ArrayBox_u16 *another_array, double *dot);
+
+void boxer_array_i16_add(ValueBox_ArrayBox_i16 *array, ValueBox_ArrayBox_i16 *another_array);
+
+void boxer_array_i16_sub(ValueBox_ArrayBox_i16 *array, ValueBox_ArrayBox_i16 *another_array);
+
+void boxer_array_i16_mul(ValueBox_ArrayBox_i16 *array, ValueBox_ArrayBox_i16 *another_array);
+
+void boxer_array_i16_add_scalar(ValueBox_ArrayBox_i16 *array, int16_t scalar);
+
+void boxer_array_i16_sub_scalar(ValueBox_ArrayBox_i16 *array, int16_t scalar);
+
+void boxer_array_i16_scale(ValueBox_ArrayBox_i16 *array, int16_t factor);
+
+void boxer_array_i16_clamp(ValueBox_ArrayBox_i16 *array, int16_t min, int16_t max);
+
+double boxer_array_i16_sum(ValueBox_ArrayBox_i16 *array);
+
+double boxer_array_i16_mean(ValueBox_ArrayBox_i16 *array);
+
+int16_t boxer_array_i16_min(ValueBox_ArrayBox_i16 *array);
+
+int16_t boxer_array_i16_max(ValueBox_ArrayBox_i16 *array);
+
+double boxer_array_i16_dot(ValueBox_ArrayBox_i16 *array, ValueBox_ArrayBox_i16 *another_array);
+
+BoxerStatus boxer_array_i16_add_checked(ValueBox_ArrayBox_i16 *array, ValueBox_ArrayBox_i16 *another_array);
+
+BoxerStatus boxer_array_i16_sub_checked(ValueBox_ArrayBox_i16 *array, ValueBox_ArrayBox_i16 *another_array);
+
+BoxerStatus boxer_array_i16_mul_checked(ValueBox_ArrayBox_i16 *array, ValueBox_ArrayBox_i16 *another_array);
+
+BoxerStatus boxer_array_i16_clamp_checked(ValueBox_ArrayBox_i16 *array, int16_t min, int16_t max);
+
+BoxerStatus boxer_array_i16_mean_checked(ValueBox_ArrayBox_i16 *array, double *mean);
+
+BoxerStatus boxer_array_i16_min_checked(ValueBox_ArrayBox_i16 *array, int16_t *min);
+
+BoxerStatus boxer_array_i16_max_checked(ValueBox_ArrayBox_i16 *array, int16_t *max);
+
+BoxerStatus boxer_array_i16_dot_checked(ValueBox_ArrayBox_i16 *array, ValueBox_ArrayBox_i16 *another_array, double *dot);
+
+void boxer_array_u32_add(ValueBox_ArrayBox_u32 *array, ValueBox_ArrayBox_u32 *another_array);
+
+void boxer_array_u32_sub(ValueBox_ArrayBox_u32 *array, ValueBox_ArrayBox_u32 *another_array);
+
+void boxer_array_u32_mul(ValueBox_ArrayBox_u32 *array, ValueBox_ArrayBox_u32 *another_array);
+
+void boxer_array_u32_add_scalar(ValueBox_ArrayBox_u32 *array, uint32_t scalar);
+
+void boxer_array_u32_sub_scalar(ValueBox_ArrayBox_u32 *array, uint32_t scalar);
+
+void boxer_array_u32_scale(ValueBox_ArrayBox_u32 *array, uint32_t factor);
+
+void boxer_array_u32_clamp(ValueBox_ArrayBox_u32 *array, uint32_t min, uint32_t max);
+
+double boxer_array_u32_sum(ValueBox_ArrayBox_u32 *array);
+
+double boxer_array_u32_mean(ValueBox_ArrayBox_u32 *array);
+
+uint32_t boxer_array_u32_min(ValueBox_ArrayBox_u32 *array);
+
+uint32_t boxer_array_u32_max(ValueBox_ArrayBox_u32 *array);
+
+double boxer_array_u32_dot(ValueBox_ArrayBox_u32 *array, ValueBox_ArrayBox_u32 *another_array);
+
+BoxerStatus boxer_array_u32_add_checked(ValueBox_ArrayBox_u32 *array, ValueBox_ArrayBox_u32 *another_array);
+
+BoxerStatus boxer_array_u32_sub_checked(ValueBox_ArrayBox_u32 *array, ValueBox_ArrayBox_u32 *another_array);
+
+BoxerStatus boxer_array_u32_mul_checked(ValueBox_ArrayBox_u32 *array, ValueBox_ArrayBox_u32 *another_array);
+
+BoxerStatus boxer_array_u32_clamp_checked(ValueBox_ArrayBox_u32 *array, uint32_t min, uint32_t max);
+
+BoxerStatus boxer_array_u32_mean_checked(ValueBox_ArrayBox_u32 *array, double *mean);
+
+BoxerStatus boxer_array_u32_min_checked(ValueBox_ArrayBox_u32 *array, uint32_t *min);
+
+BoxerStatus boxer_array_u32_max_checked(ValueBox_ArrayBox_u32 *array, uint32_t *max);
+
+BoxerStatus boxer_array_u32_dot_checked(ValueBox_ArrayBox_u32 *array, ValueBox_ArrayBox_u32 *another_array, double *dot);
+
+void boxer_array_i32_add(ValueBox_ArrayBox_i32 *array, ValueBox_ArrayBox_i32 *another_array);
+
+void boxer_array_i32_sub(ValueBox_ArrayBox_i32 *array, ValueBox_ArrayBox_i32 *another_array);
+
+void boxer_array_i32_mul(ValueBox_ArrayBox_i32 *array, ValueBox_ArrayBox_i32 *another_array);
+
+void boxer_array_i32_add_scalar(ValueBox_ArrayBox_i32 *array, int32_t scalar);
+
+void boxer_array_i32_sub_scalar(ValueBox_ArrayBox_i32 *array, int32_t scalar);
+
+void boxer_array_i32_scale(ValueBox_ArrayBox_i32 *array, int32_t factor);
+
+void boxer_array_i32_clamp(ValueBox_ArrayBox_i32 *array, int32_t min, int32_t max);
+
+double boxer_array_i32_sum(ValueBox_ArrayBox_i32 *array);
+
+double boxer_array_i32_mean(ValueBox_ArrayBox_i32 *array);
+
+int32_t boxer_array_i32_min(ValueBox_ArrayBox_i32 *array);
+
+int32_t boxer_array_i32_max(ValueBox_ArrayBox_i32 *array);
+
+double boxer_array_i32_dot(ValueBox_ArrayBox_i32 *array, ValueBox_ArrayBox_i32 *another_array);
+
+BoxerStatus boxer_array_i32_add_checked(ValueBox_ArrayBox_i32 *array, ValueBox_ArrayBox_i32 *another_array);
+
+BoxerStatus boxer_array_i32_sub_checked(ValueBox_ArrayBox_i32 *array, ValueBox_ArrayBox_i32 *another_array);
+
+BoxerStatus boxer_array_i32_mul_checked(ValueBox_ArrayBox_i32 *array, ValueBox_ArrayBox_i32 *another_array);
+
+BoxerStatus boxer_array_i32_clamp_checked(ValueBox_ArrayBox_i32 *array, int32_t min, int32_t max);
+
+BoxerStatus boxer_array_i32_mean_checked(ValueBox_ArrayBox_i32 *array, double *mean);
+
+BoxerStatus boxer_array_i32_min_checked(ValueBox_ArrayBox_i32 *array, int32_t *min);
+
+BoxerStatus boxer_array_i32_max_checked(ValueBox_ArrayBox_i32 *array, int32_t *max);
+
+BoxerStatus boxer_array_i32_dot_checked(ValueBox_ArrayBox_i32 *array, ValueBox_ArrayBox_i32 *another_array, double *dot);
+
+void boxer_array_u64_add(ValueBox_ArrayBox_u64 *array, ValueBox_ArrayBox_u64 *another_array);
+
+void boxer_array_u64_sub(ValueBox_ArrayBox_u64 *array, ValueBox_ArrayBox_u64 *another_array);
+
+void boxer_array_u64_mul(ValueBox_ArrayBox_u64 *array, ValueBox_ArrayBox_u64 *another_array);
+
+void boxer_array_u64_add_scalar(ValueBox_ArrayBox_u64 *array, uint64_t scalar);
+
+void boxer_array_u64_sub_scalar(ValueBox_ArrayBox_u64 *array, uint64_t scalar);
+
+void boxer_array_u64_scale(ValueBox_ArrayBox_u64 *array, uint64_t factor);
+
+void boxer_array_u64_clamp(ValueBox_ArrayBox_u64 *array, uint64_t min, uint64_t max);
+
+double boxer_array_u64_sum(ValueBox_ArrayBox_u64 *array);
+
+double boxer_array_u64_mean(ValueBox_ArrayBox_u64 *array);
+
+uint64_t boxer_array_u64_min(ValueBox_ArrayBox_u64 *array);
+
+uint64_t boxer_array_u64_max(ValueBox_ArrayBox_u64 *array);
+
+double boxer_array_u64_dot(ValueBox_ArrayBox_u64 *array, ValueBox_ArrayBox_u64 *another_array);
+
+BoxerStatus boxer_array_u64_add_checked(ValueBox_ArrayBox_u64 *array, ValueBox_ArrayBox_u64 *another_array);
+
+BoxerStatus boxer_array_u64_sub_checked(ValueBox_ArrayBox_u64 *array, ValueBox_ArrayBox_u64 *another_array);
+
+BoxerStatus boxer_array_u64_mul_checked(ValueBox_ArrayBox_u64 *array, ValueBox_ArrayBox_u64 *another_array);
+
+BoxerStatus boxer_array_u64_clamp_checked(ValueBox_ArrayBox_u64 *array, uint64_t min, uint64_t max);
+
+BoxerStatus boxer_array_u64_mean_checked(ValueBox_ArrayBox_u64 *array, double *mean);
+
+BoxerStatus boxer_array_u64_min_checked(ValueBox_ArrayBox_u64 *array, uint64_t *min);
+
+BoxerStatus boxer_array_u64_max_checked(ValueBox_ArrayBox_u64 *array, uint64_t *max);
+
+BoxerStatus boxer_array_u64_dot_checked(ValueBox_ArrayBox_u64 *array, ValueBox_ArrayBox_u64 *another_array, double *dot);
+
+void boxer_array_i64_add(ValueBox_ArrayBox_i64 *array, ValueBox_ArrayBox_i64 *another_array);
+
+void boxer_array_i64_sub(ValueBox_ArrayBox_i64 *array, ValueBox_ArrayBox_i64 *another_array);
+
+void boxer_array_i64_mul(ValueBox_ArrayBox_i64 *array, ValueBox_ArrayBox_i64 *another_array);
+
+void boxer_array_i64_add_scalar(ValueBox_ArrayBox_i64 *array, int64_t scalar);
+
+void boxer_array_i64_sub_scalar(ValueBox_ArrayBox_i64 *array, int64_t scalar);
+
+void boxer_array_i64_scale(ValueBox_ArrayBox_i64 *array, int64_t factor);
+
+void boxer_array_i64_clamp(ValueBox_ArrayBox_i64 *array, int64_t min, int64_t max);
+
+double boxer_array_i64_sum(ValueBox_ArrayBox_i64 *array);
+
+double boxer_array_i64_mean(ValueBox_ArrayBox_i64 *array);
+
+int64_t boxer_array_i64_min(ValueBox_ArrayBox_i64 *array);
+
+int64_t boxer_array_i64_max(ValueBox_ArrayBox_i64 *array);
+
+double boxer_array_i64_dot(ValueBox_ArrayBox_i64 *array, ValueBox_ArrayBox_i64 *another_array);
+
+BoxerStatus boxer_array_i64_add_checked(ValueBox_ArrayBox_i64 *array, ValueBox_ArrayBox_i64 *another_array);
+
+BoxerStatus boxer_array_i64_sub_checked(ValueBox_ArrayBox_i64 *array, ValueBox_ArrayBox_i64 *another_array);
+
+BoxerStatus boxer_array_i64_mul_checked(ValueBox_ArrayBox_i64 *array, ValueBox_ArrayBox_i64 *another_array);
+
+BoxerStatus boxer_array_i64_clamp_checked(ValueBox_ArrayBox_i64 *array, int64_t min, int64_t max);
+
+BoxerStatus boxer_array_i64_mean_checked(ValueBox_ArrayBox_i64 *array, double *mean);
+
+BoxerStatus boxer_array_i64_min_checked(ValueBox_ArrayBox_i64 *array, int64_t *min);
+
+BoxerStatus boxer_array_i64_max_checked(ValueBox_ArrayBox_i64 *array, int64_t *max);
+
+BoxerStatus boxer_array_i64_dot_checked(ValueBox_ArrayBox_i64 *array, ValueBox_ArrayBox_i64 *another_array, double *dot);
+
+void boxer_array_isize_add(ValueBox_ArrayBox_isize *array, ValueBox_ArrayBox_isize *another_array);
+
+void boxer_array_isize_sub(ValueBox_ArrayBox_isize *array, ValueBox_ArrayBox_isize *another_array);
+
+void boxer_array_isize_mul(ValueBox_ArrayBox_isize *array, ValueBox_ArrayBox_isize *another_array);
+
+void boxer_array_isize_add_scalar(ValueBox_ArrayBox_isize *array, intptr_t scalar);
+
+void boxer_array_isize_sub_scalar(ValueBox_ArrayBox_isize *array, intptr_t scalar);
+
+void boxer_array_isize_scale(ValueBox_ArrayBox_isize *array, intptr_t factor);
+
+void boxer_array_isize_clamp(ValueBox_ArrayBox_isize *array, intptr_t min, intptr_t max);
+
+double boxer_array_isize_sum(ValueBox_ArrayBox_isize *array);
+
+double boxer_array_isize_mean(ValueBox_ArrayBox_isize *array);
+
+intptr_t boxer_array_isize_min(ValueBox_ArrayBox_isize *array);
+
+intptr_t boxer_array_isize_max(ValueBox_ArrayBox_isize *array);
+
+double boxer_array_isize_dot(ValueBox_ArrayBox_isize *array, ValueBox_ArrayBox_isize *another_array);
+
+BoxerStatus boxer_array_isize_add_checked(ValueBox_ArrayBox_isize *array, ValueBox_ArrayBox_isize *another_array);
+
+BoxerStatus boxer_array_isize_sub_checked(ValueBox_ArrayBox_isize *array, ValueBox_ArrayBox_isize *another_array);
+
+BoxerStatus boxer_array_isize_mul_checked(ValueBox_ArrayBox_isize *array, ValueBox_ArrayBox_isize *another_array);
+
+BoxerStatus boxer_array_isize_clamp_checked(ValueBox_ArrayBox_isize *array, intptr_t min, intptr_t max);
+
+BoxerStatus boxer_array_isize_mean_checked(ValueBox_ArrayBox_isize *array, double *mean);
+
+BoxerStatus boxer_array_isize_min_checked(ValueBox_ArrayBox_isize *array, intptr_t *min);
+
+BoxerStatus boxer_array_isize_max_checked(ValueBox_ArrayBox_isize *array, intptr_t *max);
+
+BoxerStatus boxer_array_isize_dot_checked(ValueBox_ArrayBox_isize *array, ValueBox_ArrayBox_isize *another_array, double *dot);
+
+void boxer_array_usize_add(ValueBox_ArrayBox_usize *array, ValueBox_ArrayBox_usize *another_array);
+
+void boxer_array_usize_sub(ValueBox_ArrayBox_usize *array, ValueBox_ArrayBox_usize *another_array);
+
+void boxer_array_usize_mul(ValueBox_ArrayBox_usize *array, ValueBox_ArrayBox_usize *another_array);
+
+void boxer_array_usize_add_scalar(ValueBox_ArrayBox_usize *array, size_t scalar);
+
+void boxer_array_usize_sub_scalar(ValueBox_ArrayBox_usize *array, size_t scalar);
+
+void boxer_array_usize_scale(ValueBox_ArrayBox_usize *array, size_t factor);
+
+void boxer_array_usize_clamp(ValueBox_ArrayBox_usize *array, size_t min, size_t max);
+
+double boxer_array_usize_sum(ValueBox_ArrayBox_usize *array);
+
+double boxer_array_usize_mean(ValueBox_ArrayBox_usize *array);
+
+size_t boxer_array_usize_min(ValueBox_ArrayBox_usize *array);
+
+size_t boxer_array_usize_max(ValueBox_ArrayBox_usize *array);
+
+double boxer_array_usize_dot(ValueBox_ArrayBox_usize *array, ValueBox_ArrayBox_usize *another_array);
+
+BoxerStatus boxer_array_usize_add_checked(ValueBox_ArrayBox_usize *array, ValueBox_ArrayBox_usize *another_array);
+
+BoxerStatus boxer_array_usize_sub_checked(ValueBox_ArrayBox_usize *array, ValueBox_ArrayBox_usize *another_array);
+
+BoxerStatus boxer_array_usize_mul_checked(ValueBox_ArrayBox_usize *array, ValueBox_ArrayBox_usize *another_array);
+
+BoxerStatus boxer_array_usize_clamp_checked(ValueBox_ArrayBox_usize *array, size_t min, size_t max);
+
+BoxerStatus boxer_array_usize_mean_checked(ValueBox_ArrayBox_usize *array, double *mean);
+
+BoxerStatus boxer_array_usize_min_checked(ValueBox_ArrayBox_usize *array, size_t *min);
+
+BoxerStatus boxer_array_usize_max_checked(ValueBox_ArrayBox_usize *array, size_t *max);
+
+BoxerStatus boxer_array_usize_dot_checked(ValueBox_ArrayBox_usize *array, ValueBox_ArrayBox_usize *another_array, double *dot);
+
+void boxer_array_int_add(ValueBox_ArrayBox_c_int *array, ValueBox_ArrayBox_c_int *another_array);
+
+void boxer_array_int_sub(ValueBox_ArrayBox_c_int *array, ValueBox_ArrayBox_c_int *another_array);
+
+void boxer_array_int_mul(ValueBox_ArrayBox_c_int *array, ValueBox_ArrayBox_c_int *another_array);
+
+void boxer_array_int_add_scalar(ValueBox_ArrayBox_c_int *array, int scalar);
+
+void boxer_array_int_sub_scalar(ValueBox_ArrayBox_c_int *array, int scalar);
+
+void boxer_array_int_scale(ValueBox_ArrayBox_c_int *array, int factor);
+
+void boxer_array_int_clamp(ValueBox_ArrayBox_c_int *array, int min, int max);
+
+double boxer_array_int_sum(ValueBox_ArrayBox_c_int *array);
+
+double boxer_array_int_mean(ValueBox_ArrayBox_c_int *array);
+
+int boxer_array_int_min(ValueBox_ArrayBox_c_int *array);
+
+int boxer_array_int_max(ValueBox_ArrayBox_c_int *array);
+
+double boxer_array_int_dot(ValueBox_ArrayBox_c_int *array, ValueBox_ArrayBox_c_int *another_array);
+
+BoxerStatus boxer_array_int_add_checked(ValueBox_ArrayBox_c_int *array, ValueBox_ArrayBox_c_int *another_array);
+
+BoxerStatus boxer_array_int_sub_checked(ValueBox_ArrayBox_c_int *array, ValueBox_ArrayBox_c_int *another_array);
+
+BoxerStatus boxer_array_int_mul_checked(ValueBox_ArrayBox_c_int *array, ValueBox_ArrayBox_c_int *another_array);
+
+BoxerStatus boxer_array_int_clamp_checked(ValueBox_ArrayBox_c_int *array, int min, int max);
+
+BoxerStatus boxer_array_int_mean_checked(ValueBox_ArrayBox_c_int *array, double *mean);
+
+BoxerStatus boxer_array_int_min_checked(ValueBox_ArrayBox_c_int *array, int *min);
+
+BoxerStatus boxer_array_int_max_checked(ValueBox_ArrayBox_c_int *array, int *max);
+
+BoxerStatus boxer_array_int_dot_checked(ValueBox_ArrayBox_c_int *array, ValueBox_ArrayBox_c_int *another_array, double *dot);
+
+void boxer_array_uint_add(ValueBox_ArrayBox_c_uint *array, ValueBox_ArrayBox_c_uint *another_array);
+
+void boxer_array_uint_sub(ValueBox_ArrayBox_c_uint *array, ValueBox_ArrayBox_c_uint *another_array);
+
+void boxer_array_uint_mul(ValueBox_ArrayBox_c_uint *array, ValueBox_ArrayBox_c_uint *another_array);
+
+void boxer_array_uint_add_scalar(ValueBox_ArrayBox_c_uint *array, unsigned int scalar);
+
+void boxer_array_uint_sub_scalar(ValueBox_ArrayBox_c_uint *array, unsigned int scalar);
+
+void boxer_array_uint_scale(ValueBox_ArrayBox_c_uint *array, unsigned int factor);
+
+void boxer_array_uint_clamp(ValueBox_ArrayBox_c_uint *array, unsigned int min, unsigned int max);
+
+double boxer_array_uint_sum(ValueBox_ArrayBox_c_uint *array);
+
+double boxer_array_uint_mean(ValueBox_ArrayBox_c_uint *array);
+
+unsigned int boxer_array_uint_min(ValueBox_ArrayBox_c_uint *array);
+
+unsigned int boxer_array_uint_max(ValueBox_ArrayBox_c_uint *array);
+
+double boxer_array_uint_dot(ValueBox_ArrayBox_c_uint *array, ValueBox_ArrayBox_c_uint *another_array);
+
+BoxerStatus boxer_array_uint_add_checked(ValueBox_ArrayBox_c_uint *array, ValueBox_ArrayBox_c_uint *another_array);
+
+BoxerStatus boxer_array_uint_sub_checked(ValueBox_ArrayBox_c_uint *array, ValueBox_ArrayBox_c_uint *another_array);
+
+BoxerStatus boxer_array_uint_mul_checked(ValueBox_ArrayBox_c_uint *array, ValueBox_ArrayBox_c_uint *another_array);
+
+BoxerStatus boxer_array_uint_clamp_checked(ValueBox_ArrayBox_c_uint *array, unsigned int min, unsigned int max);
+
+BoxerStatus boxer_array_uint_mean_checked(ValueBox_ArrayBox_c_uint *array, double *mean);
+
+BoxerStatus boxer_array_uint_min_checked(ValueBox_ArrayBox_c_uint *array, unsigned int *min);
+
+BoxerStatus boxer_array_uint_max_checked(ValueBox_ArrayBox_c_uint *array, unsigned int *max);
+
+BoxerStatus boxer_array_uint_dot_checked(ValueBox_ArrayBox_c_uint *array, ValueBox_ArrayBox_c_uint *another_array, double *dot);
+
+void boxer_array_f32_add(ValueBox_ArrayBox_f32 *array, ValueBox_ArrayBox_f32 *another_array);
+
+void boxer_array_f32_sub(ValueBox_ArrayBox_f32 *array, ValueBox_ArrayBox_f32 *another_array);
+
+void boxer_array_f32_mul(ValueBox_ArrayBox_f32 *array, ValueBox_ArrayBox_f32 *another_array);
+
+void boxer_array_f32_add_scalar(ValueBox_ArrayBox_f32 *array, float scalar);
+
+void boxer_array_f32_sub_scalar(ValueBox_ArrayBox_f32 *array, float scalar);
+
+void boxer_array_f32_scale(ValueBox_ArrayBox_f32 *array, float factor);
+
+void boxer_array_f32_clamp(ValueBox_ArrayBox_f32 *array, float min, float max);
+
+double boxer_array_f32_sum(ValueBox_ArrayBox_f32 *array);
+
+double boxer_array_f32_mean(ValueBox_ArrayBox_f32 *array);
+
+float boxer_array_f32_min(ValueBox_ArrayBox_f32 *array);
+
+float boxer_array_f32_max(ValueBox_ArrayBox_f32 *array);
+
+double boxer_array_f32_dot(ValueBox_ArrayBox_f32 *array, ValueBox_ArrayBox_f32 *another_array);
+
+BoxerStatus boxer_array_f32_add_checked(ValueBox_ArrayBox_f32 *array, ValueBox_ArrayBox_f32 *another_array);
+
+BoxerStatus boxer_array_f32_sub_checked(ValueBox_ArrayBox_f32 *array, ValueBox_ArrayBox_f32 *another_array);
+
+BoxerStatus boxer_array_f32_mul_checked(ValueBox_ArrayBox_f32 *array, ValueBox_ArrayBox_f32 *another_array);
+
+BoxerStatus boxer_array_f32_clamp_checked(ValueBox_ArrayBox_f32 *array, float min, float max);
+
+BoxerStatus boxer_array_f32_mean_checked(ValueBox_ArrayBox_f32 *array, double *mean);
+
+BoxerStatus boxer_array_f32_min_checked(ValueBox_ArrayBox_f32 *array, float *min);
+
+BoxerStatus boxer_array_f32_max_checked(ValueBox_ArrayBox_f32 *array, float *max);
+
+BoxerStatus boxer_array_f32_dot_checked(ValueBox_ArrayBox_f32 *array, ValueBox_ArrayBox_f32 *another_array, double *dot);
+
+void boxer_array_f64_add(ValueBox_ArrayBox_f64 *array, ValueBox_ArrayBox_f64 *another_array);
+
+void boxer_array_f64_sub(ValueBox_ArrayBox_f64 *array, ValueBox_ArrayBox_f64 *another_array);
+
+void boxer_array_f64_mul(ValueBox_ArrayBox_f64 *array, ValueBox_ArrayBox_f64 *another_array);
+
+void boxer_array_f64_add_scalar(ValueBox_ArrayBox_f64 *array, double scalar);
+
+void boxer_array_f64_sub_scalar(ValueBox_ArrayBox_f64 *array, double scalar);
+
+void boxer_array_f64_scale(ValueBox_ArrayBox_f64 *array, double factor);
+
+void boxer_array_f64_clamp(ValueBox_ArrayBox_f64 *array, double min, double max);
+
+double boxer_array_f64_sum(ValueBox_ArrayBox_f64 *array);
+
+double boxer_array_f64_mean(ValueBox_ArrayBox_f64 *array);
+
+double boxer_array_f64_min(ValueBox_ArrayBox_f64 *array);
+
+double boxer_array_f64_max(ValueBox_ArrayBox_f64 *array);
+
+double boxer_array_f64_dot(ValueBox_ArrayBox_f64 *array, ValueBox_ArrayBox_f64 *another_array);
+
+BoxerStatus boxer_array_f64_add_checked(ValueBox_ArrayBox_f64 *array, ValueBox_ArrayBox_f64 *another_array);
+
+BoxerStatus boxer_array_f64_sub_checked(ValueBox_ArrayBox_f64 *array, ValueBox_ArrayBox_f64 *another_array);
+
+BoxerStatus boxer_array_f64_mul_checked(ValueBox_ArrayBox_f64 *array, ValueBox_ArrayBox_f64 *another_array);
+
+BoxerStatus boxer_array_f64_clamp_checked(ValueBox_ArrayBox_f64 *array, double min, double max);
+
+BoxerStatus boxer_array_f64_mean_checked(ValueBox_ArrayBox_f64 *array, double *mean);
+
+BoxerStatus boxer_array_f64_min_checked(ValueBox_ArrayBox_f64 *array, double *min);
+
+BoxerStatus boxer_array_f64_max_checked(ValueBox_ArrayBox_f64 *array, double *max);
+
+BoxerStatus boxer_array_f64_dot_checked(ValueBox_ArrayBox_f64 *array, ValueBox_ArrayBox_f64 *another_array, double *dot);
+
+ValueBox_ArrayBox_u8 *boxer_array_u8_to_u8(ValueBox_ArrayBox_u8 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_i8 *boxer_array_u8_to_i8(ValueBox_ArrayBox_u8 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_u16 *boxer_array_u8_to_u16(ValueBox_ArrayBox_u8 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_i16 *boxer_array_u8_to_i16(ValueBox_ArrayBox_u8 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_u32 *boxer_array_u8_to_u32(ValueBox_ArrayBox_u8 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_i32 *boxer_array_u8_to_i32(ValueBox_ArrayBox_u8 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_u64 *boxer_array_u8_to_u64(ValueBox_ArrayBox_u8 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_i64 *boxer_array_u8_to_i64(ValueBox_ArrayBox_u8 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_isize *boxer_array_u8_to_isize(ValueBox_ArrayBox_u8 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_usize *boxer_array_u8_to_usize(ValueBox_ArrayBox_u8 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_c_int *boxer_array_u8_to_int(ValueBox_ArrayBox_u8 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_c_uint *boxer_array_u8_to_uint(ValueBox_ArrayBox_u8 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_f32 *boxer_array_u8_to_f32(ValueBox_ArrayBox_u8 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_f64 *boxer_array_u8_to_f64(ValueBox_ArrayBox_u8 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_u8 *boxer_array_i8_to_u8(ValueBox_ArrayBox_i8 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_i8 *boxer_array_i8_to_i8(ValueBox_ArrayBox_i8 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_u16 *boxer_array_i8_to_u16(ValueBox_ArrayBox_i8 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_i16 *boxer_array_i8_to_i16(ValueBox_ArrayBox_i8 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_u32 *boxer_array_i8_to_u32(ValueBox_ArrayBox_i8 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_i32 *boxer_array_i8_to_i32(ValueBox_ArrayBox_i8 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_u64 *boxer_array_i8_to_u64(ValueBox_ArrayBox_i8 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_i64 *boxer_array_i8_to_i64(ValueBox_ArrayBox_i8 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_isize *boxer_array_i8_to_isize(ValueBox_ArrayBox_i8 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_usize *boxer_array_i8_to_usize(ValueBox_ArrayBox_i8 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_c_int *boxer_array_i8_to_int(ValueBox_ArrayBox_i8 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_c_uint *boxer_array_i8_to_uint(ValueBox_ArrayBox_i8 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_f32 *boxer_array_i8_to_f32(ValueBox_ArrayBox_i8 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_f64 *boxer_array_i8_to_f64(ValueBox_ArrayBox_i8 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_u8 *boxer_array_u16_to_u8(ValueBox_ArrayBox_u16 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_i8 *boxer_array_u16_to_i8(ValueBox_ArrayBox_u16 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_u16 *boxer_array_u16_to_u16(ValueBox_ArrayBox_u16 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_i16 *boxer_array_u16_to_i16(ValueBox_ArrayBox_u16 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_u32 *boxer_array_u16_to_u32(ValueBox_ArrayBox_u16 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_i32 *boxer_array_u16_to_i32(ValueBox_ArrayBox_u16 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_u64 *boxer_array_u16_to_u64(ValueBox_ArrayBox_u16 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_i64 *boxer_array_u16_to_i64(ValueBox_ArrayBox_u16 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_isize *boxer_array_u16_to_isize(ValueBox_ArrayBox_u16 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_usize *boxer_array_u16_to_usize(ValueBox_ArrayBox_u16 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_c_int *boxer_array_u16_to_int(ValueBox_ArrayBox_u16 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_c_uint *boxer_array_u16_to_uint(ValueBox_ArrayBox_u16 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_f32 *boxer_array_u16_to_f32(ValueBox_ArrayBox_u16 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_f64 *boxer_array_u16_to_f64(ValueBox_ArrayBox_u16 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_u8 *boxer_array_i16_to_u8(ValueBox_ArrayBox_i16 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_i8 *boxer_array_i16_to_i8(ValueBox_ArrayBox_i16 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_u16 *boxer_array_i16_to_u16(ValueBox_ArrayBox_i16 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_i16 *boxer_array_i16_to_i16(ValueBox_ArrayBox_i16 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_u32 *boxer_array_i16_to_u32(ValueBox_ArrayBox_i16 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_i32 *boxer_array_i16_to_i32(ValueBox_ArrayBox_i16 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_u64 *boxer_array_i16_to_u64(ValueBox_ArrayBox_i16 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_i64 *boxer_array_i16_to_i64(ValueBox_ArrayBox_i16 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_isize *boxer_array_i16_to_isize(ValueBox_ArrayBox_i16 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_usize *boxer_array_i16_to_usize(ValueBox_ArrayBox_i16 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_c_int *boxer_array_i16_to_int(ValueBox_ArrayBox_i16 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_c_uint *boxer_array_i16_to_uint(ValueBox_ArrayBox_i16 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_f32 *boxer_array_i16_to_f32(ValueBox_ArrayBox_i16 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_f64 *boxer_array_i16_to_f64(ValueBox_ArrayBox_i16 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_u8 *boxer_array_u32_to_u8(ValueBox_ArrayBox_u32 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_i8 *boxer_array_u32_to_i8(ValueBox_ArrayBox_u32 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_u16 *boxer_array_u32_to_u16(ValueBox_ArrayBox_u32 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_i16 *boxer_array_u32_to_i16(ValueBox_ArrayBox_u32 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_u32 *boxer_array_u32_to_u32(ValueBox_ArrayBox_u32 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_i32 *boxer_array_u32_to_i32(ValueBox_ArrayBox_u32 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_u64 *boxer_array_u32_to_u64(ValueBox_ArrayBox_u32 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_i64 *boxer_array_u32_to_i64(ValueBox_ArrayBox_u32 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_isize *boxer_array_u32_to_isize(ValueBox_ArrayBox_u32 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_usize *boxer_array_u32_to_usize(ValueBox_ArrayBox_u32 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_c_int *boxer_array_u32_to_int(ValueBox_ArrayBox_u32 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_c_uint *boxer_array_u32_to_uint(ValueBox_ArrayBox_u32 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_f32 *boxer_array_u32_to_f32(ValueBox_ArrayBox_u32 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_f64 *boxer_array_u32_to_f64(ValueBox_ArrayBox_u32 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_u8 *boxer_array_i32_to_u8(ValueBox_ArrayBox_i32 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_i8 *boxer_array_i32_to_i8(ValueBox_ArrayBox_i32 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_u16 *boxer_array_i32_to_u16(ValueBox_ArrayBox_i32 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_i16 *boxer_array_i32_to_i16(ValueBox_ArrayBox_i32 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_u32 *boxer_array_i32_to_u32(ValueBox_ArrayBox_i32 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_i32 *boxer_array_i32_to_i32(ValueBox_ArrayBox_i32 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_u64 *boxer_array_i32_to_u64(ValueBox_ArrayBox_i32 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_i64 *boxer_array_i32_to_i64(ValueBox_ArrayBox_i32 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_isize *boxer_array_i32_to_isize(ValueBox_ArrayBox_i32 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_usize *boxer_array_i32_to_usize(ValueBox_ArrayBox_i32 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_c_int *boxer_array_i32_to_int(ValueBox_ArrayBox_i32 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_c_uint *boxer_array_i32_to_uint(ValueBox_ArrayBox_i32 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_f32 *boxer_array_i32_to_f32(ValueBox_ArrayBox_i32 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_f64 *boxer_array_i32_to_f64(ValueBox_ArrayBox_i32 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_u8 *boxer_array_u64_to_u8(ValueBox_ArrayBox_u64 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_i8 *boxer_array_u64_to_i8(ValueBox_ArrayBox_u64 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_u16 *boxer_array_u64_to_u16(ValueBox_ArrayBox_u64 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_i16 *boxer_array_u64_to_i16(ValueBox_ArrayBox_u64 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_u32 *boxer_array_u64_to_u32(ValueBox_ArrayBox_u64 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_i32 *boxer_array_u64_to_i32(ValueBox_ArrayBox_u64 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_u64 *boxer_array_u64_to_u64(ValueBox_ArrayBox_u64 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_i64 *boxer_array_u64_to_i64(ValueBox_ArrayBox_u64 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_isize *boxer_array_u64_to_isize(ValueBox_ArrayBox_u64 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_usize *boxer_array_u64_to_usize(ValueBox_ArrayBox_u64 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_c_int *boxer_array_u64_to_int(ValueBox_ArrayBox_u64 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_c_uint *boxer_array_u64_to_uint(ValueBox_ArrayBox_u64 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_f32 *boxer_array_u64_to_f32(ValueBox_ArrayBox_u64 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_f64 *boxer_array_u64_to_f64(ValueBox_ArrayBox_u64 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_u8 *boxer_array_i64_to_u8(ValueBox_ArrayBox_i64 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_i8 *boxer_array_i64_to_i8(ValueBox_ArrayBox_i64 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_u16 *boxer_array_i64_to_u16(ValueBox_ArrayBox_i64 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_i16 *boxer_array_i64_to_i16(ValueBox_ArrayBox_i64 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_u32 *boxer_array_i64_to_u32(ValueBox_ArrayBox_i64 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_i32 *boxer_array_i64_to_i32(ValueBox_ArrayBox_i64 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_u64 *boxer_array_i64_to_u64(ValueBox_ArrayBox_i64 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_i64 *boxer_array_i64_to_i64(ValueBox_ArrayBox_i64 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_isize *boxer_array_i64_to_isize(ValueBox_ArrayBox_i64 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_usize *boxer_array_i64_to_usize(ValueBox_ArrayBox_i64 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_c_int *boxer_array_i64_to_int(ValueBox_ArrayBox_i64 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_c_uint *boxer_array_i64_to_uint(ValueBox_ArrayBox_i64 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_f32 *boxer_array_i64_to_f32(ValueBox_ArrayBox_i64 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_f64 *boxer_array_i64_to_f64(ValueBox_ArrayBox_i64 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_u8 *boxer_array_isize_to_u8(ValueBox_ArrayBox_isize *array, uint8_t conversion);
+
+ValueBox_ArrayBox_i8 *boxer_array_isize_to_i8(ValueBox_ArrayBox_isize *array, uint8_t conversion);
+
+ValueBox_ArrayBox_u16 *boxer_array_isize_to_u16(ValueBox_ArrayBox_isize *array, uint8_t conversion);
+
+ValueBox_ArrayBox_i16 *boxer_array_isize_to_i16(ValueBox_ArrayBox_isize *array, uint8_t conversion);
+
+ValueBox_ArrayBox_u32 *boxer_array_isize_to_u32(ValueBox_ArrayBox_isize *array, uint8_t conversion);
+
+ValueBox_ArrayBox_i32 *boxer_array_isize_to_i32(ValueBox_ArrayBox_isize *array, uint8_t conversion);
+
+ValueBox_ArrayBox_u64 *boxer_array_isize_to_u64(ValueBox_ArrayBox_isize *array, uint8_t conversion);
+
+ValueBox_ArrayBox_i64 *boxer_array_isize_to_i64(ValueBox_ArrayBox_isize *array, uint8_t conversion);
+
+ValueBox_ArrayBox_isize *boxer_array_isize_to_isize(ValueBox_ArrayBox_isize *array, uint8_t conversion);
+
+ValueBox_ArrayBox_usize *boxer_array_isize_to_usize(ValueBox_ArrayBox_isize *array, uint8_t conversion);
+
+ValueBox_ArrayBox_c_int *boxer_array_isize_to_int(ValueBox_ArrayBox_isize *array, uint8_t conversion);
+
+ValueBox_ArrayBox_c_uint *boxer_array_isize_to_uint(ValueBox_ArrayBox_isize *array, uint8_t conversion);
+
+ValueBox_ArrayBox_f32 *boxer_array_isize_to_f32(ValueBox_ArrayBox_isize *array, uint8_t conversion);
+
+ValueBox_ArrayBox_f64 *boxer_array_isize_to_f64(ValueBox_ArrayBox_isize *array, uint8_t conversion);
+
+ValueBox_ArrayBox_u8 *boxer_array_usize_to_u8(ValueBox_ArrayBox_usize *array, uint8_t conversion);
+
+ValueBox_ArrayBox_i8 *boxer_array_usize_to_i8(ValueBox_ArrayBox_usize *array, uint8_t conversion);
+
+ValueBox_ArrayBox_u16 *boxer_array_usize_to_u16(ValueBox_ArrayBox_usize *array, uint8_t conversion);
+
+ValueBox_ArrayBox_i16 *boxer_array_usize_to_i16(ValueBox_ArrayBox_usize *array, uint8_t conversion);
+
+ValueBox_ArrayBox_u32 *boxer_array_usize_to_u32(ValueBox_ArrayBox_usize *array, uint8_t conversion);
+
+ValueBox_ArrayBox_i32 *boxer_array_usize_to_i32(ValueBox_ArrayBox_usize *array, uint8_t conversion);
+
+ValueBox_ArrayBox_u64 *boxer_array_usize_to_u64(ValueBox_ArrayBox_usize *array, uint8_t conversion);
+
+ValueBox_ArrayBox_i64 *boxer_array_usize_to_i64(ValueBox_ArrayBox_usize *array, uint8_t conversion);
+
+ValueBox_ArrayBox_isize *boxer_array_usize_to_isize(ValueBox_ArrayBox_usize *array, uint8_t conversion);
+
+ValueBox_ArrayBox_usize *boxer_array_usize_to_usize(ValueBox_ArrayBox_usize *array, uint8_t conversion);
+
+ValueBox_ArrayBox_c_int *boxer_array_usize_to_int(ValueBox_ArrayBox_usize *array, uint8_t conversion);
+
+ValueBox_ArrayBox_c_uint *boxer_array_usize_to_uint(ValueBox_ArrayBox_usize *array, uint8_t conversion);
+
+ValueBox_ArrayBox_f32 *boxer_array_usize_to_f32(ValueBox_ArrayBox_usize *array, uint8_t conversion);
+
+ValueBox_ArrayBox_f64 *boxer_array_usize_to_f64(ValueBox_ArrayBox_usize *array, uint8_t conversion);
+
+ValueBox_ArrayBox_u8 *boxer_array_int_to_u8(ValueBox_ArrayBox_c_int *array, uint8_t conversion);
+
+ValueBox_ArrayBox_i8 *boxer_array_int_to_i8(ValueBox_ArrayBox_c_int *array, uint8_t conversion);
+
+ValueBox_ArrayBox_u16 *boxer_array_int_to_u16(ValueBox_ArrayBox_c_int *array, uint8_t conversion);
+
+ValueBox_ArrayBox_i16 *boxer_array_int_to_i16(ValueBox_ArrayBox_c_int *array, uint8_t conversion);
+
+ValueBox_ArrayBox_u32 *boxer_array_int_to_u32(ValueBox_ArrayBox_c_int *array, uint8_t conversion);
+
+ValueBox_ArrayBox_i32 *boxer_array_int_to_i32(ValueBox_ArrayBox_c_int *array, uint8_t conversion);
+
+ValueBox_ArrayBox_u64 *boxer_array_int_to_u64(ValueBox_ArrayBox_c_int *array, uint8_t conversion);
+
+ValueBox_ArrayBox_i64 *boxer_array_int_to_i64(ValueBox_ArrayBox_c_int *array, uint8_t conversion);
+
+ValueBox_ArrayBox_isize *boxer_array_int_to_isize(ValueBox_ArrayBox_c_int *array, uint8_t conversion);
+
+ValueBox_ArrayBox_usize *boxer_array_int_to_usize(ValueBox_ArrayBox_c_int *array, uint8_t conversion);
+
+ValueBox_ArrayBox_c_int *boxer_array_int_to_int(ValueBox_ArrayBox_c_int *array, uint8_t conversion);
+
+ValueBox_ArrayBox_c_uint *boxer_array_int_to_uint(ValueBox_ArrayBox_c_int *array, uint8_t conversion);
+
+ValueBox_ArrayBox_f32 *boxer_array_int_to_f32(ValueBox_ArrayBox_c_int *array, uint8_t conversion);
+
+ValueBox_ArrayBox_f64 *boxer_array_int_to_f64(ValueBox_ArrayBox_c_int *array, uint8_t conversion);
+
+ValueBox_ArrayBox_u8 *boxer_array_uint_to_u8(ValueBox_ArrayBox_c_uint *array, uint8_t conversion);
+
+ValueBox_ArrayBox_i8 *boxer_array_uint_to_i8(ValueBox_ArrayBox_c_uint *array, uint8_t conversion);
+
+ValueBox_ArrayBox_u16 *boxer_array_uint_to_u16(ValueBox_ArrayBox_c_uint *array, uint8_t conversion);
+
+ValueBox_ArrayBox_i16 *boxer_array_uint_to_i16(ValueBox_ArrayBox_c_uint *array, uint8_t conversion);
+
+ValueBox_ArrayBox_u32 *boxer_array_uint_to_u32(ValueBox_ArrayBox_c_uint *array, uint8_t conversion);
+
+ValueBox_ArrayBox_i32 *boxer_array_uint_to_i32(ValueBox_ArrayBox_c_uint *array, uint8_t conversion);
+
+ValueBox_ArrayBox_u64 *boxer_array_uint_to_u64(ValueBox_ArrayBox_c_uint *array, uint8_t conversion);
+
+ValueBox_ArrayBox_i64 *boxer_array_uint_to_i64(ValueBox_ArrayBox_c_uint *array, uint8_t conversion);
+
+ValueBox_ArrayBox_isize *boxer_array_uint_to_isize(ValueBox_ArrayBox_c_uint *array, uint8_t conversion);
+
+ValueBox_ArrayBox_usize *boxer_array_uint_to_usize(ValueBox_ArrayBox_c_uint *array, uint8_t conversion);
+
+ValueBox_ArrayBox_c_int *boxer_array_uint_to_int(ValueBox_ArrayBox_c_uint *array, uint8_t conversion);
+
+ValueBox_ArrayBox_c_uint *boxer_array_uint_to_uint(ValueBox_ArrayBox_c_uint *array, uint8_t conversion);
+
+ValueBox_ArrayBox_f32 *boxer_array_uint_to_f32(ValueBox_ArrayBox_c_uint *array, uint8_t conversion);
+
+ValueBox_ArrayBox_f64 *boxer_array_uint_to_f64(ValueBox_ArrayBox_c_uint *array, uint8_t conversion);
+
+ValueBox_ArrayBox_u8 *boxer_array_f32_to_u8(ValueBox_ArrayBox_f32 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_i8 *boxer_array_f32_to_i8(ValueBox_ArrayBox_f32 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_u16 *boxer_array_f32_to_u16(ValueBox_ArrayBox_f32 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_i16 *boxer_array_f32_to_i16(ValueBox_ArrayBox_f32 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_u32 *boxer_array_f32_to_u32(ValueBox_ArrayBox_f32 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_i32 *boxer_array_f32_to_i32(ValueBox_ArrayBox_f32 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_u64 *boxer_array_f32_to_u64(ValueBox_ArrayBox_f32 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_i64 *boxer_array_f32_to_i64(ValueBox_ArrayBox_f32 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_isize *boxer_array_f32_to_isize(ValueBox_ArrayBox_f32 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_usize *boxer_array_f32_to_usize(ValueBox_ArrayBox_f32 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_c_int *boxer_array_f32_to_int(ValueBox_ArrayBox_f32 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_c_uint *boxer_array_f32_to_uint(ValueBox_ArrayBox_f32 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_f32 *boxer_array_f32_to_f32(ValueBox_ArrayBox_f32 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_f64 *boxer_array_f32_to_f64(ValueBox_ArrayBox_f32 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_u8 *boxer_array_f64_to_u8(ValueBox_ArrayBox_f64 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_i8 *boxer_array_f64_to_i8(ValueBox_ArrayBox_f64 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_u16 *boxer_array_f64_to_u16(ValueBox_ArrayBox_f64 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_i16 *boxer_array_f64_to_i16(ValueBox_ArrayBox_f64 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_u32 *boxer_array_f64_to_u32(ValueBox_ArrayBox_f64 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_i32 *boxer_array_f64_to_i32(ValueBox_ArrayBox_f64 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_u64 *boxer_array_f64_to_u64(ValueBox_ArrayBox_f64 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_i64 *boxer_array_f64_to_i64(ValueBox_ArrayBox_f64 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_isize *boxer_array_f64_to_isize(ValueBox_ArrayBox_f64 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_usize *boxer_array_f64_to_usize(ValueBox_ArrayBox_f64 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_c_int *boxer_array_f64_to_int(ValueBox_ArrayBox_f64 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_c_uint *boxer_array_f64_to_uint(ValueBox_ArrayBox_f64 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_f32 *boxer_array_f64_to_f32(ValueBox_ArrayBox_f64 *array, uint8_t conversion);
+
+ValueBox_ArrayBox_f64 *boxer_array_f64_to_f64(ValueBox_ArrayBox_f64 *array, uint8_t conversion);
+
+#endif
+
+#if defined(VALUE_BOX_FFI_FEATURE_ARRAY_BOX) && defined(VALUE_BOX_FFI_FEATURE_SERDE)
+
+// Serialize the boxed value into a new array of bytes of a given `SerializationFormat`.
+ValueBox_ArrayBox_u8 *boxer_array_u8_serialize(ValueBox_ArrayBox_u8 *value, uint8_t format);
+
+// Create a new box with a value deserialized from an array of bytes of a given `SerializationFormat`.
+ValueBox_ArrayBox_u8 *boxer_array_u8_deserialize(ValueBox_ArrayBox_u8 *bytes, uint8_t format);
+
+// Serialize the boxed value into a new array of bytes of a given `SerializationFormat`.
+ValueBox_ArrayBox_u8 *boxer_array_i8_serialize(ValueBox_ArrayBox_i8 *value, uint8_t format);
+
+// Create a new box with a value deserialized from an array of bytes of a given `SerializationFormat`.
+ValueBox_ArrayBox_i8 *boxer_array_i8_deserialize(ValueBox_ArrayBox_u8 *bytes, uint8_t format);
+
+// Serialize the boxed value into a new array of bytes of a given `SerializationFormat`.
+ValueBox_ArrayBox_u8 *boxer_array_u16_serialize(ValueBox_ArrayBox_u16 *value, uint8_t format);
+
+// Create a new box with a value deserialized from an array of bytes of a given `SerializationFormat`.
+ValueBox_ArrayBox_u16 *boxer_array_u16_deserialize(ValueBox_ArrayBox_u8 *bytes, uint8_t format);
+
+// Serialize the boxed value into a new array of bytes of a given `SerializationFormat`.
+ValueBox_ArrayBox_u8 *boxer_array_i16_serialize(ValueBox_ArrayBox_i16 *value, uint8_t format);
+
+// Create a new box with a value deserialized from an array of bytes of a given `SerializationFormat`.
+ValueBox_ArrayBox_i16 *boxer_array_i16_deserialize(ValueBox_ArrayBox_u8 *bytes, uint8_t format);
+
+// Serialize the boxed value into a new array of bytes of a given `SerializationFormat`.
+ValueBox_ArrayBox_u8 *boxer_array_u32_serialize(ValueBox_ArrayBox_u32 *value, uint8_t format);
+
+// Create a new box with a value deserialized from an array of bytes of a given `SerializationFormat`.
+ValueBox_ArrayBox_u32 *boxer_array_u32_deserialize(ValueBox_ArrayBox_u8 *bytes, uint8_t format);
+
+// Serialize the boxed value into a new array of bytes of a given `SerializationFormat`.
+ValueBox_ArrayBox_u8 *boxer_array_i32_serialize(ValueBox_ArrayBox_i32 *value, uint8_t format);
+
+// Create a new box with a value deserialized from an array of bytes of a given `SerializationFormat`.
+ValueBox_ArrayBox_i32 *boxer_array_i32_deserialize(ValueBox_ArrayBox_u8 *bytes, uint8_t format);
+
+// Serialize the boxed value into a new array of bytes of a given `SerializationFormat`.
+ValueBox_ArrayBox_u8 *boxer_array_u64_serialize(ValueBox_ArrayBox_u64 *value, uint8_t format);
+
+// Create a new box with a value deserialized from an array of bytes of a given `SerializationFormat`.
+ValueBox_ArrayBox_u64 *boxer_array_u64_deserialize(ValueBox_ArrayBox_u8 *bytes, uint8_t format);
+
+// Serialize the boxed value into a new array of bytes of a given `SerializationFormat`.
+ValueBox_ArrayBox_u8 *boxer_array_i64_serialize(ValueBox_ArrayBox_i64 *value, uint8_t format);
+
+// Create a new box with a value deserialized from an array of bytes of a given `SerializationFormat`.
+ValueBox_ArrayBox_i64 *boxer_array_i64_deserialize(ValueBox_ArrayBox_u8 *bytes, uint8_t format);
+
+// Serialize the boxed value into a new array of bytes of a given `SerializationFormat`.
+ValueBox_ArrayBox_u8 *boxer_array_isize_serialize(ValueBox_ArrayBox_isize *value, uint8_t format);
+
+// Create a new box with a value deserialized from an array of bytes of a given `SerializationFormat`.
+ValueBox_ArrayBox_isize *boxer_array_isize_deserialize(ValueBox_ArrayBox_u8 *bytes, uint8_t format);
+
+// Serialize the boxed value into a new array of bytes of a given `SerializationFormat`.
+ValueBox_ArrayBox_u8 *boxer_array_usize_serialize(ValueBox_ArrayBox_usize *value, uint8_t format);
+
+// Create a new box with a value deserialized from an array of bytes of a given `SerializationFormat`.
+ValueBox_ArrayBox_usize *boxer_array_usize_deserialize(ValueBox_ArrayBox_u8 *bytes, uint8_t format);
+
+// Serialize the boxed value into a new array of bytes of a given `SerializationFormat`.
+ValueBox_ArrayBox_u8 *boxer_array_int_serialize(ValueBox_ArrayBox_c_int *value, uint8_t format);
+
+// Create a new box with a value deserialized from an array of bytes of a given `SerializationFormat`.
+ValueBox_ArrayBox_c_int *boxer_array_int_deserialize(ValueBox_ArrayBox_u8 *bytes, uint8_t format);
+
+// Serialize the boxed value into a new array of bytes of a given `SerializationFormat`.
+ValueBox_ArrayBox_u8 *boxer_array_uint_serialize(ValueBox_ArrayBox_c_uint *value, uint8_t format);
+
+// Create a new box with a value deserialized from an array of bytes of a given `SerializationFormat`.
+ValueBox_ArrayBox_c_uint *boxer_array_uint_deserialize(ValueBox_ArrayBox_u8 *bytes, uint8_t format);
+
+// Serialize the boxed value into a new array of bytes of a given `SerializationFormat`.
+ValueBox_ArrayBox_u8 *boxer_array_f32_serialize(ValueBox_ArrayBox_f32 *value, uint8_t format);
+
+// Create a new box with a value deserialized from an array of bytes of a given `SerializationFormat`.
+ValueBox_ArrayBox_f32 *boxer_array_f32_deserialize(ValueBox_ArrayBox_u8 *bytes, uint8_t format);
+
+// Serialize the boxed value into a new array of bytes of a given `SerializationFormat`.
+ValueBox_ArrayBox_u8 *boxer_array_f64_serialize(ValueBox_ArrayBox_f64 *value, uint8_t format);
+
+// Create a new box with a value deserialized from an array of bytes of a given `SerializationFormat`.
+ValueBox_ArrayBox_f64 *boxer_array_f64_deserialize(ValueBox_ArrayBox_u8 *bytes, uint8_t format);
+
+#endif
+
+#if defined(VALUE_BOX_FFI_FEATURE_STRING_BOX)
+
+// Return the description of all exported functions as a JSON document.
+ValueBox_StringBox *boxer_ffi_manifest(void);
+
+#endif
+
+#if defined(VALUE_BOX_FFI_FEATURE_GEOMETRY_BOX)
+
+ValueBox_U128Box *boxer_number_uint128_create(void);
+
+void boxer_number_uint128_drop(ValueBox_U128Box *ptr);
+
+uint64_t boxer_number_uint128_get_low(ValueBox_U128Box *number);
+
+void boxer_number_uint128_set_low(ValueBox_U128Box *number, uint64_t low);
+
+uint64_t boxer_number_uint128_get_high(ValueBox_U128Box *number);
+
+void boxer_number_uint128_set_high(ValueBox_U128Box *number, uint64_t high);
+
+void boxer_number_uint128_set_max(ValueBox_U128Box *number);
+
+void boxer_number_uint128_set_min(ValueBox_U128Box *number);
+
+ValueBox_Point3Box_f32 *boxer_point3_f32_default(void);
+
+ValueBox_Point3Box_f32 *boxer_point3_f32_create(float x, float y, float z);
+
+void boxer_point3_f32_drop(ValueBox_Point3Box_f32 *ptr);
+
+float boxer_point3_f32_get_x(ValueBox_Point3Box_f32 *_point_ptr);
+
+void boxer_point3_f32_set_x(ValueBox_Point3Box_f32 *_point_ptr, float x);
+
+float boxer_point3_f32_get_y(ValueBox_Point3Box_f32 *_point_ptr);
+
+void boxer_point3_f32_set_y(ValueBox_Point3Box_f32 *_point_ptr, float y);
+
+float boxer_point3_f32_get_z(ValueBox_Point3Box_f32 *_point_ptr);
+
+void boxer_point3_f32_set_z(ValueBox_Point3Box_f32 *_point_ptr, float z);
+
+ValueBox_PointBox_f32 *boxer_point_f32_default(void);
+
+ValueBox_PointBox_f32 *boxer_point_f32_create(float x, float y);
+
+void boxer_point_f32_drop(ValueBox_PointBox_f32 *ptr);
+
+float boxer_point_f32_get_x(ValueBox_PointBox_f32 *_point_ptr);
+
+void boxer_point_f32_set_x(ValueBox_PointBox_f32 *_point_ptr, float x);
+
+float boxer_point_f32_get_y(ValueBox_PointBox_f32 *_point_ptr);
+
+void boxer_point_f32_set_y(ValueBox_PointBox_f32 *_point_ptr, float y);
+
+BoxerStatus boxer_point_f32_get_x_checked(ValueBox_PointBox_f32 *_point_ptr, float *x);
+
+BoxerStatus boxer_point_f32_set_x_checked(ValueBox_PointBox_f32 *_point_ptr, float x);
+
+BoxerStatus boxer_point_f32_get_y_checked(ValueBox_PointBox_f32 *_point_ptr, float *y);
+
+BoxerStatus boxer_point_f32_set_y_checked(ValueBox_PointBox_f32 *_point_ptr, float y);
+
+ValueBox_PointBox_f64 *boxer_point_f64_default(void);
+
+ValueBox_PointBox_f64 *boxer_point_f64_create(double x, double y);
+
+void boxer_point_f64_drop(ValueBox_PointBox_f64 *ptr);
+
+double boxer_point_f64_get_x(ValueBox_PointBox_f64 *_point_ptr);
+
+void boxer_point_f64_set_x(ValueBox_PointBox_f64 *_point_ptr, double x);
+
+double boxer_point_f64_get_y(ValueBox_PointBox_f64 *_point_ptr);
+
+void boxer_point_f64_set_y(ValueBox_PointBox_f64 *_point_ptr, double y);
+
+BoxerStatus boxer_point_f64_get_x_checked(ValueBox_PointBox_f64 *_point_ptr, double *x);
+
+BoxerStatus boxer_point_f64_set_x_checked(ValueBox_PointBox_f64 *_point_ptr, double x);
+
+BoxerStatus boxer_point_f64_get_y_checked(ValueBox_PointBox_f64 *_point_ptr, double *y);
+
+BoxerStatus boxer_point_f64_set_y_checked(ValueBox_PointBox_f64 *_point_ptr, double y);
+
+ValueBox_PointBox_i32 *boxer_point_i32_default(void);
+
+ValueBox_PointBox_i32 *boxer_point_i32_create(int32_t x, int32_t y);
+
+void boxer_point_i32_drop(ValueBox_PointBox_i32 *ptr);
+
+int32_t boxer_point_i32_get_x(ValueBox_PointBox_i32 *point_ptr);
+
+void boxer_point_i32_set_x(ValueBox_PointBox_i32 *point_ptr, int32_t x);
+
+int32_t boxer_point_i32_get_y(ValueBox_PointBox_i32 *point_ptr);
+
+void boxer_point_i32_set_y(ValueBox_PointBox_i32 *_point_ptr, int32_t y);
+
+BoxerStatus boxer_point_i32_get_x_checked(ValueBox_PointBox_i32 *point_ptr, int32_t *x);
+
+BoxerStatus boxer_point_i32_set_x_checked(ValueBox_PointBox_i32 *point_ptr, int32_t x);
+
+BoxerStatus boxer_point_i32_get_y_checked(ValueBox_PointBox_i32 *point_ptr, int32_t *y);
+
+BoxerStatus boxer_point_i32_set_y_checked(ValueBox_PointBox_i32 *point_ptr, int32_t y);
+
+ValueBox_PointBox_u64 *boxer_point_u64_default(void);
+
+ValueBox_PointBox_u64 *boxer_point_u64_create(uint64_t x, uint64_t y);
+
+void boxer_point_u64_drop(ValueBox_PointBox_u64 *ptr);
+
+uint64_t boxer_point_u64_get_x(ValueBox_PointBox_u64 *_point_ptr);
+
+void boxer_point_u64_set_x(ValueBox_PointBox_u64 *_point_ptr, uint64_t x);
+
+uint64_t boxer_point_u64_get_y(ValueBox_PointBox_u64 *_point_ptr);
+
+void boxer_point_u64_set_y(ValueBox_PointBox_u64 *_point_ptr, uint64_t y);
+
+BoxerStatus boxer_point_u64_get_x_checked(ValueBox_PointBox_u64 *_point_ptr, uint64_t *x);
+
+BoxerStatus boxer_point_u64_set_x_checked(ValueBox_PointBox_u64 *_point_ptr, uint64_t x);
+
+BoxerStatus boxer_point_u64_get_y_checked(ValueBox_PointBox_u64 *_point_ptr, uint64_t *y);
+
+BoxerStatus boxer_point_u64_set_y_checked(ValueBox_PointBox_u64 *_point_ptr, uint64_t y);
+
+ValueBox_Range_usize *boxer_range_usize_create(void);
+
+void boxer_range_usize_drop(ValueBox_Range_usize *range);
+
+size_t boxer_range_usize_get_start(ValueBox_Range_usize *range);
+
+void boxer_range_usize_set_start(ValueBox_Range_usize *range, size_t start);
+
+size_t boxer_range_usize_get_end(ValueBox_Range_usize *range);
+
+void boxer_range_usize_set_end(ValueBox_Range_usize *range, size_t end);
+
+ValueBox_SizeBox_f32 *boxer_size_f32_create(void);
+
+void boxer_size_f32_drop(ValueBox_SizeBox_f32 *ptr);
+
+float boxer_size_f32_get_width(ValueBox_SizeBox_f32 *ptr);
+
+void boxer_size_f32_set_width(ValueBox_SizeBox_f32 *ptr, float width);
+
+float boxer_size_f32_get_height(ValueBox_SizeBox_f32 *ptr);
+
+void boxer_size_f32_set_height(ValueBox_SizeBox_f32 *ptr, float height);
+
+BoxerStatus boxer_size_f32_get_width_checked(ValueBox_SizeBox_f32 *ptr, float *width);
+
+BoxerStatus boxer_size_f32_set_width_checked(ValueBox_SizeBox_f32 *ptr, float width);
+
+BoxerStatus boxer_size_f32_get_height_checked(ValueBox_SizeBox_f32 *ptr, float *height);
+
+BoxerStatus boxer_size_f32_set_height_checked(ValueBox_SizeBox_f32 *ptr, float height);
+
+ValueBox_SizeBox_f64 *boxer_size_f64_create(void);
+
+void boxer_size_f64_drop(ValueBox_SizeBox_f64 *ptr);
+
+double boxer_size_f64_get_width(ValueBox_SizeBox_f64 *ptr);
+
+void boxer_size_f64_set_width(ValueBox_SizeBox_f64 *ptr, double width);
+
+double boxer_size_f64_get_height(ValueBox_SizeBox_f64 *ptr);
+
+void boxer_size_f64_set_height(ValueBox_SizeBox_f64 *ptr, double height);
+
+BoxerStatus boxer_size_f64_get_width_checked(ValueBox_SizeBox_f64 *ptr, double *width);
+
+BoxerStatus boxer_size_f64_set_width_checked(ValueBox_SizeBox_f64 *ptr, double width);
+
+BoxerStatus boxer_size_f64_get_height_checked(ValueBox_SizeBox_f64 *ptr, double *height);
+
+BoxerStatus boxer_size_f64_set_height_checked(ValueBox_SizeBox_f64 *ptr, double height);
+
+ValueBox_SizeBox_i32 *boxer_size_i32_create(void);
+
+void boxer_size_i32_drop(ValueBox_SizeBox_i32 *ptr);
+
+int32_t boxer_size_i32_get_width(ValueBox_SizeBox_i32 *ptr);
+
+void boxer_size_i32_set_width(ValueBox_SizeBox_i32 *ptr, int32_t width);
+
+int32_t boxer_size_i32_get_height(ValueBox_SizeBox_i32 *ptr);
+
+void boxer_size_i32_set_height(ValueBox_SizeBox_i32 *ptr, int32_t height);
+
+BoxerStatus boxer_size_i32_get_width_checked(ValueBox_SizeBox_i32 *ptr, int32_t *width);
+
+BoxerStatus boxer_size_i32_set_width_checked(ValueBox_SizeBox_i32 *ptr, int32_t width);
+
+BoxerStatus boxer_size_i32_get_height_checked(ValueBox_SizeBox_i32 *ptr, int32_t *height);
+
+BoxerStatus boxer_size_i32_set_height_checked(ValueBox_SizeBox_i32 *ptr, int32_t height);
+
+ValueBox_SizeBox_u32 *boxer_size_u32_create(void);
+
+void boxer_size_u32_drop(ValueBox_SizeBox_u32 *ptr);
+
+uint32_t boxer_size_u32_get_width(ValueBox_SizeBox_u32 *ptr);
+
+void boxer_size_u32_set_width(ValueBox_SizeBox_u32 *ptr, uint32_t width);
+
+uint32_t boxer_size_u32_get_height(ValueBox_SizeBox_u32 *ptr);
+
+void boxer_size_u32_set_height(ValueBox_SizeBox_u32 *ptr, uint32_t height);
+
+BoxerStatus boxer_size_u32_get_width_checked(ValueBox_SizeBox_u32 *ptr, uint32_t *width);
+
+BoxerStatus boxer_size_u32_set_width_checked(ValueBox_SizeBox_u32 *ptr, uint32_t width);
+
+BoxerStatus boxer_size_u32_get_height_checked(ValueBox_SizeBox_u32 *ptr, uint32_t *height);
+
+BoxerStatus boxer_size_u32_set_height_checked(ValueBox_SizeBox_u32 *ptr, uint32_t height);
+
+ValueBox_SizeBox_u64 *boxer_size_u64_create(void);
+
+void boxer_size_u64_drop(ValueBox_SizeBox_u64 *ptr);
+
+uint64_t boxer_size_u64_get_width(ValueBox_SizeBox_u64 *ptr);
+
+void boxer_size_u64_set_width(ValueBox_SizeBox_u64 *ptr, uint64_t width);
+
+uint64_t boxer_size_u64_get_height(ValueBox_SizeBox_u64 *ptr);
+
+void boxer_size_u64_set_height(ValueBox_SizeBox_u64 *ptr, uint64_t height);
+
+BoxerStatus boxer_size_u64_get_width_checked(ValueBox_SizeBox_u64 *ptr, uint64_t *width);
+
+BoxerStatus boxer_size_u64_set_width_checked(ValueBox_SizeBox_u64 *ptr, uint64_t width);
+
+BoxerStatus boxer_size_u64_get_height_checked(ValueBox_SizeBox_u64 *ptr, uint64_t *height);
+
+BoxerStatus boxer_size_u64_set_height_checked(ValueBox_SizeBox_u64 *ptr, uint64_t height);
+
+#endif
+
+#if defined(VALUE_BOX_FFI_FEATURE_GEOMETRY_BOX) && defined(VALUE_BOX_FFI_FEATURE_SERDE)
+
+// Serialize the boxed value into a new array of bytes of a given `SerializationFormat`.
+ValueBox_ArrayBox_u8 *boxer_point_f32_serialize(ValueBox_PointBox_f32 *value, uint8_t format);
+
+// Create a new box with a value deserialized from an array of bytes of a given `SerializationFormat`.
+ValueBox_PointBox_f32 *boxer_point_f32_deserialize(ValueBox_ArrayBox_u8 *bytes, uint8_t format);
+
+// Serialize the boxed value into a new array of bytes of a given `SerializationFormat`.
+ValueBox_ArrayBox_u8 *boxer_point_f64_serialize(ValueBox_PointBox_f64 *value, uint8_t format);
+
+// Create a new box with a value deserialized from an array of bytes of a given `SerializationFormat`.
+ValueBox_PointBox_f64 *boxer_point_f64_deserialize(ValueBox_ArrayBox_u8 *bytes, uint8_t format);
+
+// Serialize the boxed value into a new array of bytes of a given `SerializationFormat`.
+ValueBox_ArrayBox_u8 *boxer_point_i32_serialize(ValueBox_PointBox_i32 *value, uint8_t format);
+
+// Create a new box with a value deserialized from an array of bytes of a given `SerializationFormat`.
+ValueBox_PointBox_i32 *boxer_point_i32_deserialize(ValueBox_ArrayBox_u8 *bytes, uint8_t format);
+
+// Serialize the boxed value into a new array of bytes of a given `SerializationFormat`.
+ValueBox_ArrayBox_u8 *boxer_point_u64_serialize(ValueBox_PointBox_u64 *value, uint8_t format);
+
+// Create a new box with a value deserialized from an array of bytes of a given `SerializationFormat`.
+ValueBox_PointBox_u64 *boxer_point_u64_deserialize(ValueBox_ArrayBox_u8 *bytes, uint8_t format);
+
+// Serialize the boxed value into a new array of bytes of a given `SerializationFormat`.
+ValueBox_ArrayBox_u8 *boxer_point3_f32_serialize(ValueBox_Point3Box_f32 *value, uint8_t format);
+
+// Create a new box with a value deserialized from an array of bytes of a given `SerializationFormat`.
+ValueBox_Point3Box_f32 *boxer_point3_f32_deserialize(ValueBox_ArrayBox_u8 *bytes, uint8_t format);
+
+// Serialize the boxed value into a new array of bytes of a given `SerializationFormat`.
+ValueBox_ArrayBox_u8 *boxer_size_f32_serialize(ValueBox_SizeBox_f32 *value, uint8_t format);
+
+// Create a new box with a value deserialized from an array of bytes of a given `SerializationFormat`.
+ValueBox_SizeBox_f32 *boxer_size_f32_deserialize(ValueBox_ArrayBox_u8 *bytes, uint8_t format);
+
+// Serialize the boxed value into a new array of bytes of a given `SerializationFormat`.
+ValueBox_ArrayBox_u8 *boxer_size_f64_serialize(ValueBox_SizeBox_f64 *value, uint8_t format);
+
+// Create a new box with a value deserialized from an array of bytes of a given `SerializationFormat`.
+ValueBox_SizeBox_f64 *boxer_size_f64_deserialize(ValueBox_ArrayBox_u8 *bytes, uint8_t format);
+
+// Serialize the boxed value into a new array of bytes of a given `SerializationFormat`.
+ValueBox_ArrayBox_u8 *boxer_size_i32_serialize(ValueBox_SizeBox_i32 *value, uint8_t format);
+
+// Create a new box with a value deserialized from an array of bytes of a given `SerializationFormat`.
+ValueBox_SizeBox_i32 *boxer_size_i32_deserialize(ValueBox_ArrayBox_u8 *bytes, uint8_t format);
+
+// Serialize the boxed value into a new array of bytes of a given `SerializationFormat`.
+ValueBox_ArrayBox_u8 *boxer_size_u32_serialize(ValueBox_SizeBox_u32 *value, uint8_t format);
+
+// Create a new box with a value deserialized from an array of bytes of a given `SerializationFormat`.
+ValueBox_SizeBox_u32 *boxer_size_u32_deserialize(ValueBox_ArrayBox_u8 *bytes, uint8_t format);
+
+// Serialize the boxed value into a new array of bytes of a given `SerializationFormat`.
+ValueBox_ArrayBox_u8 *boxer_size_u64_serialize(ValueBox_SizeBox_u64 *value, uint8_t format);
+
+// Create a new box with a value deserialized from an array of bytes of a given `SerializationFormat`.
+ValueBox_SizeBox_u64 *boxer_size_u64_deserialize(ValueBox_ArrayBox_u8 *bytes, uint8_t format);
+
+// Serialize the boxed value into a new array of bytes of a given `SerializationFormat`.
+ValueBox_ArrayBox_u8 *boxer_number_uint128_serialize(ValueBox_U128Box *value, uint8_t format);
+
+// Create a new box with a value deserialized from an array of bytes of a given `SerializationFormat`.
+ValueBox_U128Box *boxer_number_uint128_deserialize(ValueBox_ArrayBox_u8 *bytes, uint8_t format);
+
+#endif
+
+#if defined(VALUE_BOX_FFI_FEATURE_IMAGE_BOX)
+
+// Create an image of transparent black pixels of a given `PixelFormat` with tightly packed rows
+ValueBox_ImageBox *boxer_image_create(uint32_t width, uint32_t height, uint8_t format);
+
+// Create an image with a copy of the pixels of a given array whose rows are padded to a stride
+ValueBox_ImageBox *boxer_image_create_from_array(ValueBox_ArrayBox_u8 *array, uint32_t width, uint32_t height, size_t stride, uint8_t format);
+
+void boxer_image_drop(ValueBox_ImageBox *image);
+
+uint32_t boxer_image_get_width(ValueBox_ImageBox *image);
+
+uint32_t boxer_image_get_height(ValueBox_ImageBox *image);
+
+// Return a new size box with the width and height of an image
+ValueBox_SizeBox_u32 *boxer_image_get_size(ValueBox_ImageBox *image);
+
+// Return the amount of bytes between the starts of two rows
+size_t boxer_image_get_stride(ValueBox_ImageBox *image);
+
+// Return the `PixelFormat` of an image or 255, which is not a format, if the image is not there
+uint8_t boxer_image_get_format(ValueBox_ImageBox *image);
+
+// Return a pointer to the pixels of an image, it is valid until the image is dropped,
+// rotated or converted to a format of another pixel size
+uint8_t *boxer_image_get_data(ValueBox_ImageBox *image);
+
+// Return the amount of bytes of the pixels of an image
+size_t boxer_image_get_length(ValueBox_ImageBox *image);
+
+// Return a pixel as an `0xAARRGGBB` color, pixels without alpha are opaque
+uint32_t boxer_image_get_pixel(ValueBox_ImageBox *image, uint32_t x, uint32_t y);
+
+// Set a pixel to an `0xAARRGGBB` color, formats without alpha ignore the alpha
+void boxer_image_set_pixel(ValueBox_ImageBox *image, uint32_t x, uint32_t y, uint32_t argb);
+
+// Create a new image with tightly packed rows from a region of a given image
+ValueBox_ImageBox *boxer_image_crop(ValueBox_ImageBox *image, uint32_t x, uint32_t y, uint32_t width, uint32_t height);
+
+// Copy a region of a source image into a destination image converting the pixels
+// to the format of the destination, the source may be the destination.
+void boxer_image_copy_region(ValueBox_ImageBox *source, uint32_t x, uint32_t y, uint32_t width, uint32_t height, ValueBox_ImageBox *destination, uint32_t to_x, uint32_t to_y);
+
+// Mirror an image from left to right
+void boxer_image_flip_horizontally(ValueBox_ImageBox *image);
+
+// Mirror an image from top to bottom
+void boxer_image_flip_vertically(ValueBox_ImageBox *image);
+
+// Rotate an image by 90 degrees clockwise, its rows become tightly packed
+void boxer_image_rotate_clockwise(ValueBox_ImageBox *image);
+
+// Rotate an image by 90 degrees counter-clockwise, its rows become tightly packed
+void boxer_image_rotate_counter_clockwise(ValueBox_ImageBox *image);
+
+// Convert the pixels of an image to another `PixelFormat`, pixels without alpha get a given alpha
+void boxer_image_convert_format(ValueBox_ImageBox *image, uint8_t format, uint8_t alpha);
+
+BoxerStatus boxer_image_get_pixel_checked(ValueBox_ImageBox *image, uint32_t x, uint32_t y, uint32_t *argb);
+
+BoxerStatus boxer_image_set_pixel_checked(ValueBox_ImageBox *image, uint32_t x, uint32_t y, uint32_t argb);
+
+BoxerStatus boxer_image_copy_region_checked(ValueBox_ImageBox *source, uint32_t x, uint32_t y, uint32_t width, uint32_t height, ValueBox_ImageBox *destination, uint32_t to_x, uint32_t to_y);
+
+BoxerStatus boxer_image_convert_format_checked(ValueBox_ImageBox *image, uint8_t format, uint8_t alpha);
+
+#endif
+
+#if defined(VALUE_BOX_FFI_FEATURE_STRING_BOX)
+
+ValueBox_StringBox *boxer_string_create(void);
+
+// I copy the data (must *not* contain zero-byte).
+// length must not include the zero-byte
+ValueBox_StringBox *boxer_string_from_byte_string(const uint8_t *data, size_t length);
+
+// I copy the data (must *not* contain zero-byte).
+// length must not include the zero-byte
+ValueBox_StringBox *boxer_string_from_wide_string(const uint32_t *data, size_t length);
+
+// I copy the data (must contain zero-byte).
+// length must not include the zero-byte
+ValueBox_StringBox *boxer_string_from_utf8_string(const uint8_t *data, size_t length);
+
+void boxer_string_drop(ValueBox_StringBox *string_box);
+
+size_t boxer_string_get_len(ValueBox_StringBox *string_box);
+
+size_t boxer_string_get_char_count(ValueBox_StringBox *string_box);
+
+const uint8_t *boxer_string_get_ptr(ValueBox_StringBox *string_box);
+
+void boxer_string_char_index_to_byte_range(ValueBox_StringBox *string_ptr, size_t index, ValueBox_Range_usize *range_ptr);
+
+void boxer_string_char_index_to_utf16_range(ValueBox_StringBox *string_box, size_t index, ValueBox_Range_usize *range_ptr);
+
+size_t boxer_string_utf16_position_to_char_index(ValueBox_StringBox *string_box, size_t index);
+
+BoxerStatus boxer_string_get_len_checked(ValueBox_StringBox *string_box, size_t *length);
+
+BoxerStatus boxer_string_get_char_count_checked(ValueBox_StringBox *string_box, size_t *char_count);
+
+BoxerStatus boxer_string_get_ptr_checked(ValueBox_StringBox *string_box, const uint8_t **data);
+
+BoxerStatus boxer_string_utf16_position_to_char_index_checked(ValueBox_StringBox *string_box, size_t index, size_t *char_index);
+
+#endif
+
+#if defined(VALUE_BOX_FFI_FEATURE_STRING_BOX) && defined(VALUE_BOX_FFI_FEATURE_SERDE)
+
+// Serialize the boxed value into a new array of bytes of a given `SerializationFormat`.
+ValueBox_ArrayBox_u8 *boxer_string_serialize(ValueBox_StringBox *value, uint8_t format);
+
+// Create a new box with a value deserialized from an array of bytes of a given `SerializationFormat`.
+ValueBox_StringBox *boxer_string_deserialize(ValueBox_ArrayBox_u8 *bytes, uint8_t format);
+
+#endif
+
+#if defined(VALUE_BOX_FFI_FEATURE_VALUE_BOX)
+
+const void *boxer_value_box_get_pointer(ValueBox *ptr);
+
+bool boxer_value_box_is_valid(ValueBox *ptr);
+
+// Create another box sharing the value with a given one, or return null if
+// the box was not created shared. The value is dropped once all boxes sharing it are released.
+ValueBox *boxer_value_box_retain(ValueBox *ptr);
+
+// Create a weak box that refers to the value of a given box without keeping it alive.
+// The weak box must be released.
+ValueBox *boxer_value_box_downgrade(ValueBox *ptr);
+
+// Create a strong box from a weak one, or return null if the value is gone
+// or the box is not weak.
+ValueBox *boxer_value_box_upgrade(ValueBox *ptr);
+
+// Create a callback that calls a given function with the user data and an argument.
+// The destructor, if not null, is called with the user data once the callback is dropped.
+// Return null if the affinity is not a `CallbackAffinity`, the user data is not released then.
+ValueBox_CallbackBox *boxer_callback_create(CallbackFunction callback, void *user_data, CallbackDestructor destructor, uint8_t affinity);
+
+void boxer_callback_call(ValueBox_CallbackBox *callback, void *argument);
+
+CallbackAffinity boxer_callback_get_affinity(ValueBox_CallbackBox *callback);
+
+void boxer_callback_drop(ValueBox_CallbackBox *callback);
+
+#endif
+
+#if defined(VALUE_BOX_FFI_FEATURE_VALUE_BOX) && defined(VALUE_BOX_FFI_FEATURE_CENSUS)
+
+// Start or stop capturing a backtrace for every box passed to the foreign side.
+void boxer_census_capture_backtraces(bool enabled);
+
+// Return the amount of boxes that were not released yet.
+size_t boxer_census_live_count(void);
+
+// Return the amount of distinct types of live boxes.
+size_t boxer_census_type_count(void);
+
+// Return the amount of live boxes holding a value of a given type.
+// The type name must be a UTF-8 string without zero-byte.
+size_t boxer_census_live_count_of_type(const uint8_t *type_name, size_t length);
+
+// Describe all live boxes, including their creation backtraces if captured.
+ValueBox_StringBox *boxer_census_dump(void);
+
+#endif
+
+#if defined(VALUE_BOX_FFI_FEATURE_VALUE_BOX)
+
+// Return the status of the last error that happened on the current thread
+// or 0 (`BoxerStatus::Ok`) if there was no error since it was last cleared.
+BoxerStatus boxer_last_error_status(void);
+
+// Write the kind of the error reported by a given status into `kind`.
+// Return false if the status is 0 (`BoxerStatus::Ok`) or unknown, in which case `kind` is left untouched.
+bool boxer_status_error_kind(BoxerStatus status, BoxerErrorKind *kind);
+
+#endif
+
+#if defined(VALUE_BOX_FFI_FEATURE_VALUE_BOX) && defined(VALUE_BOX_FFI_FEATURE_STRING_BOX)
+
+// Return the description of the last error that happened on the current thread
+// or null if there was no error since it was last cleared.
+ValueBox_StringBox *boxer_last_error_message(void);
+
+#endif
+
+#if defined(VALUE_BOX_FFI_FEATURE_VALUE_BOX)
+
+// Forget the last error that happened on the current thread.
+void boxer_last_error_clear(void);
+
+// Call a given function with the user data whenever the future can make progress
+// and should be polled again. The callback may be called from any thread.
+// It should be registered before the future is polled for the first time.
+void boxer_future_set_wake_callback(ValueBox_FutureBox *future, FutureWakeCallback callback, void *user_data);
+
+// Poll the future once. A future that can not be polled is reported as `FutureBoxState_Taken`.
+FutureBoxState boxer_future_poll(ValueBox_FutureBox *future);
+
+bool boxer_future_is_ready(ValueBox_FutureBox *future);
+
+// Take the result of a complete future as a box of its output type,
+// or return null if the future is not complete yet.
+ValueBox *boxer_future_take_result(ValueBox_FutureBox *future);
+
+void boxer_future_drop(ValueBox_FutureBox *future);
+
+#endif
+
+bool boxer_test(void);
+
+// Return a new box with the phlow object describing the value of a given box,
+// or null if there is no value or the library was built without the `phlow` feature.
+void *boxer_value_box_to_phlow_object(ValueBox *value_box);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* VALUE_BOX_FFI_H */
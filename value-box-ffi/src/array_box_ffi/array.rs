@@ -1,7 +1,12 @@
-use std::any::Any;
+use std::any::{type_name, Any};
+use std::ffi::c_void;
+use std::mem::size_of;
 
-use array_box::ArrayBox;
-use value_box::{BoxerError, ReturnBoxerResult, ValueBox, ValueBoxPointer};
+use array_box::{ArrayBox, ArrayBoxRelease};
+use value_box::{
+    catch_panic, BoxerError, BoxerStatus, Result, ReturnBoxerResult, ReturnBoxerStatus, ValueBox,
+    ValueBoxIntoRaw, ValueBoxPointer,
+};
 
 pub trait ArrayBoxFFI<T>
 where
@@ -13,6 +18,17 @@ where
 
     fn boxer_array_create_from_data(_data: *mut T, amount: usize) -> *mut ValueBox<ArrayBox<T>>;
 
+    fn boxer_array_create_from_foreign_data(
+        _data: *mut T,
+        amount: usize,
+        release: ArrayBoxRelease,
+        release_data: *mut c_void,
+    ) -> *mut ValueBox<ArrayBox<T>>;
+
+    fn boxer_array_invalidate(_maybe_null_ptr: *mut ValueBox<ArrayBox<T>>);
+
+    fn boxer_array_is_valid(_maybe_null_ptr: *mut ValueBox<ArrayBox<T>>) -> bool;
+
     fn boxer_array_drop(ptr: *mut ValueBox<ArrayBox<T>>);
 
     fn boxer_array_copy_into(
@@ -39,6 +55,137 @@ where
     fn boxer_array_at(_maybe_null_ptr: *mut ValueBox<ArrayBox<T>>, index: usize, default: T) -> T
     where
         T: Clone;
+
+    fn boxer_array_push(_maybe_null_ptr: *mut ValueBox<ArrayBox<T>>, item: T);
+
+    fn boxer_array_pop(_maybe_null_ptr: *mut ValueBox<ArrayBox<T>>, default: T) -> T;
+
+    fn boxer_array_insert(_maybe_null_ptr: *mut ValueBox<ArrayBox<T>>, index: usize, item: T);
+
+    fn boxer_array_remove(
+        _maybe_null_ptr: *mut ValueBox<ArrayBox<T>>,
+        index: usize,
+        default: T,
+    ) -> T;
+
+    fn boxer_array_truncate(_maybe_null_ptr: *mut ValueBox<ArrayBox<T>>, length: usize);
+
+    fn boxer_array_resize(_maybe_null_ptr: *mut ValueBox<ArrayBox<T>>, length: usize, item: T);
+
+    fn boxer_array_reserve(_maybe_null_ptr: *mut ValueBox<ArrayBox<T>>, additional: usize);
+
+    fn boxer_array_extend_from_slice(
+        _maybe_null_ptr: *mut ValueBox<ArrayBox<T>>,
+        _data: *const T,
+        length: usize,
+    );
+
+    fn boxer_array_copy_range_into(
+        _maybe_null_source_ptr: *mut ValueBox<ArrayBox<T>>,
+        start: usize,
+        end: usize,
+        _maybe_null_destination_ptr: *mut ValueBox<ArrayBox<T>>,
+        index: usize,
+    );
+
+    fn boxer_array_fill(
+        _maybe_null_ptr: *mut ValueBox<ArrayBox<T>>,
+        start: usize,
+        end: usize,
+        item: T,
+    );
+
+    fn boxer_array_reverse(_maybe_null_ptr: *mut ValueBox<ArrayBox<T>>);
+
+    fn boxer_array_rotate_left(_maybe_null_ptr: *mut ValueBox<ArrayBox<T>>, amount: usize);
+
+    fn boxer_array_rotate_right(_maybe_null_ptr: *mut ValueBox<ArrayBox<T>>, amount: usize);
+
+    fn boxer_array_sub_array(
+        _maybe_null_ptr: *mut ValueBox<ArrayBox<T>>,
+        start: usize,
+        end: usize,
+    ) -> *mut ValueBox<ArrayBox<T>>;
+
+    fn boxer_array_copy_into_checked(
+        _maybe_null_source_ptr: *mut ValueBox<ArrayBox<T>>,
+        _maybe_null_destination_ptr: *mut ValueBox<ArrayBox<T>>,
+    ) -> BoxerStatus;
+
+    fn boxer_array_copy_into_data_checked(
+        _maybe_null_source_ptr: *mut ValueBox<ArrayBox<T>>,
+        _destination_data: *mut T,
+        length: usize,
+    ) -> BoxerStatus;
+
+    fn boxer_array_get_length_checked(
+        _maybe_null_ptr: *mut ValueBox<ArrayBox<T>>,
+        length: *mut usize,
+    ) -> BoxerStatus;
+
+    fn boxer_array_get_capacity_checked(
+        _maybe_null_ptr: *mut ValueBox<ArrayBox<T>>,
+        capacity: *mut usize,
+    ) -> BoxerStatus;
+
+    fn boxer_array_get_data_checked(
+        _maybe_null_ptr: *mut ValueBox<ArrayBox<T>>,
+        data: *mut *mut T,
+    ) -> BoxerStatus;
+
+    fn boxer_array_at_put_checked(
+        _maybe_null_ptr: *mut ValueBox<ArrayBox<T>>,
+        index: usize,
+        item: T,
+    ) -> BoxerStatus;
+
+    fn boxer_array_at_checked(
+        _maybe_null_ptr: *mut ValueBox<ArrayBox<T>>,
+        index: usize,
+        item: *mut T,
+    ) -> BoxerStatus;
+
+    fn boxer_array_pop_checked(
+        _maybe_null_ptr: *mut ValueBox<ArrayBox<T>>,
+        item: *mut T,
+    ) -> BoxerStatus;
+
+    fn boxer_array_insert_checked(
+        _maybe_null_ptr: *mut ValueBox<ArrayBox<T>>,
+        index: usize,
+        item: T,
+    ) -> BoxerStatus;
+
+    fn boxer_array_remove_checked(
+        _maybe_null_ptr: *mut ValueBox<ArrayBox<T>>,
+        index: usize,
+        item: *mut T,
+    ) -> BoxerStatus;
+
+    fn boxer_array_copy_range_into_checked(
+        _maybe_null_source_ptr: *mut ValueBox<ArrayBox<T>>,
+        start: usize,
+        end: usize,
+        _maybe_null_destination_ptr: *mut ValueBox<ArrayBox<T>>,
+        index: usize,
+    ) -> BoxerStatus;
+
+    fn boxer_array_fill_checked(
+        _maybe_null_ptr: *mut ValueBox<ArrayBox<T>>,
+        start: usize,
+        end: usize,
+        item: T,
+    ) -> BoxerStatus;
+
+    fn boxer_array_rotate_left_checked(
+        _maybe_null_ptr: *mut ValueBox<ArrayBox<T>>,
+        amount: usize,
+    ) -> BoxerStatus;
+
+    fn boxer_array_rotate_right_checked(
+        _maybe_null_ptr: *mut ValueBox<ArrayBox<T>>,
+        amount: usize,
+    ) -> BoxerStatus;
 }
 
 impl<T> ArrayBoxFFI<T> for ArrayBox<T>
@@ -54,11 +201,42 @@ where
     }
 
     fn boxer_array_create_with(element: T, amount: usize) -> *mut ValueBox<ArrayBox<T>> {
-        ValueBox::new(ArrayBox::<T>::from_vector(vec![element; amount])).into_raw()
+        catch_panic(|| {
+            let vector = vec![element; amount];
+            Ok(ValueBox::new(ArrayBox::<T>::from_vector(vector)))
+        })
+        .into_raw()
     }
 
     fn boxer_array_create_from_data(_data: *mut T, amount: usize) -> *mut ValueBox<ArrayBox<T>> {
-        ValueBox::new(ArrayBox::<T>::from_data(_data, amount)).into_raw()
+        catch_panic(|| Ok(ValueBox::new(ArrayBox::<T>::from_data(_data, amount)))).into_raw()
+    }
+
+    fn boxer_array_create_from_foreign_data(
+        _data: *mut T,
+        amount: usize,
+        release: ArrayBoxRelease,
+        release_data: *mut c_void,
+    ) -> *mut ValueBox<ArrayBox<T>> {
+        catch_panic(|| {
+            Ok(ValueBox::new(ArrayBox::<T>::from_foreign_data(
+                _data,
+                amount,
+                release,
+                release_data,
+            )))
+        })
+        .into_raw()
+    }
+
+    fn boxer_array_invalidate(array_box: *mut ValueBox<ArrayBox<T>>) {
+        array_box.with_mut_ok(|array| array.invalidate()).log();
+    }
+
+    fn boxer_array_is_valid(array_box: *mut ValueBox<ArrayBox<T>>) -> bool {
+        array_box
+            .with_ref_ok(|array| array.is_valid())
+            .or_log(false)
     }
 
     fn boxer_array_drop(ptr: *mut ValueBox<ArrayBox<T>>) {
@@ -69,13 +247,7 @@ where
         source_array: *mut ValueBox<ArrayBox<T>>,
         destination_array: *mut ValueBox<ArrayBox<T>>,
     ) {
-        source_array
-            .with_ref(|source_array| {
-                destination_array.with_mut_ok(|destination_array| {
-                    source_array.copy_into(destination_array);
-                })
-            })
-            .log();
+        copy_into(source_array, destination_array).log();
     }
 
     fn boxer_array_copy_into_data(
@@ -83,32 +255,7 @@ where
         destination_data: *mut T,
         length: usize,
     ) {
-        source_array
-            .with_ref(|source_array| {
-                if source_array.length > length {
-                    BoxerError::AnyError(
-                        format!(
-                            "The source (len = {}) does not fit into destination (len = {})",
-                            source_array.length, length
-                        )
-                        .into(),
-                    )
-                    .into()
-                } else if source_array.data.is_null() {
-                    BoxerError::AnyError("The source data must not be nil".into()).into()
-                } else if destination_data.is_null() {
-                    BoxerError::AnyError("The destination data must not be nil".into()).into()
-                } else {
-                    Ok(unsafe {
-                        std::ptr::copy_nonoverlapping::<T>(
-                            source_array.data,
-                            destination_data,
-                            length,
-                        )
-                    })
-                }
-            })
-            .log();
+        copy_into_data(source_array, destination_data, length).log();
     }
 
     fn boxer_array_get_length(array_box: *mut ValueBox<ArrayBox<T>>) -> usize {
@@ -120,27 +267,483 @@ where
     }
 
     fn boxer_array_get_data(array_box: *mut ValueBox<ArrayBox<T>>) -> *mut T {
-        array_box
-            .with_ref_ok(|array| array.data)
-            .or_log(std::ptr::null_mut())
+        get_data(array_box).or_log(std::ptr::null_mut())
     }
 
     fn boxer_array_at_put(array_box: *mut ValueBox<ArrayBox<T>>, index: usize, item: T)
     where
         T: Clone,
     {
-        array_box
-            .with_mut_ok(|array| array.at_put(index, item))
-            .log();
+        at_put(array_box, index, item).log();
     }
 
     fn boxer_array_at(array_box: *mut ValueBox<ArrayBox<T>>, index: usize, default: T) -> T
     where
         T: Clone,
     {
+        at(array_box, index).or_log(default)
+    }
+
+    fn boxer_array_push(array_box: *mut ValueBox<ArrayBox<T>>, item: T) {
+        grow(array_box, |array| array.push(item)).log();
+    }
+
+    fn boxer_array_pop(array_box: *mut ValueBox<ArrayBox<T>>, default: T) -> T {
+        pop(array_box).or_log(default)
+    }
+
+    fn boxer_array_insert(array_box: *mut ValueBox<ArrayBox<T>>, index: usize, item: T) {
+        insert(array_box, index, item).log();
+    }
+
+    fn boxer_array_remove(array_box: *mut ValueBox<ArrayBox<T>>, index: usize, default: T) -> T {
+        remove(array_box, index).or_log(default)
+    }
+
+    fn boxer_array_truncate(array_box: *mut ValueBox<ArrayBox<T>>, length: usize) {
+        grow(array_box, |array| array.truncate(length)).log();
+    }
+
+    fn boxer_array_resize(array_box: *mut ValueBox<ArrayBox<T>>, length: usize, item: T) {
+        grow(array_box, |array| array.resize(length, item)).log();
+    }
+
+    fn boxer_array_reserve(array_box: *mut ValueBox<ArrayBox<T>>, additional: usize) {
+        grow(array_box, |array| array.reserve(additional)).log();
+    }
+
+    fn boxer_array_extend_from_slice(
+        array_box: *mut ValueBox<ArrayBox<T>>,
+        data: *const T,
+        length: usize,
+    ) {
+        extend_from_slice(array_box, data, length).log();
+    }
+
+    fn boxer_array_copy_range_into(
+        source_array: *mut ValueBox<ArrayBox<T>>,
+        start: usize,
+        end: usize,
+        destination_array: *mut ValueBox<ArrayBox<T>>,
+        index: usize,
+    ) {
+        copy_range_into(source_array, start, end, destination_array, index).log();
+    }
+
+    fn boxer_array_fill(array_box: *mut ValueBox<ArrayBox<T>>, start: usize, end: usize, item: T) {
+        fill(array_box, start, end, item).log();
+    }
+
+    fn boxer_array_reverse(array_box: *mut ValueBox<ArrayBox<T>>) {
         array_box
-            .with_ref_ok(|array| array.at(index))
-            .or_log(default)
+            .with_mut(|array| check_valid(array).map(|_| array.reverse()))
+            .log();
+    }
+
+    fn boxer_array_rotate_left(array_box: *mut ValueBox<ArrayBox<T>>, amount: usize) {
+        rotate(array_box, amount, ArrayBox::rotate_left).log();
+    }
+
+    fn boxer_array_rotate_right(array_box: *mut ValueBox<ArrayBox<T>>, amount: usize) {
+        rotate(array_box, amount, ArrayBox::rotate_right).log();
+    }
+
+    fn boxer_array_sub_array(
+        array_box: *mut ValueBox<ArrayBox<T>>,
+        start: usize,
+        end: usize,
+    ) -> *mut ValueBox<ArrayBox<T>> {
+        sub_array(array_box, start, end).into_raw()
+    }
+
+    fn boxer_array_copy_into_checked(
+        source_array: *mut ValueBox<ArrayBox<T>>,
+        destination_array: *mut ValueBox<ArrayBox<T>>,
+    ) -> BoxerStatus {
+        copy_into(source_array, destination_array).status()
+    }
+
+    fn boxer_array_copy_into_data_checked(
+        source_array: *mut ValueBox<ArrayBox<T>>,
+        destination_data: *mut T,
+        length: usize,
+    ) -> BoxerStatus {
+        copy_into_data(source_array, destination_data, length).status()
+    }
+
+    fn boxer_array_get_length_checked(
+        array_box: *mut ValueBox<ArrayBox<T>>,
+        length: *mut usize,
+    ) -> BoxerStatus {
+        array_box.with_ref_ok(|array| array.length).write_to(length)
+    }
+
+    fn boxer_array_get_capacity_checked(
+        array_box: *mut ValueBox<ArrayBox<T>>,
+        capacity: *mut usize,
+    ) -> BoxerStatus {
+        array_box
+            .with_ref_ok(|array| array.capacity)
+            .write_to(capacity)
+    }
+
+    fn boxer_array_get_data_checked(
+        array_box: *mut ValueBox<ArrayBox<T>>,
+        data: *mut *mut T,
+    ) -> BoxerStatus {
+        get_data(array_box).write_to(data)
+    }
+
+    fn boxer_array_at_put_checked(
+        array_box: *mut ValueBox<ArrayBox<T>>,
+        index: usize,
+        item: T,
+    ) -> BoxerStatus {
+        at_put(array_box, index, item).status()
+    }
+
+    fn boxer_array_at_checked(
+        array_box: *mut ValueBox<ArrayBox<T>>,
+        index: usize,
+        item: *mut T,
+    ) -> BoxerStatus {
+        at(array_box, index).write_to(item)
+    }
+
+    fn boxer_array_pop_checked(array_box: *mut ValueBox<ArrayBox<T>>, item: *mut T) -> BoxerStatus {
+        pop(array_box).write_to(item)
+    }
+
+    fn boxer_array_insert_checked(
+        array_box: *mut ValueBox<ArrayBox<T>>,
+        index: usize,
+        item: T,
+    ) -> BoxerStatus {
+        insert(array_box, index, item).status()
+    }
+
+    fn boxer_array_remove_checked(
+        array_box: *mut ValueBox<ArrayBox<T>>,
+        index: usize,
+        item: *mut T,
+    ) -> BoxerStatus {
+        remove(array_box, index).write_to(item)
+    }
+
+    fn boxer_array_copy_range_into_checked(
+        source_array: *mut ValueBox<ArrayBox<T>>,
+        start: usize,
+        end: usize,
+        destination_array: *mut ValueBox<ArrayBox<T>>,
+        index: usize,
+    ) -> BoxerStatus {
+        copy_range_into(source_array, start, end, destination_array, index).status()
+    }
+
+    fn boxer_array_fill_checked(
+        array_box: *mut ValueBox<ArrayBox<T>>,
+        start: usize,
+        end: usize,
+        item: T,
+    ) -> BoxerStatus {
+        fill(array_box, start, end, item).status()
+    }
+
+    fn boxer_array_rotate_left_checked(
+        array_box: *mut ValueBox<ArrayBox<T>>,
+        amount: usize,
+    ) -> BoxerStatus {
+        rotate(array_box, amount, ArrayBox::rotate_left).status()
+    }
+
+    fn boxer_array_rotate_right_checked(
+        array_box: *mut ValueBox<ArrayBox<T>>,
+        amount: usize,
+    ) -> BoxerStatus {
+        rotate(array_box, amount, ArrayBox::rotate_right).status()
+    }
+}
+
+/// Borrow the foreign data as an array box for the scope of a call,
+/// the array is invalidated afterwards even if the callee kept a view of it.
+pub fn with_borrowed_array<T: Any, R>(
+    data: *mut T,
+    length: usize,
+    op: impl FnOnce(*mut ValueBox<ArrayBox<T>>) -> Result<R>,
+) -> Result<R> {
+    let array_box = ValueBox::new(ArrayBox::from_data(data, length)).into_raw();
+    let result = catch_panic(|| op(array_box));
+    array_box.with_mut_ok(|array| array.invalidate())?;
+    array_box.release();
+    result
+}
+
+/// Fail with [`BoxerError::Invalidated`] instead of accessing the data freed by its foreign owner.
+pub(crate) fn check_valid<T: Any>(array: &ArrayBox<T>) -> Result<()> {
+    if array.is_valid() {
+        Ok(())
+    } else {
+        BoxerError::Invalidated(type_name::<ArrayBox<T>>().to_string()).into()
+    }
+}
+
+/// The buffer of an array must stay in place while there are views of it, see [`ArrayBox::sub_array`].
+fn check_not_viewed<T: Any>(array: &ArrayBox<T>) -> Result<()> {
+    if array.has_views() {
+        BoxerError::AlreadyBorrowed(type_name::<ArrayBox<T>>().to_string()).into()
+    } else {
+        Ok(())
+    }
+}
+
+fn check_index<T>(array: &ArrayBox<T>, index: usize) -> Result<()> {
+    if index < array.length {
+        Ok(())
+    } else {
+        BoxerError::OutOfBounds {
+            index,
+            length: array.length,
+        }
+        .into()
+    }
+}
+
+fn get_data<T: Any>(array_box: *mut ValueBox<ArrayBox<T>>) -> Result<*mut T> {
+    array_box.with_ref(|array| check_valid(array).map(|_| array.data))
+}
+
+fn at<T: Copy + Any>(array_box: *mut ValueBox<ArrayBox<T>>, index: usize) -> Result<T> {
+    array_box.with_ref(|array| {
+        check_valid(array)?;
+        check_index(array, index).map(|_| array.at(index))
+    })
+}
+
+fn at_put<T: Any>(array_box: *mut ValueBox<ArrayBox<T>>, index: usize, item: T) -> Result<()> {
+    array_box.with_mut(|array| {
+        check_valid(array)?;
+        check_index(array, index).map(|_| array.at_put(index, item))
+    })
+}
+
+/// Change the length or capacity of a valid array,
+/// a capacity overflow is reported as an error instead of unwinding into the foreign code.
+/// A failed allocation aborts the process like everywhere else in Rust.
+fn grow<T: Any, R: Any>(
+    array_box: *mut ValueBox<ArrayBox<T>>,
+    op: impl FnOnce(&mut ArrayBox<T>) -> R,
+) -> Result<R> {
+    array_box.with_mut(|array| {
+        check_valid(array)?;
+        check_not_viewed(array)?;
+        catch_panic(|| Ok(op(array)))
+    })
+}
+
+fn pop<T: Copy + Any>(array_box: *mut ValueBox<ArrayBox<T>>) -> Result<T> {
+    grow(array_box, |array| array.pop())?
+        .ok_or_else(|| BoxerError::NoValue(type_name::<ArrayBox<T>>().to_string()))
+}
+
+fn insert<T: Copy + Any>(
+    array_box: *mut ValueBox<ArrayBox<T>>,
+    index: usize,
+    item: T,
+) -> Result<()> {
+    array_box.with_mut(|array| {
+        check_valid(array)?;
+        check_not_viewed(array)?;
+        if index > array.length {
+            return BoxerError::OutOfBounds {
+                index,
+                length: array.length,
+            }
+            .into();
+        }
+        catch_panic(|| {
+            array.insert(index, item);
+            Ok(())
+        })
+    })
+}
+
+fn remove<T: Copy + Any>(array_box: *mut ValueBox<ArrayBox<T>>, index: usize) -> Result<T> {
+    array_box.with_mut(|array| {
+        check_valid(array)?;
+        check_not_viewed(array)?;
+        check_index(array, index).map(|_| array.remove(index))
+    })
+}
+
+fn extend_from_slice<T: Copy + Any>(
+    array_box: *mut ValueBox<ArrayBox<T>>,
+    data: *const T,
+    length: usize,
+) -> Result<()> {
+    if length == 0 {
+        return Ok(());
+    }
+    if data.is_null() {
+        return BoxerError::AnyError("The source data must not be nil".into()).into();
+    }
+    grow(array_box, |array| {
+        // the buffer moves when it grows, items within it are copied out first
+        if overlaps(array, data, length) {
+            let items = unsafe { std::slice::from_raw_parts(data, length) }.to_vec();
+            array.extend_from_slice(&items)
+        } else {
+            array.extend_from_slice(unsafe { std::slice::from_raw_parts(data, length) })
+        }
+    })
+}
+
+/// Return true if the given items are within the buffer of an array.
+fn overlaps<T>(array: &ArrayBox<T>, data: *const T, length: usize) -> bool {
+    let buffer = array.data as *const T as usize;
+    let buffer_end = buffer + array.capacity * size_of::<T>();
+    let items = data as usize;
+    let items_end = items + length * size_of::<T>();
+    items < buffer_end && buffer < items_end
+}
+
+fn check_range<T>(array: &ArrayBox<T>, start: usize, end: usize) -> Result<()> {
+    if end > array.length {
+        BoxerError::OutOfBounds {
+            index: end,
+            length: array.length,
+        }
+        .into()
+    } else if start > end {
+        BoxerError::OutOfBounds {
+            index: start,
+            length: end,
+        }
+        .into()
+    } else {
+        Ok(())
+    }
+}
+
+fn copy_range_into<T: Copy + Any>(
+    source_array: *mut ValueBox<ArrayBox<T>>,
+    start: usize,
+    end: usize,
+    destination_array: *mut ValueBox<ArrayBox<T>>,
+    index: usize,
+) -> Result<()> {
+    let destination_end = index.saturating_add(end.saturating_sub(start));
+
+    // the same array can not be borrowed twice, the ranges may overlap
+    if source_array == destination_array {
+        return source_array.with_mut(|array| {
+            check_valid(array)?;
+            check_range(array, start, end)?;
+            check_range(array, index, destination_end)?;
+            array.copy_within(start..end, index);
+            Ok(())
+        });
+    }
+
+    source_array.with_ref(|source_array| {
+        destination_array.with_mut(|destination_array| {
+            check_valid(source_array)?;
+            check_valid(destination_array)?;
+            check_range(source_array, start, end)?;
+            check_range(destination_array, index, destination_end)?;
+            source_array.copy_range_into(start..end, destination_array, index);
+            Ok(())
+        })
+    })
+}
+
+fn fill<T: Copy + Any>(
+    array_box: *mut ValueBox<ArrayBox<T>>,
+    start: usize,
+    end: usize,
+    item: T,
+) -> Result<()> {
+    array_box.with_mut(|array| {
+        check_valid(array)?;
+        check_range(array, start, end).map(|_| array.fill(start..end, item))
+    })
+}
+
+fn rotate<T: Copy + Any>(
+    array_box: *mut ValueBox<ArrayBox<T>>,
+    amount: usize,
+    op: fn(&mut ArrayBox<T>, usize),
+) -> Result<()> {
+    array_box.with_mut(|array| {
+        check_valid(array)?;
+        check_range(array, 0, amount).map(|_| op(array, amount))
+    })
+}
+
+/// The parent array can not be resized while the view exists,
+/// the view is invalidated once the parent is dropped or invalidated.
+fn sub_array<T: Copy + Any>(
+    array_box: *mut ValueBox<ArrayBox<T>>,
+    start: usize,
+    end: usize,
+) -> Result<ValueBox<ArrayBox<T>>> {
+    array_box.with_mut(|array| {
+        check_valid(array)?;
+        check_range(array, start, end).map(|_| ValueBox::new(array.sub_array(start..end)))
+    })
+}
+
+fn copy_into<T: Any>(
+    source_array: *mut ValueBox<ArrayBox<T>>,
+    destination_array: *mut ValueBox<ArrayBox<T>>,
+) -> Result<()> {
+    source_array.with_ref(|source_array| {
+        destination_array.with_mut(|destination_array| {
+            check_valid(destination_array)?;
+            copy_array_into(
+                source_array,
+                destination_array.data,
+                destination_array.length,
+            )
+        })
+    })
+}
+
+fn copy_into_data<T: Any>(
+    source_array: *mut ValueBox<ArrayBox<T>>,
+    destination_data: *mut T,
+    length: usize,
+) -> Result<()> {
+    source_array.with_ref(|source_array| copy_array_into(source_array, destination_data, length))
+}
+
+fn copy_array_into<T: Any>(
+    source_array: &ArrayBox<T>,
+    destination_data: *mut T,
+    length: usize,
+) -> Result<()> {
+    check_valid(source_array)?;
+    if source_array.length > length {
+        BoxerError::AnyError(
+            format!(
+                "The source (len = {}) does not fit into destination (len = {})",
+                source_array.length, length
+            )
+            .into(),
+        )
+        .into()
+    } else if source_array.data.is_null() {
+        BoxerError::AnyError("The source data must not be nil".into()).into()
+    } else if destination_data.is_null() {
+        BoxerError::AnyError("The destination data must not be nil".into()).into()
+    } else {
+        unsafe {
+            std::ptr::copy_nonoverlapping::<T>(
+                source_array.data,
+                destination_data,
+                source_array.length,
+            )
+        };
+        Ok(())
     }
 }
 
@@ -217,10 +820,507 @@ macro_rules! array_ffi {
                 <array_box::ArrayBox<$ty> as self::array::ArrayBoxFFI::<$ty>>::boxer_array_at_put(array, index, item);
             }
 
+            #[no_mangle]
+            pub extern "C" fn [<boxer_array_ $name _push>](array: *mut value_box::ValueBox<array_box::ArrayBox<$ty>>, item: $ty) {
+                <array_box::ArrayBox<$ty> as self::array::ArrayBoxFFI::<$ty>>::boxer_array_push(array, item);
+            }
+
+            #[no_mangle]
+            pub extern "C" fn [<boxer_array_ $name _pop>](array: *mut value_box::ValueBox<array_box::ArrayBox<$ty>>) -> $ty {
+                <array_box::ArrayBox<$ty> as self::array::ArrayBoxFFI::<$ty>>::boxer_array_pop(array, $default)
+            }
+
+            #[no_mangle]
+            pub extern "C" fn [<boxer_array_ $name _insert>](
+                array: *mut value_box::ValueBox<array_box::ArrayBox<$ty>>,
+                index: usize,
+                item: $ty,
+            ) {
+                <array_box::ArrayBox<$ty> as self::array::ArrayBoxFFI::<$ty>>::boxer_array_insert(array, index, item);
+            }
+
+            #[no_mangle]
+            pub extern "C" fn [<boxer_array_ $name _remove>](array: *mut value_box::ValueBox<array_box::ArrayBox<$ty>>, index: usize) -> $ty {
+                <array_box::ArrayBox<$ty> as self::array::ArrayBoxFFI::<$ty>>::boxer_array_remove(array, index, $default)
+            }
+
+            #[no_mangle]
+            pub extern "C" fn [<boxer_array_ $name _truncate>](array: *mut value_box::ValueBox<array_box::ArrayBox<$ty>>, length: usize) {
+                <array_box::ArrayBox<$ty> as self::array::ArrayBoxFFI::<$ty>>::boxer_array_truncate(array, length);
+            }
+
+            #[no_mangle]
+            pub extern "C" fn [<boxer_array_ $name _resize>](
+                array: *mut value_box::ValueBox<array_box::ArrayBox<$ty>>,
+                length: usize,
+                item: $ty,
+            ) {
+                <array_box::ArrayBox<$ty> as self::array::ArrayBoxFFI::<$ty>>::boxer_array_resize(array, length, item);
+            }
+
+            #[no_mangle]
+            pub extern "C" fn [<boxer_array_ $name _reserve>](array: *mut value_box::ValueBox<array_box::ArrayBox<$ty>>, additional: usize) {
+                <array_box::ArrayBox<$ty> as self::array::ArrayBoxFFI::<$ty>>::boxer_array_reserve(array, additional);
+            }
+
+            #[no_mangle]
+            pub extern "C" fn [<boxer_array_ $name _extend_from_slice>](
+                array: *mut value_box::ValueBox<array_box::ArrayBox<$ty>>,
+                data: *const $ty,
+                amount: usize,
+            ) {
+                <array_box::ArrayBox<$ty> as self::array::ArrayBoxFFI::<$ty>>::boxer_array_extend_from_slice(array, data, amount);
+            }
+
+            #[no_mangle]
+            pub extern "C" fn [<boxer_array_ $name _pop_checked>](
+                array: *mut value_box::ValueBox<array_box::ArrayBox<$ty>>,
+                item: *mut $ty,
+            ) -> value_box::BoxerStatus {
+                <array_box::ArrayBox<$ty> as self::array::ArrayBoxFFI::<$ty>>::boxer_array_pop_checked(array, item)
+            }
+
+            #[no_mangle]
+            pub extern "C" fn [<boxer_array_ $name _insert_checked>](
+                array: *mut value_box::ValueBox<array_box::ArrayBox<$ty>>,
+                index: usize,
+                item: $ty,
+            ) -> value_box::BoxerStatus {
+                <array_box::ArrayBox<$ty> as self::array::ArrayBoxFFI::<$ty>>::boxer_array_insert_checked(array, index, item)
+            }
+
+            #[no_mangle]
+            pub extern "C" fn [<boxer_array_ $name _remove_checked>](
+                array: *mut value_box::ValueBox<array_box::ArrayBox<$ty>>,
+                index: usize,
+                item: *mut $ty,
+            ) -> value_box::BoxerStatus {
+                <array_box::ArrayBox<$ty> as self::array::ArrayBoxFFI::<$ty>>::boxer_array_remove_checked(array, index, item)
+            }
+
+            #[no_mangle]
+            pub extern "C" fn [<boxer_array_ $name _copy_range_into>](
+                src: *mut value_box::ValueBox<array_box::ArrayBox<$ty>>,
+                start: usize,
+                end: usize,
+                dst: *mut value_box::ValueBox<array_box::ArrayBox<$ty>>,
+                index: usize,
+            ) {
+                <array_box::ArrayBox<$ty> as self::array::ArrayBoxFFI::<$ty>>::boxer_array_copy_range_into(src, start, end, dst, index);
+            }
+
+            #[no_mangle]
+            pub extern "C" fn [<boxer_array_ $name _fill>](
+                array: *mut value_box::ValueBox<array_box::ArrayBox<$ty>>,
+                start: usize,
+                end: usize,
+                item: $ty,
+            ) {
+                <array_box::ArrayBox<$ty> as self::array::ArrayBoxFFI::<$ty>>::boxer_array_fill(array, start, end, item);
+            }
+
+            #[no_mangle]
+            pub extern "C" fn [<boxer_array_ $name _reverse>](array: *mut value_box::ValueBox<array_box::ArrayBox<$ty>>) {
+                <array_box::ArrayBox<$ty> as self::array::ArrayBoxFFI::<$ty>>::boxer_array_reverse(array);
+            }
+
+            #[no_mangle]
+            pub extern "C" fn [<boxer_array_ $name _rotate_left>](array: *mut value_box::ValueBox<array_box::ArrayBox<$ty>>, amount: usize) {
+                <array_box::ArrayBox<$ty> as self::array::ArrayBoxFFI::<$ty>>::boxer_array_rotate_left(array, amount);
+            }
+
+            #[no_mangle]
+            pub extern "C" fn [<boxer_array_ $name _rotate_right>](array: *mut value_box::ValueBox<array_box::ArrayBox<$ty>>, amount: usize) {
+                <array_box::ArrayBox<$ty> as self::array::ArrayBoxFFI::<$ty>>::boxer_array_rotate_right(array, amount);
+            }
+
+            /// Create an array that views the items within a range of a given array without copying them.
+            /// The array can not be resized while the view exists,
+            /// the view becomes invalid once the array is dropped or invalidated.
+            #[no_mangle]
+            pub extern "C" fn [<boxer_array_ $name _sub_array>](
+                array: *mut value_box::ValueBox<array_box::ArrayBox<$ty>>,
+                start: usize,
+                end: usize,
+            ) -> *mut value_box::ValueBox<array_box::ArrayBox<$ty>> {
+                <array_box::ArrayBox<$ty> as self::array::ArrayBoxFFI::<$ty>>::boxer_array_sub_array(array, start, end)
+            }
+
+            #[no_mangle]
+            pub extern "C" fn [<boxer_array_ $name _copy_range_into_checked>](
+                src: *mut value_box::ValueBox<array_box::ArrayBox<$ty>>,
+                start: usize,
+                end: usize,
+                dst: *mut value_box::ValueBox<array_box::ArrayBox<$ty>>,
+                index: usize,
+            ) -> value_box::BoxerStatus {
+                <array_box::ArrayBox<$ty> as self::array::ArrayBoxFFI::<$ty>>::boxer_array_copy_range_into_checked(src, start, end, dst, index)
+            }
+
+            #[no_mangle]
+            pub extern "C" fn [<boxer_array_ $name _fill_checked>](
+                array: *mut value_box::ValueBox<array_box::ArrayBox<$ty>>,
+                start: usize,
+                end: usize,
+                item: $ty,
+            ) -> value_box::BoxerStatus {
+                <array_box::ArrayBox<$ty> as self::array::ArrayBoxFFI::<$ty>>::boxer_array_fill_checked(array, start, end, item)
+            }
+
+            #[no_mangle]
+            pub extern "C" fn [<boxer_array_ $name _rotate_left_checked>](
+                array: *mut value_box::ValueBox<array_box::ArrayBox<$ty>>,
+                amount: usize,
+            ) -> value_box::BoxerStatus {
+                <array_box::ArrayBox<$ty> as self::array::ArrayBoxFFI::<$ty>>::boxer_array_rotate_left_checked(array, amount)
+            }
+
+            #[no_mangle]
+            pub extern "C" fn [<boxer_array_ $name _rotate_right_checked>](
+                array: *mut value_box::ValueBox<array_box::ArrayBox<$ty>>,
+                amount: usize,
+            ) -> value_box::BoxerStatus {
+                <array_box::ArrayBox<$ty> as self::array::ArrayBoxFFI::<$ty>>::boxer_array_rotate_right_checked(array, amount)
+            }
+
+            #[no_mangle]
+            pub extern "C" fn [<boxer_array_ $name _copy_into_checked>](
+                src: *mut value_box::ValueBox<array_box::ArrayBox<$ty>>,
+                dst: *mut value_box::ValueBox<array_box::ArrayBox<$ty>>,
+            ) -> value_box::BoxerStatus {
+                <array_box::ArrayBox<$ty> as self::array::ArrayBoxFFI::<$ty>>::boxer_array_copy_into_checked(src, dst)
+            }
+
+            #[no_mangle]
+            pub extern "C" fn [<boxer_array_ $name _copy_into_data_checked>](
+                src: *mut value_box::ValueBox<array_box::ArrayBox<$ty>>,
+                data: *mut $ty,
+                amount: usize,
+            ) -> value_box::BoxerStatus {
+                <array_box::ArrayBox<$ty> as self::array::ArrayBoxFFI::<$ty>>::boxer_array_copy_into_data_checked(src, data, amount)
+            }
+
+            #[no_mangle]
+            pub extern "C" fn [<boxer_array_ $name _get_length_checked>](
+                array: *mut value_box::ValueBox<array_box::ArrayBox<$ty>>,
+                length: *mut usize,
+            ) -> value_box::BoxerStatus {
+                <array_box::ArrayBox<$ty> as self::array::ArrayBoxFFI::<$ty>>::boxer_array_get_length_checked(array, length)
+            }
+
+            #[no_mangle]
+            pub extern "C" fn [<boxer_array_ $name _get_capacity_checked>](
+                array: *mut value_box::ValueBox<array_box::ArrayBox<$ty>>,
+                capacity: *mut usize,
+            ) -> value_box::BoxerStatus {
+                <array_box::ArrayBox<$ty> as self::array::ArrayBoxFFI::<$ty>>::boxer_array_get_capacity_checked(array, capacity)
+            }
+
+            #[no_mangle]
+            pub extern "C" fn [<boxer_array_ $name _get_data_checked>](
+                array: *mut value_box::ValueBox<array_box::ArrayBox<$ty>>,
+                data: *mut *mut $ty,
+            ) -> value_box::BoxerStatus {
+                <array_box::ArrayBox<$ty> as self::array::ArrayBoxFFI::<$ty>>::boxer_array_get_data_checked(array, data)
+            }
+
+            #[no_mangle]
+            pub extern "C" fn [<boxer_array_ $name _at_checked>](
+                array: *mut value_box::ValueBox<array_box::ArrayBox<$ty>>,
+                index: usize,
+                item: *mut $ty,
+            ) -> value_box::BoxerStatus {
+                <array_box::ArrayBox<$ty> as self::array::ArrayBoxFFI::<$ty>>::boxer_array_at_checked(array, index, item)
+            }
+
+            #[no_mangle]
+            pub extern "C" fn [<boxer_array_ $name _at_put_checked>](
+                array: *mut value_box::ValueBox<array_box::ArrayBox<$ty>>,
+                index: usize,
+                item: $ty,
+            ) -> value_box::BoxerStatus {
+                <array_box::ArrayBox<$ty> as self::array::ArrayBoxFFI::<$ty>>::boxer_array_at_put_checked(array, index, item)
+            }
+
+            #[no_mangle]
+            pub extern "C" fn [<boxer_array_ $name _create_from_foreign_data>](
+                data: *mut $ty,
+                amount: usize,
+                release: array_box::ArrayBoxRelease,
+                release_data: *mut std::ffi::c_void,
+            ) -> *mut value_box::ValueBox<array_box::ArrayBox<$ty>> {
+                <array_box::ArrayBox<$ty> as self::array::ArrayBoxFFI::<$ty>>::boxer_array_create_from_foreign_data(data, amount, release, release_data)
+            }
+
+            /// Invalidate the array when its foreign data is freed or moved,
+            /// accessing the data of the array fails afterwards.
+            #[no_mangle]
+            pub extern "C" fn [<boxer_array_ $name _invalidate>](array: *mut value_box::ValueBox<array_box::ArrayBox<$ty>>) {
+                <array_box::ArrayBox<$ty> as self::array::ArrayBoxFFI::<$ty>>::boxer_array_invalidate(array);
+            }
+
+            #[no_mangle]
+            pub extern "C" fn [<boxer_array_ $name _is_valid>](array: *mut value_box::ValueBox<array_box::ArrayBox<$ty>>) -> bool {
+                <array_box::ArrayBox<$ty> as self::array::ArrayBoxFFI::<$ty>>::boxer_array_is_valid(array)
+            }
+
             #[no_mangle]
             pub extern "C" fn [<boxer_array_ $name _drop>](array: *mut value_box::ValueBox<array_box::ArrayBox<$ty>>) {
                 <array_box::ArrayBox<$ty> as self::array::ArrayBoxFFI::<$ty>>::boxer_array_drop(array);
             }
+
+            pub(crate) fn [<boxer_array_ $name _register_manifest>](manifest: &mut $crate::manifest::FfiManifest) {
+                type Array = *mut value_box::ValueBox<array_box::ArrayBox<$ty>>;
+                type Status = value_box::BoxerStatus;
+                use $crate::manifest::ffi_function;
+
+                manifest.add(ffi_function!(Creates fn [<boxer_array_ $name _create>]() -> Array));
+                manifest.add(ffi_function!(Creates fn [<boxer_array_ $name _create_with>](element: $ty, amount: usize) -> Array));
+                manifest.add(ffi_function!(Creates fn [<boxer_array_ $name _create_from_data>](data: *mut $ty, amount: usize) -> Array));
+                manifest.add(ffi_function!(Creates fn [<boxer_array_ $name _create_from_foreign_data>](data: *mut $ty, amount: usize, release: array_box::ArrayBoxRelease, release_data: *mut std::ffi::c_void) -> Array));
+                manifest.add(ffi_function!(Borrows fn [<boxer_array_ $name _invalidate>](array: Array)));
+                manifest.add(ffi_function!(Borrows fn [<boxer_array_ $name _is_valid>](array: Array) -> bool));
+                manifest.add(ffi_function!(Borrows fn [<boxer_array_ $name _copy_into>](src: Array, dst: Array)));
+                manifest.add(ffi_function!(Borrows fn [<boxer_array_ $name _copy_into_data>](src: Array, data: *mut $ty, amount: usize)));
+                manifest.add(ffi_function!(Borrows fn [<boxer_array_ $name _get_length>](array: Array) -> usize));
+                manifest.add(ffi_function!(Borrows fn [<boxer_array_ $name _get_capacity>](array: Array) -> usize));
+                manifest.add(ffi_function!(Borrows fn [<boxer_array_ $name _get_data>](array: Array) -> *mut $ty));
+                manifest.add(ffi_function!(Borrows fn [<boxer_array_ $name _at>](array: Array, index: usize) -> $ty));
+                manifest.add(ffi_function!(Borrows fn [<boxer_array_ $name _at_put>](array: Array, index: usize, item: $ty)));
+                manifest.add(ffi_function!(Borrows fn [<boxer_array_ $name _push>](array: Array, item: $ty)));
+                manifest.add(ffi_function!(Borrows fn [<boxer_array_ $name _pop>](array: Array) -> $ty));
+                manifest.add(ffi_function!(Borrows fn [<boxer_array_ $name _insert>](array: Array, index: usize, item: $ty)));
+                manifest.add(ffi_function!(Borrows fn [<boxer_array_ $name _remove>](array: Array, index: usize) -> $ty));
+                manifest.add(ffi_function!(Borrows fn [<boxer_array_ $name _truncate>](array: Array, length: usize)));
+                manifest.add(ffi_function!(Borrows fn [<boxer_array_ $name _resize>](array: Array, length: usize, item: $ty)));
+                manifest.add(ffi_function!(Borrows fn [<boxer_array_ $name _reserve>](array: Array, additional: usize)));
+                manifest.add(ffi_function!(Borrows fn [<boxer_array_ $name _extend_from_slice>](array: Array, data: *const $ty, amount: usize)));
+                manifest.add(ffi_function!(Borrows fn [<boxer_array_ $name _copy_range_into>](src: Array, start: usize, end: usize, dst: Array, index: usize)));
+                manifest.add(ffi_function!(Borrows fn [<boxer_array_ $name _fill>](array: Array, start: usize, end: usize, item: $ty)));
+                manifest.add(ffi_function!(Borrows fn [<boxer_array_ $name _reverse>](array: Array)));
+                manifest.add(ffi_function!(Borrows fn [<boxer_array_ $name _rotate_left>](array: Array, amount: usize)));
+                manifest.add(ffi_function!(Borrows fn [<boxer_array_ $name _rotate_right>](array: Array, amount: usize)));
+                manifest.add(ffi_function!(Creates fn [<boxer_array_ $name _sub_array>](array: Array, start: usize, end: usize) -> Array));
+                manifest.add(ffi_function!(Borrows fn [<boxer_array_ $name _copy_into_checked>](src: Array, dst: Array) -> Status));
+                manifest.add(ffi_function!(Borrows fn [<boxer_array_ $name _copy_into_data_checked>](src: Array, data: *mut $ty, amount: usize) -> Status));
+                manifest.add(ffi_function!(Borrows fn [<boxer_array_ $name _get_length_checked>](array: Array, length: *mut usize) -> Status));
+                manifest.add(ffi_function!(Borrows fn [<boxer_array_ $name _get_capacity_checked>](array: Array, capacity: *mut usize) -> Status));
+                manifest.add(ffi_function!(Borrows fn [<boxer_array_ $name _get_data_checked>](array: Array, data: *mut *mut $ty) -> Status));
+                manifest.add(ffi_function!(Borrows fn [<boxer_array_ $name _at_checked>](array: Array, index: usize, item: *mut $ty) -> Status));
+                manifest.add(ffi_function!(Borrows fn [<boxer_array_ $name _at_put_checked>](array: Array, index: usize, item: $ty) -> Status));
+                manifest.add(ffi_function!(Borrows fn [<boxer_array_ $name _pop_checked>](array: Array, item: *mut $ty) -> Status));
+                manifest.add(ffi_function!(Borrows fn [<boxer_array_ $name _insert_checked>](array: Array, index: usize, item: $ty) -> Status));
+                manifest.add(ffi_function!(Borrows fn [<boxer_array_ $name _remove_checked>](array: Array, index: usize, item: *mut $ty) -> Status));
+                manifest.add(ffi_function!(Borrows fn [<boxer_array_ $name _copy_range_into_checked>](src: Array, start: usize, end: usize, dst: Array, index: usize) -> Status));
+                manifest.add(ffi_function!(Borrows fn [<boxer_array_ $name _fill_checked>](array: Array, start: usize, end: usize, item: $ty) -> Status));
+                manifest.add(ffi_function!(Borrows fn [<boxer_array_ $name _rotate_left_checked>](array: Array, amount: usize) -> Status));
+                manifest.add(ffi_function!(Borrows fn [<boxer_array_ $name _rotate_right_checked>](array: Array, amount: usize) -> Status));
+                manifest.add(ffi_function!(Consumes fn [<boxer_array_ $name _drop>](array: Array)));
+            }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{at, with_borrowed_array};
+    use crate::*;
+    use std::ffi::c_void;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use value_box::{AnyValueBoxPointer, BoxerErrorKind, BoxerStatus, ValueBoxPointer};
+
+    #[test]
+    fn test_at_out_of_bounds() {
+        let array = boxer_array_u32_create_with(42, 2);
+        assert_eq!(boxer_array_u32_at(array, 1), 42);
+        assert_eq!(boxer_array_u32_at(array, 2), 0);
+
+        boxer_array_u32_at_put(array, 5, 1);
+        assert_eq!(boxer_array_u32_get_length(array), 2);
+        boxer_array_u32_drop(array);
+    }
+
+    #[test]
+    fn test_at_checked() {
+        let array = boxer_array_u32_create_with(42, 2);
+
+        let mut item = 0;
+        assert_eq!(
+            boxer_array_u32_at_checked(array, 1, &mut item),
+            BoxerStatus::Ok
+        );
+        assert_eq!(item, 42);
+
+        assert_eq!(
+            boxer_array_u32_at_put_checked(array, 2, 1),
+            BoxerErrorKind::OutOfBounds
+        );
+        assert_eq!(
+            boxer_array_u32_at_checked(array, 2, &mut item),
+            BoxerErrorKind::OutOfBounds
+        );
+        boxer_array_u32_drop(array);
+
+        assert_eq!(
+            boxer_array_u32_at_checked(std::ptr::null_mut(), 0, &mut item),
+            BoxerErrorKind::NullPointer
+        );
+    }
+
+    #[test]
+    fn test_extend_from_own_items() {
+        let array = boxer_array_u32_create_with(7, 3);
+        let data = boxer_array_u32_get_data(array);
+        boxer_array_u32_at_put(array, 0, 1);
+        boxer_array_u32_extend_from_slice(array, data, 3);
+        assert_eq!(
+            array
+                .with_ref_ok(|array| array.to_slice().to_vec())
+                .unwrap(),
+            vec![1, 7, 7, 1, 7, 7]
+        );
+        boxer_array_u32_drop(array);
+    }
+
+    #[test]
+    fn test_grow_and_shrink() {
+        let array = boxer_array_u32_create();
+        boxer_array_u32_push(array, 1);
+        boxer_array_u32_extend_from_slice(array, [2, 3, 4].as_ptr(), 3);
+        boxer_array_u32_insert(array, 0, 0);
+        assert_eq!(boxer_array_u32_get_length(array), 5);
+
+        assert_eq!(boxer_array_u32_remove(array, 1), 1);
+        assert_eq!(boxer_array_u32_pop(array), 4);
+        boxer_array_u32_reserve(array, 10);
+        assert!(boxer_array_u32_get_capacity(array) >= 13);
+
+        boxer_array_u32_resize(array, 5, 9);
+        boxer_array_u32_truncate(array, 4);
+        assert_eq!(
+            array
+                .with_ref_ok(|array| array.to_slice().to_vec())
+                .unwrap(),
+            vec![0, 2, 3, 9]
+        );
+
+        let mut item = 0;
+        assert_eq!(
+            boxer_array_u32_insert_checked(array, 5, 1),
+            BoxerErrorKind::OutOfBounds
+        );
+        assert_eq!(
+            boxer_array_u32_remove_checked(array, 4, &mut item),
+            BoxerErrorKind::OutOfBounds
+        );
+        boxer_array_u32_truncate(array, 0);
+        assert_eq!(
+            boxer_array_u32_pop_checked(array, &mut item),
+            BoxerErrorKind::NoValue
+        );
+        boxer_array_u32_drop(array);
+    }
+
+    #[test]
+    fn test_ranges() {
+        let array = boxer_array_u8_create_with(0, 6);
+        boxer_array_u8_fill(array, 0, 3, 1);
+        boxer_array_u8_copy_range_into(array, 0, 3, array, 2);
+        boxer_array_u8_reverse(array);
+        boxer_array_u8_rotate_left(array, 1);
+        assert_eq!(
+            array
+                .with_ref_ok(|array| array.to_slice().to_vec())
+                .unwrap(),
+            vec![1, 1, 1, 1, 1, 0]
+        );
+
+        let another_array = boxer_array_u8_create_with(0, 2);
+        assert_eq!(
+            boxer_array_u8_copy_range_into_checked(array, 0, 3, another_array, 0),
+            BoxerErrorKind::OutOfBounds
+        );
+        assert_eq!(
+            boxer_array_u8_fill_checked(array, 4, 7, 1),
+            BoxerErrorKind::OutOfBounds
+        );
+        assert_eq!(
+            boxer_array_u8_rotate_right_checked(array, 7),
+            BoxerErrorKind::OutOfBounds
+        );
+        boxer_array_u8_drop(another_array);
+
+        let view = boxer_array_u8_sub_array(array, 3, 5);
+        boxer_array_u8_fill(view, 0, 2, 9);
+        assert_eq!(boxer_array_u8_at(view, 1), 9);
+        assert_eq!(boxer_array_u8_get_length(view), 2);
+
+        // the buffer must not be moved while it is viewed
+        let length = boxer_array_u8_get_length(array);
+        boxer_array_u8_push(array, 1);
+        assert_eq!(boxer_array_u8_get_length(array), length);
+        assert_eq!(boxer_array_u8_at(array, 4), 9);
+
+        boxer_array_u8_drop(array);
+        assert!(!boxer_array_u8_is_valid(view));
+        boxer_array_u8_drop(view);
+    }
+
+    #[test]
+    fn test_create_with_too_many() {
+        let array = boxer_array_u64_create_with(0, usize::MAX);
+        assert!(array.is_null());
+    }
+
+    static RELEASED: AtomicBool = AtomicBool::new(false);
+
+    extern "C" fn release(_release_data: *mut c_void) {
+        RELEASED.store(true, Ordering::SeqCst);
+    }
+
+    #[test]
+    fn test_invalidated_foreign_data() {
+        let mut data = vec![1u32, 2, 3];
+        let array = boxer_array_u32_create_from_foreign_data(
+            data.as_mut_ptr(),
+            data.len(),
+            Some(release),
+            std::ptr::null_mut(),
+        );
+        assert!(boxer_array_u32_is_valid(array));
+        assert_eq!(boxer_array_u32_at(array, 2), 3);
+
+        boxer_array_u32_invalidate(array);
+        assert!(!boxer_array_u32_is_valid(array));
+        assert!(boxer_array_u32_get_data(array).is_null());
+
+        let mut item = 0;
+        assert_eq!(
+            boxer_array_u32_at_checked(array, 0, &mut item),
+            BoxerErrorKind::Invalidated
+        );
+        boxer_array_u32_drop(array);
+        assert!(!RELEASED.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn test_borrowed_array_outlives_call() -> value_box::Result<()> {
+        let mut data = vec![1u8, 2, 3];
+        let view = with_borrowed_array(data.as_mut_ptr(), data.len(), |array| {
+            assert_eq!(boxer_array_u8_at(array, 1), 2);
+            assert!(matches!(
+                array.retain_any(),
+                Err(value_box::BoxerError::NotShared(_))
+            ));
+            Ok(boxer_array_u8_sub_array(array, 0, 3))
+        })?;
+
+        assert!(!boxer_array_u8_is_valid(view));
+        assert!(matches!(
+            at(view, 1),
+            Err(value_box::BoxerError::Invalidated(_))
+        ));
+        boxer_array_u8_drop(view);
+        Ok(())
+    }
+}
@@ -1,47 +1,41 @@
-use array_box::ArrayBox;
-use value_box::{ReturnBoxerResult, ValueBox, ValueBoxPointer};
+use array_box::{for_each_chunk_mut, ArrayBox};
+use value_box::{
+    BoxerStatus, Result, ReturnBoxerResult, ReturnBoxerStatus, ValueBox, ValueBoxIntoRaw,
+    ValueBoxPointer,
+};
 
-/// In-place convert between color formats
-pub fn boxer_array_u8_convert_color_format<Block>(slice: &mut [u8], _converter: Block)
+use crate::array::check_valid;
+use crate::manifest::{ffi_function, FfiManifest};
+use crate::pixels::{
+    convert_pixel_rows, convert_pixels, convert_pixels_in_place, pixel_count, premultiply_alpha,
+    unpremultiply_alpha, PixelFormat, PixelRows,
+};
+
+/// In-place convert between color formats of 4-byte pixels on the shared workers,
+/// fails if the length is not a multiple of 4
+pub fn boxer_array_u8_convert_color_format<Block>(slice: &mut [u8], _converter: Block) -> Result<()>
 where
-    Block: Fn(u32) -> u32 + Send + Copy,
+    Block: Fn(u32) -> u32 + Send + Sync + Copy,
 {
-    if slice.len() % 4 == 0 {
-        let slice_u32 = unsafe {
-            std::slice::from_raw_parts_mut(slice.as_mut_ptr() as *mut u32, slice.len() / 4)
-        };
-
-        if slice_u32.len() > 512 {
-            let threads = 16;
-            let chunk_size =
-                slice_u32.len() / threads + if slice_u32.len() % threads != 0 { 1 } else { 0 };
-
-            // Scoped threads allow the compiler to prove that no threads will outlive
-            // table (which would be bad).
-            let _ = crossbeam::scope(|scope| {
-                // Chop `table` into disjoint sub-slices.
-                for each_chunk in slice_u32.chunks_mut(chunk_size) {
-                    // Spawn a thread operating on that subslice.
-                    scope.spawn(move |_| {
-                        for color in each_chunk {
-                            *color = _converter(*color);
-                        }
-                    });
-                }
-                // `crossbeam::scope` ensures that *all* spawned threads join before
-                // returning control back from this closure.
-            });
-        } else {
-            for color in slice_u32 {
-                *color = _converter(*color);
-            }
+    pixel_count(slice, PixelFormat::Argb32)?;
+
+    // pixels are converted from bytes, the slice may not be aligned to u32
+    let convert = move |pixels: &mut [u8]| {
+        for pixel in pixels.chunks_exact_mut(4) {
+            let color = u32::from_be_bytes([pixel[0], pixel[1], pixel[2], pixel[3]]);
+            pixel.copy_from_slice(&_converter(color).to_be_bytes());
         }
-    }
+    };
+
+    for_each_chunk_mut(slice, 4, convert);
+    Ok(())
 }
 
+// colors are read in big endian, so that the first byte of a pixel is the highest byte
+
 #[inline]
 fn argb_to_rgba(argb: u32) -> u32 {
-    argb.rotate_right(8)
+    argb.rotate_left(8)
 }
 
 #[inline]
@@ -51,41 +45,292 @@ fn bgra_to_argb(bgra: u32) -> u32 {
 
 #[inline]
 fn rgba_to_argb(rgba: u32) -> u32 {
-    rgba.rotate_left(8)
+    rgba.rotate_right(8)
+}
+
+fn convert_color_format(
+    array: *mut ValueBox<ArrayBox<u8>>,
+    converter: fn(u32) -> u32,
+) -> Result<()> {
+    array.with_mut(|array| {
+        check_valid(array)?;
+        boxer_array_u8_convert_color_format(array.to_slice(), converter)
+    })
 }
 
 /// In-place convert argb to rgba
 #[no_mangle]
 pub extern "C" fn boxer_array_u8_argb_to_rgba(array: *mut ValueBox<ArrayBox<u8>>) {
-    array
-        .with_mut_ok(|array| boxer_array_u8_convert_color_format(array.to_slice(), argb_to_rgba))
-        .log();
+    convert_color_format(array, argb_to_rgba).log();
 }
 
 /// In-place convert bgra to argb
 #[no_mangle]
 pub extern "C" fn boxer_array_u8_bgra_to_argb(array: *mut ValueBox<ArrayBox<u8>>) {
-    array
-        .with_mut_ok(|array| {
-            boxer_array_u8_convert_color_format(array.to_slice(), bgra_to_argb);
-        })
-        .log();
+    convert_color_format(array, bgra_to_argb).log();
 }
 
 /// In-place convert rgba to argb
 #[no_mangle]
 pub extern "C" fn boxer_array_u8_rgba_to_argb(array: *mut ValueBox<ArrayBox<u8>>) {
+    convert_color_format(array, rgba_to_argb).log();
+}
+
+/// Create a new array with the pixels of a given array converted to another `PixelFormat`,
+/// pixels without alpha get a given alpha.
+#[no_mangle]
+pub extern "C" fn boxer_array_u8_convert_pixels(
+    array: *mut ValueBox<ArrayBox<u8>>,
+    source_format: u8,
+    destination_format: u8,
+    alpha: u8,
+) -> *mut ValueBox<ArrayBox<u8>> {
     array
-        .with_mut_ok(|array| {
-            boxer_array_u8_convert_color_format(array.to_slice(), rgba_to_argb);
+        .with_ref(|array| {
+            check_valid(array)?;
+            let source_format = PixelFormat::try_from(source_format)?;
+            let destination_format = PixelFormat::try_from(destination_format)?;
+            let source = array.to_slice();
+            let count = pixel_count(source, source_format)?;
+            let mut destination = vec![0; count * destination_format.bytes_per_pixel()];
+            convert_pixels(
+                source,
+                source_format,
+                &mut destination,
+                destination_format,
+                alpha,
+            )?;
+            Ok(ValueBox::new(ArrayBox::from_vector(destination)))
+        })
+        .into_raw()
+}
+
+/// In-place convert pixels between two `PixelFormat`s of the same pixel size
+#[no_mangle]
+pub extern "C" fn boxer_array_u8_convert_pixels_in_place(
+    array: *mut ValueBox<ArrayBox<u8>>,
+    source_format: u8,
+    destination_format: u8,
+) {
+    map_pixels(array, |pixels| {
+        convert_pixels_in_place(
+            pixels,
+            source_format.try_into()?,
+            destination_format.try_into()?,
+        )
+    })
+    .log();
+}
+
+/// Convert the pixels of an image whose rows are padded to a stride into another image
+#[no_mangle]
+pub extern "C" fn boxer_array_u8_convert_pixel_rows(
+    source: *mut ValueBox<ArrayBox<u8>>,
+    width: usize,
+    height: usize,
+    source_stride: usize,
+    source_format: u8,
+    destination: *mut ValueBox<ArrayBox<u8>>,
+    destination_stride: usize,
+    destination_format: u8,
+    alpha: u8,
+) {
+    pixel_rows(width, height, source_stride, source_format)
+        .and_then(|source_rows| {
+            let destination_rows =
+                pixel_rows(width, height, destination_stride, destination_format)?;
+            convert_rows(source, source_rows, destination, destination_rows, alpha)
         })
         .log();
 }
 
+/// In-place multiply the color of pixels of a given `PixelFormat` by their alpha
+#[no_mangle]
+pub extern "C" fn boxer_array_u8_premultiply_alpha(array: *mut ValueBox<ArrayBox<u8>>, format: u8) {
+    map_pixels(array, |pixels| {
+        premultiply_alpha(pixels, format.try_into()?)
+    })
+    .log();
+}
+
+/// In-place divide the color of pixels of a given `PixelFormat` by their alpha
+#[no_mangle]
+pub extern "C" fn boxer_array_u8_unpremultiply_alpha(
+    array: *mut ValueBox<ArrayBox<u8>>,
+    format: u8,
+) {
+    map_pixels(array, |pixels| {
+        unpremultiply_alpha(pixels, format.try_into()?)
+    })
+    .log();
+}
+
+/// Same as `boxer_array_u8_convert_pixels_in_place`, but return the status of the conversion
+#[no_mangle]
+pub extern "C" fn boxer_array_u8_convert_pixels_in_place_checked(
+    array: *mut ValueBox<ArrayBox<u8>>,
+    source_format: u8,
+    destination_format: u8,
+) -> BoxerStatus {
+    map_pixels(array, |pixels| {
+        convert_pixels_in_place(
+            pixels,
+            source_format.try_into()?,
+            destination_format.try_into()?,
+        )
+    })
+    .status()
+}
+
+/// Same as `boxer_array_u8_convert_pixel_rows`, but return the status of the conversion
+#[no_mangle]
+pub extern "C" fn boxer_array_u8_convert_pixel_rows_checked(
+    source: *mut ValueBox<ArrayBox<u8>>,
+    width: usize,
+    height: usize,
+    source_stride: usize,
+    source_format: u8,
+    destination: *mut ValueBox<ArrayBox<u8>>,
+    destination_stride: usize,
+    destination_format: u8,
+    alpha: u8,
+) -> BoxerStatus {
+    pixel_rows(width, height, source_stride, source_format)
+        .and_then(|source_rows| {
+            let destination_rows =
+                pixel_rows(width, height, destination_stride, destination_format)?;
+            convert_rows(source, source_rows, destination, destination_rows, alpha)
+        })
+        .status()
+}
+
+/// Same as `boxer_array_u8_premultiply_alpha`, but return the status of the operation
+#[no_mangle]
+pub extern "C" fn boxer_array_u8_premultiply_alpha_checked(
+    array: *mut ValueBox<ArrayBox<u8>>,
+    format: u8,
+) -> BoxerStatus {
+    map_pixels(array, |pixels| {
+        premultiply_alpha(pixels, format.try_into()?)
+    })
+    .status()
+}
+
+/// Same as `boxer_array_u8_unpremultiply_alpha`, but return the status of the operation
+#[no_mangle]
+pub extern "C" fn boxer_array_u8_unpremultiply_alpha_checked(
+    array: *mut ValueBox<ArrayBox<u8>>,
+    format: u8,
+) -> BoxerStatus {
+    map_pixels(array, |pixels| {
+        unpremultiply_alpha(pixels, format.try_into()?)
+    })
+    .status()
+}
+
+fn map_pixels(
+    array: *mut ValueBox<ArrayBox<u8>>,
+    op: impl FnOnce(&mut [u8]) -> Result<()>,
+) -> Result<()> {
+    array.with_mut(|array| {
+        check_valid(array)?;
+        op(array.to_slice())
+    })
+}
+
+fn pixel_rows(width: usize, height: usize, stride: usize, format: u8) -> Result<PixelRows> {
+    PixelFormat::try_from(format).map(|format| PixelRows::new(width, height, stride, format))
+}
+
+fn convert_rows(
+    source: *mut ValueBox<ArrayBox<u8>>,
+    source_rows: PixelRows,
+    destination: *mut ValueBox<ArrayBox<u8>>,
+    destination_rows: PixelRows,
+    alpha: u8,
+) -> Result<()> {
+    source.with_ref(|source| {
+        destination.with_mut(|destination| {
+            check_valid(source)?;
+            check_valid(destination)?;
+            convert_pixel_rows(
+                source.to_slice(),
+                source_rows,
+                destination.to_slice(),
+                destination_rows,
+                alpha,
+            )
+        })
+    })
+}
+
+pub(crate) fn register_manifest(manifest: &mut FfiManifest) {
+    type Array = *mut ValueBox<ArrayBox<u8>>;
+    type Status = BoxerStatus;
+    type Format = u8;
+
+    manifest.add(ffi_function!(Borrows fn boxer_array_u8_argb_to_rgba(array: Array)));
+    manifest.add(ffi_function!(Borrows fn boxer_array_u8_bgra_to_argb(array: Array)));
+    manifest.add(ffi_function!(Borrows fn boxer_array_u8_rgba_to_argb(array: Array)));
+    manifest.add(ffi_function!(Creates fn boxer_array_u8_convert_pixels(
+        array: Array,
+        source_format: Format,
+        destination_format: Format,
+        alpha: u8,
+    ) -> Array));
+    manifest.add(
+        ffi_function!(Borrows fn boxer_array_u8_convert_pixels_in_place(
+            array: Array,
+            source_format: Format,
+            destination_format: Format,
+        )),
+    );
+    manifest.add(ffi_function!(Borrows fn boxer_array_u8_convert_pixel_rows(
+        source: Array,
+        width: usize,
+        height: usize,
+        source_stride: usize,
+        source_format: Format,
+        destination: Array,
+        destination_stride: usize,
+        destination_format: Format,
+        alpha: u8,
+    )));
+    manifest.add(
+        ffi_function!(Borrows fn boxer_array_u8_premultiply_alpha(array: Array, format: Format)),
+    );
+    manifest.add(
+        ffi_function!(Borrows fn boxer_array_u8_unpremultiply_alpha(array: Array, format: Format)),
+    );
+    manifest.add(
+        ffi_function!(Borrows fn boxer_array_u8_convert_pixels_in_place_checked(
+        array: Array,
+        source_format: Format,
+        destination_format: Format,
+    ) -> Status),
+    );
+    manifest.add(
+        ffi_function!(Borrows fn boxer_array_u8_convert_pixel_rows_checked(
+        source: Array,
+        width: usize,
+        height: usize,
+        source_stride: usize,
+        source_format: Format,
+        destination: Array,
+        destination_stride: usize,
+        destination_format: Format,
+        alpha: u8,
+    ) -> Status),
+    );
+    manifest.add(ffi_function!(Borrows fn boxer_array_u8_premultiply_alpha_checked(array: Array, format: Format) -> Status));
+    manifest.add(ffi_function!(Borrows fn boxer_array_u8_unpremultiply_alpha_checked(array: Array, format: Format) -> Status));
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::*;
+    use value_box::BoxerErrorKind;
 
     #[test]
     fn test_argb_to_rgba() {
@@ -119,6 +364,59 @@ mod tests {
         assert_eq!(boxer_array_u8_at(rgba, 3), 200);
     }
 
+    #[test]
+    fn test_convert_pixels() {
+        let rgb = boxer_array_u8_create_with(10, 6);
+        let bgra = boxer_array_u8_convert_pixels(
+            rgb,
+            PixelFormat::Rgb24 as u8,
+            PixelFormat::Bgra32 as u8,
+            128,
+        );
+        assert_eq!(boxer_array_u8_get_length(bgra), 8);
+        assert_eq!(boxer_array_u8_at(bgra, 3), 128);
+
+        boxer_array_u8_premultiply_alpha(bgra, PixelFormat::Bgra32 as u8);
+        assert_eq!(boxer_array_u8_at(bgra, 0), 5);
+
+        assert_eq!(
+            boxer_array_u8_convert_pixels_in_place_checked(
+                rgb,
+                PixelFormat::Rgb24 as u8,
+                PixelFormat::Rgba32 as u8
+            ),
+            BoxerErrorKind::InvalidArgument
+        );
+        assert_eq!(
+            boxer_array_u8_premultiply_alpha_checked(bgra, 7),
+            BoxerErrorKind::InvalidArgument
+        );
+        assert!(boxer_array_u8_convert_pixels(rgb, 7, PixelFormat::Rgb24 as u8, 0).is_null());
+        assert_eq!(
+            boxer_array_u8_convert_pixel_rows_checked(
+                bgra,
+                1,
+                2,
+                4,
+                PixelFormat::Bgra32 as u8,
+                rgb,
+                3,
+                PixelFormat::Rgb24 as u8,
+                0
+            ),
+            BoxerStatus::Ok
+        );
+        assert_eq!(boxer_array_u8_at(rgb, 2), 5);
+
+        boxer_array_u8_at_put(rgb, 5, 1);
+        boxer_array_u8_push(rgb, 0);
+        boxer_array_u8_argb_to_rgba(rgb);
+        assert_eq!(boxer_array_u8_at(rgb, 5), 1);
+
+        boxer_array_u8_drop(bgra);
+        boxer_array_u8_drop(rgb);
+    }
+
     #[test]
     fn test_bgra_to_argb() {
         let bgra = boxer_array_u8_create_with(0, 4);
@@ -134,5 +432,4 @@ mod tests {
         assert_eq!(boxer_array_u8_at(bgra, 2), 100);
         assert_eq!(boxer_array_u8_at(bgra, 3), 0);
     }
-
 }
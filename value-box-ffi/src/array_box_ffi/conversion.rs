@@ -0,0 +1,212 @@
+use std::any::{type_name, Any};
+
+use array_box::{ArrayBox, ArrayConversion, ArrayConvert};
+use value_box::{BoxerError, ValueBox, ValueBoxIntoRaw, ValueBoxPointer};
+
+use crate::array::check_valid;
+
+/// Create a new array with the items of a given array converted to another number type
+/// using a given `ArrayConversion`, a lossless conversion fails with `LossyConversion`
+/// if any item can not be represented exactly.
+pub fn convert<T: ArrayConvert + Any, U: ArrayConvert + Any>(
+    array_box: *mut ValueBox<ArrayBox<T>>,
+    conversion: u8,
+) -> *mut ValueBox<ArrayBox<U>> {
+    array_box
+        .with_ref(|array| {
+            check_valid(array)?;
+            let conversion = ArrayConversion::try_from(conversion).map_err(|value| {
+                BoxerError::InvalidArgument(format!("Unknown array conversion {}", value))
+            })?;
+            array
+                .convert::<U>(conversion)
+                .map(ValueBox::new)
+                .ok_or_else(|| BoxerError::LossyConversion {
+                    from: type_name::<T>().to_string(),
+                    to: type_name::<U>().to_string(),
+                })
+        })
+        .into_raw()
+}
+
+/// Generate the conversions of an array of a given number type
+/// to arrays of all number types registered in `array_box_ffi/mod.rs`.
+#[macro_export]
+macro_rules! array_conversion_ffi {
+    ($ty:ident) => { array_conversion_ffi!($ty, $ty); };
+    ($ty:path, $name:ident) => {
+        paste::paste! {
+            #[no_mangle]
+            pub extern "C" fn [<boxer_array_ $name _to_u8>](
+                array: *mut value_box::ValueBox<array_box::ArrayBox<$ty>>,
+                conversion: u8,
+            ) -> *mut value_box::ValueBox<array_box::ArrayBox<u8>> {
+                self::conversion::convert(array, conversion)
+            }
+
+            #[no_mangle]
+            pub extern "C" fn [<boxer_array_ $name _to_i8>](
+                array: *mut value_box::ValueBox<array_box::ArrayBox<$ty>>,
+                conversion: u8,
+            ) -> *mut value_box::ValueBox<array_box::ArrayBox<i8>> {
+                self::conversion::convert(array, conversion)
+            }
+
+            #[no_mangle]
+            pub extern "C" fn [<boxer_array_ $name _to_u16>](
+                array: *mut value_box::ValueBox<array_box::ArrayBox<$ty>>,
+                conversion: u8,
+            ) -> *mut value_box::ValueBox<array_box::ArrayBox<u16>> {
+                self::conversion::convert(array, conversion)
+            }
+
+            #[no_mangle]
+            pub extern "C" fn [<boxer_array_ $name _to_i16>](
+                array: *mut value_box::ValueBox<array_box::ArrayBox<$ty>>,
+                conversion: u8,
+            ) -> *mut value_box::ValueBox<array_box::ArrayBox<i16>> {
+                self::conversion::convert(array, conversion)
+            }
+
+            #[no_mangle]
+            pub extern "C" fn [<boxer_array_ $name _to_u32>](
+                array: *mut value_box::ValueBox<array_box::ArrayBox<$ty>>,
+                conversion: u8,
+            ) -> *mut value_box::ValueBox<array_box::ArrayBox<u32>> {
+                self::conversion::convert(array, conversion)
+            }
+
+            #[no_mangle]
+            pub extern "C" fn [<boxer_array_ $name _to_i32>](
+                array: *mut value_box::ValueBox<array_box::ArrayBox<$ty>>,
+                conversion: u8,
+            ) -> *mut value_box::ValueBox<array_box::ArrayBox<i32>> {
+                self::conversion::convert(array, conversion)
+            }
+
+            #[no_mangle]
+            pub extern "C" fn [<boxer_array_ $name _to_u64>](
+                array: *mut value_box::ValueBox<array_box::ArrayBox<$ty>>,
+                conversion: u8,
+            ) -> *mut value_box::ValueBox<array_box::ArrayBox<u64>> {
+                self::conversion::convert(array, conversion)
+            }
+
+            #[no_mangle]
+            pub extern "C" fn [<boxer_array_ $name _to_i64>](
+                array: *mut value_box::ValueBox<array_box::ArrayBox<$ty>>,
+                conversion: u8,
+            ) -> *mut value_box::ValueBox<array_box::ArrayBox<i64>> {
+                self::conversion::convert(array, conversion)
+            }
+
+            #[no_mangle]
+            pub extern "C" fn [<boxer_array_ $name _to_isize>](
+                array: *mut value_box::ValueBox<array_box::ArrayBox<$ty>>,
+                conversion: u8,
+            ) -> *mut value_box::ValueBox<array_box::ArrayBox<isize>> {
+                self::conversion::convert(array, conversion)
+            }
+
+            #[no_mangle]
+            pub extern "C" fn [<boxer_array_ $name _to_usize>](
+                array: *mut value_box::ValueBox<array_box::ArrayBox<$ty>>,
+                conversion: u8,
+            ) -> *mut value_box::ValueBox<array_box::ArrayBox<usize>> {
+                self::conversion::convert(array, conversion)
+            }
+
+            #[no_mangle]
+            pub extern "C" fn [<boxer_array_ $name _to_int>](
+                array: *mut value_box::ValueBox<array_box::ArrayBox<$ty>>,
+                conversion: u8,
+            ) -> *mut value_box::ValueBox<array_box::ArrayBox<std::ffi::c_int>> {
+                self::conversion::convert(array, conversion)
+            }
+
+            #[no_mangle]
+            pub extern "C" fn [<boxer_array_ $name _to_uint>](
+                array: *mut value_box::ValueBox<array_box::ArrayBox<$ty>>,
+                conversion: u8,
+            ) -> *mut value_box::ValueBox<array_box::ArrayBox<std::ffi::c_uint>> {
+                self::conversion::convert(array, conversion)
+            }
+
+            #[no_mangle]
+            pub extern "C" fn [<boxer_array_ $name _to_f32>](
+                array: *mut value_box::ValueBox<array_box::ArrayBox<$ty>>,
+                conversion: u8,
+            ) -> *mut value_box::ValueBox<array_box::ArrayBox<f32>> {
+                self::conversion::convert(array, conversion)
+            }
+
+            #[no_mangle]
+            pub extern "C" fn [<boxer_array_ $name _to_f64>](
+                array: *mut value_box::ValueBox<array_box::ArrayBox<$ty>>,
+                conversion: u8,
+            ) -> *mut value_box::ValueBox<array_box::ArrayBox<f64>> {
+                self::conversion::convert(array, conversion)
+            }
+
+            pub(crate) fn [<boxer_array_ $name _register_conversion_manifest>](manifest: &mut $crate::manifest::FfiManifest) {
+                type Array = *mut value_box::ValueBox<array_box::ArrayBox<$ty>>;
+                type Conversion = u8;
+                use $crate::manifest::ffi_function;
+
+                manifest.add(ffi_function!(Creates fn [<boxer_array_ $name _to_u8>](array: Array, conversion: Conversion) -> *mut value_box::ValueBox<array_box::ArrayBox<u8>>));
+                manifest.add(ffi_function!(Creates fn [<boxer_array_ $name _to_i8>](array: Array, conversion: Conversion) -> *mut value_box::ValueBox<array_box::ArrayBox<i8>>));
+                manifest.add(ffi_function!(Creates fn [<boxer_array_ $name _to_u16>](array: Array, conversion: Conversion) -> *mut value_box::ValueBox<array_box::ArrayBox<u16>>));
+                manifest.add(ffi_function!(Creates fn [<boxer_array_ $name _to_i16>](array: Array, conversion: Conversion) -> *mut value_box::ValueBox<array_box::ArrayBox<i16>>));
+                manifest.add(ffi_function!(Creates fn [<boxer_array_ $name _to_u32>](array: Array, conversion: Conversion) -> *mut value_box::ValueBox<array_box::ArrayBox<u32>>));
+                manifest.add(ffi_function!(Creates fn [<boxer_array_ $name _to_i32>](array: Array, conversion: Conversion) -> *mut value_box::ValueBox<array_box::ArrayBox<i32>>));
+                manifest.add(ffi_function!(Creates fn [<boxer_array_ $name _to_u64>](array: Array, conversion: Conversion) -> *mut value_box::ValueBox<array_box::ArrayBox<u64>>));
+                manifest.add(ffi_function!(Creates fn [<boxer_array_ $name _to_i64>](array: Array, conversion: Conversion) -> *mut value_box::ValueBox<array_box::ArrayBox<i64>>));
+                manifest.add(ffi_function!(Creates fn [<boxer_array_ $name _to_isize>](array: Array, conversion: Conversion) -> *mut value_box::ValueBox<array_box::ArrayBox<isize>>));
+                manifest.add(ffi_function!(Creates fn [<boxer_array_ $name _to_usize>](array: Array, conversion: Conversion) -> *mut value_box::ValueBox<array_box::ArrayBox<usize>>));
+                manifest.add(ffi_function!(Creates fn [<boxer_array_ $name _to_int>](array: Array, conversion: Conversion) -> *mut value_box::ValueBox<array_box::ArrayBox<std::ffi::c_int>>));
+                manifest.add(ffi_function!(Creates fn [<boxer_array_ $name _to_uint>](array: Array, conversion: Conversion) -> *mut value_box::ValueBox<array_box::ArrayBox<std::ffi::c_uint>>));
+                manifest.add(ffi_function!(Creates fn [<boxer_array_ $name _to_f32>](array: Array, conversion: Conversion) -> *mut value_box::ValueBox<array_box::ArrayBox<f32>>));
+                manifest.add(ffi_function!(Creates fn [<boxer_array_ $name _to_f64>](array: Array, conversion: Conversion) -> *mut value_box::ValueBox<array_box::ArrayBox<f64>>));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use array_box::ArrayConversion;
+    #[cfg(feature = "value-box")]
+    use value_box::BoxerErrorKind;
+    use value_box::ValueBoxPointer;
+
+    #[test]
+    fn test_convert() {
+        let pixels = boxer_array_u8_create_with(51, 3);
+        let normalized = boxer_array_u8_to_f32(pixels, ArrayConversion::Normalizing as u8);
+        assert_eq!(boxer_array_f32_at(normalized, 2), 0.2);
+
+        boxer_array_f32_at_put(normalized, 0, 1.5);
+        let lossy = boxer_array_f32_to_int(normalized, ArrayConversion::Lossless as u8);
+        assert!(lossy.is_null());
+        #[cfg(feature = "value-box")]
+        assert_eq!(boxer_last_error_status(), BoxerErrorKind::LossyConversion);
+
+        let saturated = boxer_array_f32_to_u16(normalized, ArrayConversion::Saturating as u8);
+        assert_eq!(
+            saturated
+                .with_ref_ok(|array| array.to_slice().to_vec())
+                .unwrap(),
+            vec![1, 0, 0]
+        );
+
+        let unknown = boxer_array_f32_to_u16(normalized, 4);
+        assert!(unknown.is_null());
+        #[cfg(feature = "value-box")]
+        assert_eq!(boxer_last_error_status(), BoxerErrorKind::InvalidArgument);
+
+        boxer_array_u16_drop(saturated);
+        boxer_array_f32_drop(normalized);
+        boxer_array_u8_drop(pixels);
+    }
+}
@@ -1,7 +1,17 @@
 pub mod array;
 pub mod array_u8;
+pub mod conversion;
+pub mod numeric;
+pub mod parallel;
+pub mod pixels;
 
+#[cfg(feature = "serde")]
+pub use serialization::*;
+
+use crate::array_conversion_ffi;
 use crate::array_ffi;
+use crate::array_numeric_ffi;
+use crate::manifest::FfiManifest;
 
 array_ffi!(u8);
 array_ffi!(i8);
@@ -20,3 +30,146 @@ array_ffi!(std::ffi::c_uint, uint);
 
 array_ffi!(f32);
 array_ffi!(f64);
+
+array_numeric_ffi!(u8);
+array_numeric_ffi!(i8);
+array_numeric_ffi!(u16);
+array_numeric_ffi!(i16);
+array_numeric_ffi!(u32);
+array_numeric_ffi!(i32);
+array_numeric_ffi!(u64);
+array_numeric_ffi!(i64);
+
+array_numeric_ffi!(isize);
+array_numeric_ffi!(usize);
+
+array_numeric_ffi!(std::ffi::c_int, int);
+array_numeric_ffi!(std::ffi::c_uint, uint);
+
+array_numeric_ffi!(f32);
+array_numeric_ffi!(f64);
+
+array_conversion_ffi!(u8);
+array_conversion_ffi!(i8);
+array_conversion_ffi!(u16);
+array_conversion_ffi!(i16);
+array_conversion_ffi!(u32);
+array_conversion_ffi!(i32);
+array_conversion_ffi!(u64);
+array_conversion_ffi!(i64);
+
+array_conversion_ffi!(isize);
+array_conversion_ffi!(usize);
+
+array_conversion_ffi!(std::ffi::c_int, int);
+array_conversion_ffi!(std::ffi::c_uint, uint);
+
+array_conversion_ffi!(f32);
+array_conversion_ffi!(f64);
+
+#[cfg(feature = "serde")]
+mod serialization {
+    use crate::{serde_ffi, Serializable};
+    use array_box::ArrayBox;
+    use std::any::Any;
+
+    impl<T: Any + serde::Serialize> Serializable for ArrayBox<T> {
+        fn check_valid(&self) -> value_box::Result<()> {
+            crate::array::check_valid(self)
+        }
+    }
+
+    serde_ffi!(ArrayBox<u8>, array_u8);
+    serde_ffi!(ArrayBox<i8>, array_i8);
+    serde_ffi!(ArrayBox<u16>, array_u16);
+    serde_ffi!(ArrayBox<i16>, array_i16);
+    serde_ffi!(ArrayBox<u32>, array_u32);
+    serde_ffi!(ArrayBox<i32>, array_i32);
+    serde_ffi!(ArrayBox<u64>, array_u64);
+    serde_ffi!(ArrayBox<i64>, array_i64);
+    serde_ffi!(ArrayBox<isize>, array_isize);
+    serde_ffi!(ArrayBox<usize>, array_usize);
+    serde_ffi!(ArrayBox<std::ffi::c_int>, array_int);
+    serde_ffi!(ArrayBox<std::ffi::c_uint>, array_uint);
+    serde_ffi!(ArrayBox<f32>, array_f32);
+    serde_ffi!(ArrayBox<f64>, array_f64);
+}
+
+pub(crate) fn register_manifest(manifest: &mut FfiManifest) {
+    manifest.set_origin("array-box", "array-box");
+
+    boxer_array_u8_register_manifest(manifest);
+    boxer_array_i8_register_manifest(manifest);
+    boxer_array_u16_register_manifest(manifest);
+    boxer_array_i16_register_manifest(manifest);
+    boxer_array_u32_register_manifest(manifest);
+    boxer_array_i32_register_manifest(manifest);
+    boxer_array_u64_register_manifest(manifest);
+    boxer_array_i64_register_manifest(manifest);
+
+    boxer_array_isize_register_manifest(manifest);
+    boxer_array_usize_register_manifest(manifest);
+
+    boxer_array_int_register_manifest(manifest);
+    boxer_array_uint_register_manifest(manifest);
+
+    boxer_array_f32_register_manifest(manifest);
+    boxer_array_f64_register_manifest(manifest);
+
+    boxer_array_u8_register_numeric_manifest(manifest);
+    boxer_array_i8_register_numeric_manifest(manifest);
+    boxer_array_u16_register_numeric_manifest(manifest);
+    boxer_array_i16_register_numeric_manifest(manifest);
+    boxer_array_u32_register_numeric_manifest(manifest);
+    boxer_array_i32_register_numeric_manifest(manifest);
+    boxer_array_u64_register_numeric_manifest(manifest);
+    boxer_array_i64_register_numeric_manifest(manifest);
+
+    boxer_array_isize_register_numeric_manifest(manifest);
+    boxer_array_usize_register_numeric_manifest(manifest);
+
+    boxer_array_int_register_numeric_manifest(manifest);
+    boxer_array_uint_register_numeric_manifest(manifest);
+
+    boxer_array_f32_register_numeric_manifest(manifest);
+    boxer_array_f64_register_numeric_manifest(manifest);
+
+    boxer_array_u8_register_conversion_manifest(manifest);
+    boxer_array_i8_register_conversion_manifest(manifest);
+    boxer_array_u16_register_conversion_manifest(manifest);
+    boxer_array_i16_register_conversion_manifest(manifest);
+    boxer_array_u32_register_conversion_manifest(manifest);
+    boxer_array_i32_register_conversion_manifest(manifest);
+    boxer_array_u64_register_conversion_manifest(manifest);
+    boxer_array_i64_register_conversion_manifest(manifest);
+
+    boxer_array_isize_register_conversion_manifest(manifest);
+    boxer_array_usize_register_conversion_manifest(manifest);
+
+    boxer_array_int_register_conversion_manifest(manifest);
+    boxer_array_uint_register_conversion_manifest(manifest);
+
+    boxer_array_f32_register_conversion_manifest(manifest);
+    boxer_array_f64_register_conversion_manifest(manifest);
+
+    array_u8::register_manifest(manifest);
+    parallel::register_manifest(manifest);
+
+    #[cfg(feature = "serde")]
+    {
+        serialization::boxer_array_u8_register_serde_manifest(manifest);
+        serialization::boxer_array_i8_register_serde_manifest(manifest);
+        serialization::boxer_array_u16_register_serde_manifest(manifest);
+        serialization::boxer_array_i16_register_serde_manifest(manifest);
+        serialization::boxer_array_u32_register_serde_manifest(manifest);
+        serialization::boxer_array_i32_register_serde_manifest(manifest);
+        serialization::boxer_array_u64_register_serde_manifest(manifest);
+        serialization::boxer_array_i64_register_serde_manifest(manifest);
+        serialization::boxer_array_isize_register_serde_manifest(manifest);
+        serialization::boxer_array_usize_register_serde_manifest(manifest);
+        serialization::boxer_array_int_register_serde_manifest(manifest);
+        serialization::boxer_array_uint_register_serde_manifest(manifest);
+        serialization::boxer_array_f32_register_serde_manifest(manifest);
+        serialization::boxer_array_f64_register_serde_manifest(manifest);
+    }
+}
@@ -0,0 +1,352 @@
+use std::any::{type_name, Any};
+
+use array_box::{ArrayBox, ArrayNumber};
+use value_box::{BoxerError, Result, ValueBox, ValueBoxPointer};
+
+use crate::array::check_valid;
+
+/// Apply an element-wise kernel of `ArrayBox` between two arrays of the same length.
+/// An array combined with itself is combined with a copy of its items.
+pub fn zip_with<T: ArrayNumber + Any>(
+    array_box: *mut ValueBox<ArrayBox<T>>,
+    another_box: *mut ValueBox<ArrayBox<T>>,
+    op: fn(&mut ArrayBox<T>, &ArrayBox<T>),
+) -> Result<()> {
+    if array_box == another_box {
+        return array_box.with_mut(|array| {
+            check_valid(array)?;
+            let copy = ArrayBox::from_vector(array.to_slice().to_vec());
+            op(array, &copy);
+            Ok(())
+        });
+    }
+
+    array_box.with_mut(|array| {
+        another_box.with_ref(|another_array| {
+            check_valid(array)?;
+            check_valid(another_array)?;
+            check_length(array, another_array).map(|_| op(array, another_array))
+        })
+    })
+}
+
+/// Apply an in-place kernel of `ArrayBox` to a valid array
+pub fn map<T: ArrayNumber + Any>(
+    array_box: *mut ValueBox<ArrayBox<T>>,
+    op: impl FnOnce(&mut ArrayBox<T>),
+) -> Result<()> {
+    array_box.with_mut(|array| {
+        check_valid(array)?;
+        op(array);
+        Ok(())
+    })
+}
+
+pub fn clamp<T: ArrayNumber + Any>(
+    array_box: *mut ValueBox<ArrayBox<T>>,
+    min: T,
+    max: T,
+) -> Result<()> {
+    array_box.with_mut(|array| {
+        check_valid(array)?;
+        array
+            .clamp(min, max)
+            .map_err(|error| BoxerError::InvalidArgument(error.to_string()))
+    })
+}
+
+/// Reduce a valid array to a single value, an empty array has no minimum, maximum or mean.
+pub fn reduce<T: ArrayNumber + Any, R: Any>(
+    array_box: *mut ValueBox<ArrayBox<T>>,
+    op: fn(&ArrayBox<T>) -> Option<R>,
+) -> Result<R> {
+    array_box.with_ref(|array| {
+        check_valid(array)?;
+        op(array).ok_or_else(|| BoxerError::NoValue(type_name::<ArrayBox<T>>().to_string()))
+    })
+}
+
+pub fn dot<T: ArrayNumber + Any>(
+    array_box: *mut ValueBox<ArrayBox<T>>,
+    another_box: *mut ValueBox<ArrayBox<T>>,
+) -> Result<f64> {
+    if array_box == another_box {
+        return reduce(array_box, |array| Some(array.dot(array)));
+    }
+
+    array_box.with_ref(|array| {
+        another_box.with_ref(|another_array| {
+            check_valid(array)?;
+            check_valid(another_array)?;
+            check_length(array, another_array).map(|_| array.dot(another_array))
+        })
+    })
+}
+
+fn check_length<T>(array: &ArrayBox<T>, another_array: &ArrayBox<T>) -> Result<()> {
+    if array.length == another_array.length {
+        Ok(())
+    } else {
+        BoxerError::LengthMismatch {
+            expected: array.length,
+            actual: another_array.length,
+        }
+        .into()
+    }
+}
+
+/// Generate the numeric kernels of an array of a given number type,
+/// operations between arrays of different lengths fail with `LengthMismatch`.
+#[macro_export]
+macro_rules! array_numeric_ffi {
+    ($ty:ident) => { array_numeric_ffi!($ty, $ty, Default::default()); };
+    ($ty:path, $name:ident) => { array_numeric_ffi!($ty, $name, Default::default()); };
+    ($ty:path, $name:ident, $default:expr) => {
+        paste::paste! {
+            #[no_mangle]
+            pub extern "C" fn [<boxer_array_ $name _add>](
+                array: *mut value_box::ValueBox<array_box::ArrayBox<$ty>>,
+                another_array: *mut value_box::ValueBox<array_box::ArrayBox<$ty>>,
+            ) {
+                use value_box::ReturnBoxerResult;
+                self::numeric::zip_with(array, another_array, array_box::ArrayBox::add).log();
+            }
+
+            #[no_mangle]
+            pub extern "C" fn [<boxer_array_ $name _sub>](
+                array: *mut value_box::ValueBox<array_box::ArrayBox<$ty>>,
+                another_array: *mut value_box::ValueBox<array_box::ArrayBox<$ty>>,
+            ) {
+                use value_box::ReturnBoxerResult;
+                self::numeric::zip_with(array, another_array, array_box::ArrayBox::sub).log();
+            }
+
+            #[no_mangle]
+            pub extern "C" fn [<boxer_array_ $name _mul>](
+                array: *mut value_box::ValueBox<array_box::ArrayBox<$ty>>,
+                another_array: *mut value_box::ValueBox<array_box::ArrayBox<$ty>>,
+            ) {
+                use value_box::ReturnBoxerResult;
+                self::numeric::zip_with(array, another_array, array_box::ArrayBox::mul).log();
+            }
+
+            #[no_mangle]
+            pub extern "C" fn [<boxer_array_ $name _add_scalar>](array: *mut value_box::ValueBox<array_box::ArrayBox<$ty>>, scalar: $ty) {
+                use value_box::ReturnBoxerResult;
+                self::numeric::map(array, |array| array.add_scalar(scalar)).log();
+            }
+
+            #[no_mangle]
+            pub extern "C" fn [<boxer_array_ $name _sub_scalar>](array: *mut value_box::ValueBox<array_box::ArrayBox<$ty>>, scalar: $ty) {
+                use value_box::ReturnBoxerResult;
+                self::numeric::map(array, |array| array.sub_scalar(scalar)).log();
+            }
+
+            #[no_mangle]
+            pub extern "C" fn [<boxer_array_ $name _scale>](array: *mut value_box::ValueBox<array_box::ArrayBox<$ty>>, factor: $ty) {
+                use value_box::ReturnBoxerResult;
+                self::numeric::map(array, |array| array.scale(factor)).log();
+            }
+
+            #[no_mangle]
+            pub extern "C" fn [<boxer_array_ $name _clamp>](array: *mut value_box::ValueBox<array_box::ArrayBox<$ty>>, min: $ty, max: $ty) {
+                use value_box::ReturnBoxerResult;
+                self::numeric::clamp(array, min, max).log();
+            }
+
+            #[no_mangle]
+            pub extern "C" fn [<boxer_array_ $name _sum>](array: *mut value_box::ValueBox<array_box::ArrayBox<$ty>>) -> f64 {
+                use value_box::ReturnBoxerResult;
+                self::numeric::reduce(array, |array| Some(array.sum())).or_log(0.0)
+            }
+
+            #[no_mangle]
+            pub extern "C" fn [<boxer_array_ $name _mean>](array: *mut value_box::ValueBox<array_box::ArrayBox<$ty>>) -> f64 {
+                use value_box::ReturnBoxerResult;
+                self::numeric::reduce(array, array_box::ArrayBox::mean).or_log(0.0)
+            }
+
+            #[no_mangle]
+            pub extern "C" fn [<boxer_array_ $name _min>](array: *mut value_box::ValueBox<array_box::ArrayBox<$ty>>) -> $ty {
+                use value_box::ReturnBoxerResult;
+                self::numeric::reduce(array, array_box::ArrayBox::min).or_log($default)
+            }
+
+            #[no_mangle]
+            pub extern "C" fn [<boxer_array_ $name _max>](array: *mut value_box::ValueBox<array_box::ArrayBox<$ty>>) -> $ty {
+                use value_box::ReturnBoxerResult;
+                self::numeric::reduce(array, array_box::ArrayBox::max).or_log($default)
+            }
+
+            #[no_mangle]
+            pub extern "C" fn [<boxer_array_ $name _dot>](
+                array: *mut value_box::ValueBox<array_box::ArrayBox<$ty>>,
+                another_array: *mut value_box::ValueBox<array_box::ArrayBox<$ty>>,
+            ) -> f64 {
+                use value_box::ReturnBoxerResult;
+                self::numeric::dot(array, another_array).or_log(0.0)
+            }
+
+            #[no_mangle]
+            pub extern "C" fn [<boxer_array_ $name _add_checked>](
+                array: *mut value_box::ValueBox<array_box::ArrayBox<$ty>>,
+                another_array: *mut value_box::ValueBox<array_box::ArrayBox<$ty>>,
+            ) -> value_box::BoxerStatus {
+                use value_box::ReturnBoxerStatus;
+                self::numeric::zip_with(array, another_array, array_box::ArrayBox::add).status()
+            }
+
+            #[no_mangle]
+            pub extern "C" fn [<boxer_array_ $name _sub_checked>](
+                array: *mut value_box::ValueBox<array_box::ArrayBox<$ty>>,
+                another_array: *mut value_box::ValueBox<array_box::ArrayBox<$ty>>,
+            ) -> value_box::BoxerStatus {
+                use value_box::ReturnBoxerStatus;
+                self::numeric::zip_with(array, another_array, array_box::ArrayBox::sub).status()
+            }
+
+            #[no_mangle]
+            pub extern "C" fn [<boxer_array_ $name _mul_checked>](
+                array: *mut value_box::ValueBox<array_box::ArrayBox<$ty>>,
+                another_array: *mut value_box::ValueBox<array_box::ArrayBox<$ty>>,
+            ) -> value_box::BoxerStatus {
+                use value_box::ReturnBoxerStatus;
+                self::numeric::zip_with(array, another_array, array_box::ArrayBox::mul).status()
+            }
+
+            #[no_mangle]
+            pub extern "C" fn [<boxer_array_ $name _clamp_checked>](
+                array: *mut value_box::ValueBox<array_box::ArrayBox<$ty>>,
+                min: $ty,
+                max: $ty,
+            ) -> value_box::BoxerStatus {
+                use value_box::ReturnBoxerStatus;
+                self::numeric::clamp(array, min, max).status()
+            }
+
+            #[no_mangle]
+            pub extern "C" fn [<boxer_array_ $name _mean_checked>](
+                array: *mut value_box::ValueBox<array_box::ArrayBox<$ty>>,
+                mean: *mut f64,
+            ) -> value_box::BoxerStatus {
+                use value_box::ReturnBoxerStatus;
+                self::numeric::reduce(array, array_box::ArrayBox::mean).write_to(mean)
+            }
+
+            #[no_mangle]
+            pub extern "C" fn [<boxer_array_ $name _min_checked>](
+                array: *mut value_box::ValueBox<array_box::ArrayBox<$ty>>,
+                min: *mut $ty,
+            ) -> value_box::BoxerStatus {
+                use value_box::ReturnBoxerStatus;
+                self::numeric::reduce(array, array_box::ArrayBox::min).write_to(min)
+            }
+
+            #[no_mangle]
+            pub extern "C" fn [<boxer_array_ $name _max_checked>](
+                array: *mut value_box::ValueBox<array_box::ArrayBox<$ty>>,
+                max: *mut $ty,
+            ) -> value_box::BoxerStatus {
+                use value_box::ReturnBoxerStatus;
+                self::numeric::reduce(array, array_box::ArrayBox::max).write_to(max)
+            }
+
+            #[no_mangle]
+            pub extern "C" fn [<boxer_array_ $name _dot_checked>](
+                array: *mut value_box::ValueBox<array_box::ArrayBox<$ty>>,
+                another_array: *mut value_box::ValueBox<array_box::ArrayBox<$ty>>,
+                dot: *mut f64,
+            ) -> value_box::BoxerStatus {
+                use value_box::ReturnBoxerStatus;
+                self::numeric::dot(array, another_array).write_to(dot)
+            }
+
+            pub(crate) fn [<boxer_array_ $name _register_numeric_manifest>](manifest: &mut $crate::manifest::FfiManifest) {
+                type Array = *mut value_box::ValueBox<array_box::ArrayBox<$ty>>;
+                type Status = value_box::BoxerStatus;
+                use $crate::manifest::ffi_function;
+
+                manifest.add(ffi_function!(Borrows fn [<boxer_array_ $name _add>](array: Array, another_array: Array)));
+                manifest.add(ffi_function!(Borrows fn [<boxer_array_ $name _sub>](array: Array, another_array: Array)));
+                manifest.add(ffi_function!(Borrows fn [<boxer_array_ $name _mul>](array: Array, another_array: Array)));
+                manifest.add(ffi_function!(Borrows fn [<boxer_array_ $name _add_scalar>](array: Array, scalar: $ty)));
+                manifest.add(ffi_function!(Borrows fn [<boxer_array_ $name _sub_scalar>](array: Array, scalar: $ty)));
+                manifest.add(ffi_function!(Borrows fn [<boxer_array_ $name _scale>](array: Array, factor: $ty)));
+                manifest.add(ffi_function!(Borrows fn [<boxer_array_ $name _clamp>](array: Array, min: $ty, max: $ty)));
+                manifest.add(ffi_function!(Borrows fn [<boxer_array_ $name _sum>](array: Array) -> f64));
+                manifest.add(ffi_function!(Borrows fn [<boxer_array_ $name _mean>](array: Array) -> f64));
+                manifest.add(ffi_function!(Borrows fn [<boxer_array_ $name _min>](array: Array) -> $ty));
+                manifest.add(ffi_function!(Borrows fn [<boxer_array_ $name _max>](array: Array) -> $ty));
+                manifest.add(ffi_function!(Borrows fn [<boxer_array_ $name _dot>](array: Array, another_array: Array) -> f64));
+                manifest.add(ffi_function!(Borrows fn [<boxer_array_ $name _add_checked>](array: Array, another_array: Array) -> Status));
+                manifest.add(ffi_function!(Borrows fn [<boxer_array_ $name _sub_checked>](array: Array, another_array: Array) -> Status));
+                manifest.add(ffi_function!(Borrows fn [<boxer_array_ $name _mul_checked>](array: Array, another_array: Array) -> Status));
+                manifest.add(ffi_function!(Borrows fn [<boxer_array_ $name _clamp_checked>](array: Array, min: $ty, max: $ty) -> Status));
+                manifest.add(ffi_function!(Borrows fn [<boxer_array_ $name _mean_checked>](array: Array, mean: *mut f64) -> Status));
+                manifest.add(ffi_function!(Borrows fn [<boxer_array_ $name _min_checked>](array: Array, min: *mut $ty) -> Status));
+                manifest.add(ffi_function!(Borrows fn [<boxer_array_ $name _max_checked>](array: Array, max: *mut $ty) -> Status));
+                manifest.add(ffi_function!(Borrows fn [<boxer_array_ $name _dot_checked>](array: Array, another_array: Array, dot: *mut f64) -> Status));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use value_box::BoxerErrorKind;
+
+    #[test]
+    fn test_element_wise() {
+        let array = boxer_array_f32_create_with(2.0, 4);
+        let another_array = boxer_array_f32_create_with(3.0, 4);
+
+        boxer_array_f32_mul(array, another_array);
+        boxer_array_f32_add(array, array);
+        boxer_array_f32_scale(array, 0.5);
+        boxer_array_f32_clamp(array, 0.0, 5.0);
+        assert_eq!(boxer_array_f32_at(array, 3), 5.0);
+        assert_eq!(boxer_array_f32_dot(array, another_array), 60.0);
+        assert_eq!(boxer_array_f32_dot(array, array), 100.0);
+
+        let shorter_array = boxer_array_f32_create_with(1.0, 3);
+        assert_eq!(
+            boxer_array_f32_sub_checked(array, shorter_array),
+            BoxerErrorKind::LengthMismatch
+        );
+        assert_eq!(
+            boxer_array_f32_clamp_checked(array, f32::NAN, 1.0),
+            BoxerErrorKind::InvalidArgument
+        );
+        assert_eq!(
+            boxer_array_f32_clamp_checked(array, 2.0, 1.0),
+            BoxerErrorKind::InvalidArgument
+        );
+
+        boxer_array_f32_drop(shorter_array);
+        boxer_array_f32_drop(another_array);
+        boxer_array_f32_drop(array);
+    }
+
+    #[test]
+    fn test_reductions() {
+        let array = boxer_array_u8_create_with(200, 10);
+        boxer_array_u8_at_put(array, 0, 0);
+        assert_eq!(boxer_array_u8_sum(array), 1800.0);
+        assert_eq!(boxer_array_u8_mean(array), 180.0);
+        assert_eq!(boxer_array_u8_min(array), 0);
+        assert_eq!(boxer_array_u8_max(array), 200);
+
+        boxer_array_u8_add_scalar(array, 100);
+        assert_eq!(boxer_array_u8_max(array), 255);
+        boxer_array_u8_drop(array);
+
+        let empty = boxer_array_i64_create();
+        let mut min = 0;
+        assert_eq!(
+            boxer_array_i64_min_checked(empty, &mut min),
+            BoxerErrorKind::NoValue
+        );
+        boxer_array_i64_drop(empty);
+    }
+}
@@ -0,0 +1,33 @@
+use array_box::{parallel_threshold, parallelism, set_parallel_threshold, set_parallelism};
+
+use crate::manifest::{ffi_function, FfiManifest};
+
+/// Set the amount of worker threads of bulk array operations,
+/// 0 resets it to the amount of available cores and 1 disables the workers
+#[no_mangle]
+pub extern "C" fn boxer_set_parallelism(threads: usize) {
+    set_parallelism(threads);
+}
+
+#[no_mangle]
+pub extern "C" fn boxer_get_parallelism() -> usize {
+    parallelism()
+}
+
+/// Set the amount of array items from which bulk operations are split between the workers
+#[no_mangle]
+pub extern "C" fn boxer_set_parallel_threshold(items: usize) {
+    set_parallel_threshold(items);
+}
+
+#[no_mangle]
+pub extern "C" fn boxer_get_parallel_threshold() -> usize {
+    parallel_threshold()
+}
+
+pub(crate) fn register_manifest(manifest: &mut FfiManifest) {
+    manifest.add(ffi_function!(None fn boxer_set_parallelism(threads: usize)));
+    manifest.add(ffi_function!(None fn boxer_get_parallelism() -> usize));
+    manifest.add(ffi_function!(None fn boxer_set_parallel_threshold(items: usize)));
+    manifest.add(ffi_function!(None fn boxer_get_parallel_threshold() -> usize));
+}
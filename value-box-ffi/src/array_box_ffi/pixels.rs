@@ -0,0 +1,487 @@
+use array_box::{for_each_chunk_mut, for_each_chunk_zip, LengthMismatch};
+use value_box::{BoxerError, Result};
+
+/// The layout of a pixel in memory, the name lists the channels in byte order.
+/// For example the bytes of an `Argb32` pixel are alpha, red, green and blue.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[repr(u8)]
+pub enum PixelFormat {
+    Argb32 = 0,
+    Rgba32 = 1,
+    Bgra32 = 2,
+    Abgr32 = 3,
+    Rgb24 = 4,
+    Bgr24 = 5,
+    /// A single luminance channel
+    Gray8 = 6,
+}
+
+impl TryFrom<u8> for PixelFormat {
+    type Error = BoxerError;
+
+    fn try_from(value: u8) -> Result<Self> {
+        match value {
+            0 => Ok(Self::Argb32),
+            1 => Ok(Self::Rgba32),
+            2 => Ok(Self::Bgra32),
+            3 => Ok(Self::Abgr32),
+            4 => Ok(Self::Rgb24),
+            5 => Ok(Self::Bgr24),
+            6 => Ok(Self::Gray8),
+            _ => BoxerError::InvalidArgument(format!("Unknown pixel format {}", value)).into(),
+        }
+    }
+}
+
+const RED: usize = 0;
+const GREEN: usize = 1;
+const BLUE: usize = 2;
+const ALPHA: usize = 3;
+
+impl PixelFormat {
+    pub fn bytes_per_pixel(&self) -> usize {
+        match self {
+            PixelFormat::Argb32
+            | PixelFormat::Rgba32
+            | PixelFormat::Bgra32
+            | PixelFormat::Abgr32 => 4,
+            PixelFormat::Rgb24 | PixelFormat::Bgr24 => 3,
+            PixelFormat::Gray8 => 1,
+        }
+    }
+
+    pub fn has_alpha(&self) -> bool {
+        self.alpha_index().is_some()
+    }
+
+    /// The channel stored in each byte of a pixel, gray pixels have no channels
+    fn channels(&self) -> &'static [usize] {
+        match self {
+            PixelFormat::Argb32 => &[ALPHA, RED, GREEN, BLUE],
+            PixelFormat::Rgba32 => &[RED, GREEN, BLUE, ALPHA],
+            PixelFormat::Bgra32 => &[BLUE, GREEN, RED, ALPHA],
+            PixelFormat::Abgr32 => &[ALPHA, BLUE, GREEN, RED],
+            PixelFormat::Rgb24 => &[RED, GREEN, BLUE],
+            PixelFormat::Bgr24 => &[BLUE, GREEN, RED],
+            PixelFormat::Gray8 => &[],
+        }
+    }
+
+    fn alpha_index(&self) -> Option<usize> {
+        self.channels().iter().position(|channel| *channel == ALPHA)
+    }
+
+    /// Read a pixel as an `0xAARRGGBB` color, pixels without alpha are opaque
+    pub fn read_argb(&self, pixel: &[u8]) -> u32 {
+        let [red, green, blue, alpha] = self.decode(pixel, u8::MAX);
+        u32::from_be_bytes([alpha, red, green, blue])
+    }
+
+    /// Write an `0xAARRGGBB` color into a pixel, formats without alpha ignore it
+    pub fn write_argb(&self, argb: u32, pixel: &mut [u8]) {
+        let [alpha, red, green, blue] = argb.to_be_bytes();
+        self.encode([red, green, blue, alpha], pixel);
+    }
+
+    /// Read a pixel as red, green, blue and alpha, pixels without alpha are opaque unless
+    /// a given alpha is used instead
+    #[inline]
+    fn decode(&self, pixel: &[u8], alpha: u8) -> [u8; 4] {
+        match self {
+            PixelFormat::Gray8 => [pixel[0], pixel[0], pixel[0], alpha],
+            _ => {
+                let mut rgba = [0, 0, 0, alpha];
+                for (byte, channel) in pixel.iter().zip(self.channels()) {
+                    rgba[*channel] = *byte;
+                }
+                rgba
+            }
+        }
+    }
+
+    #[inline]
+    fn encode(&self, rgba: [u8; 4], pixel: &mut [u8]) {
+        match self {
+            PixelFormat::Gray8 => pixel[0] = luminance(rgba),
+            _ => {
+                for (byte, channel) in pixel.iter_mut().zip(self.channels()) {
+                    *byte = rgba[*channel];
+                }
+            }
+        }
+    }
+}
+
+/// The luma of the ITU-R BT.601 standard in fixed point arithmetic
+#[inline]
+fn luminance(rgba: [u8; 4]) -> u8 {
+    ((rgba[RED] as u32 * 77 + rgba[GREEN] as u32 * 150 + rgba[BLUE] as u32 * 29 + 128) >> 8) as u8
+}
+
+/// Return the amount of pixels in a buffer or fail if its length is not a multiple of the pixel size
+pub fn pixel_count(pixels: &[u8], format: PixelFormat) -> Result<usize> {
+    let bytes_per_pixel = format.bytes_per_pixel();
+    if pixels.len().is_multiple_of(bytes_per_pixel) {
+        Ok(pixels.len() / bytes_per_pixel)
+    } else {
+        BoxerError::InvalidArgument(format!(
+            "The length {} is not a multiple of {} bytes of a {:?} pixel",
+            pixels.len(),
+            bytes_per_pixel,
+            format
+        ))
+        .into()
+    }
+}
+
+/// Convert tightly packed pixels into a destination of the same amount of pixels.
+/// Formats without alpha are converted to formats with alpha using a given alpha.
+pub fn convert_pixels(
+    source: &[u8],
+    source_format: PixelFormat,
+    destination: &mut [u8],
+    destination_format: PixelFormat,
+    alpha: u8,
+) -> Result<()> {
+    let count = pixel_count(source, source_format)?;
+    let expected = count * destination_format.bytes_per_pixel();
+    if destination.len() != expected {
+        return BoxerError::LengthMismatch {
+            expected,
+            actual: destination.len(),
+        }
+        .into();
+    }
+
+    for_each_chunk_zip(
+        source,
+        source_format.bytes_per_pixel(),
+        destination,
+        destination_format.bytes_per_pixel(),
+        |source, destination| {
+            convert_pixel_chunk(
+                source,
+                source_format,
+                destination,
+                destination_format,
+                alpha,
+            )
+        },
+    )
+    .map_err(length_mismatch)
+}
+
+fn convert_pixel_chunk(
+    source: &[u8],
+    source_format: PixelFormat,
+    destination: &mut [u8],
+    destination_format: PixelFormat,
+    alpha: u8,
+) {
+    if source_format == destination_format {
+        destination.copy_from_slice(source);
+        return;
+    }
+    for (source_pixel, destination_pixel) in source
+        .chunks_exact(source_format.bytes_per_pixel())
+        .zip(destination.chunks_exact_mut(destination_format.bytes_per_pixel()))
+    {
+        destination_format.encode(source_format.decode(source_pixel, alpha), destination_pixel);
+    }
+}
+
+/// Convert pixels in place between two formats of the same pixel size,
+/// for example to reorder the channels of 4-byte pixels.
+pub fn convert_pixels_in_place(
+    pixels: &mut [u8],
+    source_format: PixelFormat,
+    destination_format: PixelFormat,
+) -> Result<()> {
+    if source_format.bytes_per_pixel() != destination_format.bytes_per_pixel() {
+        return BoxerError::InvalidArgument(format!(
+            "Can not convert {:?} to {:?} in place, the pixel sizes differ",
+            source_format, destination_format
+        ))
+        .into();
+    }
+    pixel_count(pixels, source_format)?;
+
+    let bytes_per_pixel = source_format.bytes_per_pixel();
+    for_each_chunk_mut(pixels, bytes_per_pixel, |pixels| {
+        for pixel in pixels.chunks_exact_mut(bytes_per_pixel) {
+            let rgba = source_format.decode(pixel, u8::MAX);
+            destination_format.encode(rgba, pixel);
+        }
+    });
+    Ok(())
+}
+
+/// The layout of the rows of an image whose rows may be padded to a stride in bytes
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct PixelRows {
+    pub width: usize,
+    pub height: usize,
+    pub stride: usize,
+    pub format: PixelFormat,
+}
+
+impl PixelRows {
+    pub fn new(width: usize, height: usize, stride: usize, format: PixelFormat) -> Self {
+        Self {
+            width,
+            height,
+            stride,
+            format,
+        }
+    }
+
+    pub fn row_length(&self) -> usize {
+        self.width.saturating_mul(self.format.bytes_per_pixel())
+    }
+
+    /// Return the amount of bytes from the start of the first row to the end of the last row
+    pub fn length(&self) -> usize {
+        match self.height {
+            0 => 0,
+            height => self
+                .stride
+                .saturating_mul(height - 1)
+                .saturating_add(self.row_length()),
+        }
+    }
+
+    /// Fail if the rows do not fit within the stride or a buffer of a given length
+    pub fn check(&self, length: usize) -> Result<()> {
+        if self.stride < self.row_length() {
+            return BoxerError::InvalidArgument(format!(
+                "The stride {} is smaller than a row of {} bytes",
+                self.stride,
+                self.row_length()
+            ))
+            .into();
+        }
+        let expected = self.length();
+        if length < expected {
+            return BoxerError::LengthMismatch {
+                expected,
+                actual: length,
+            }
+            .into();
+        }
+        Ok(())
+    }
+
+    pub fn rows<'a>(&self, pixels: &'a [u8]) -> impl Iterator<Item = &'a [u8]> {
+        let row_length = self.row_length();
+        pixels
+            .chunks(self.stride.max(1))
+            .take(self.height)
+            .map(move |row| &row[..row_length])
+    }
+
+    pub fn rows_mut<'a>(&self, pixels: &'a mut [u8]) -> impl Iterator<Item = &'a mut [u8]> {
+        let row_length = self.row_length();
+        pixels
+            .chunks_mut(self.stride.max(1))
+            .take(self.height)
+            .map(move |row| &mut row[..row_length])
+    }
+}
+
+/// Convert the pixels of an image row by row, skipping the padding of the rows.
+pub fn convert_pixel_rows(
+    source: &[u8],
+    source_rows: PixelRows,
+    destination: &mut [u8],
+    destination_rows: PixelRows,
+    alpha: u8,
+) -> Result<()> {
+    if (source_rows.width, source_rows.height) != (destination_rows.width, destination_rows.height)
+    {
+        return BoxerError::InvalidArgument(format!(
+            "The source of {}x{} pixels does not match the destination of {}x{} pixels",
+            source_rows.width, source_rows.height, destination_rows.width, destination_rows.height
+        ))
+        .into();
+    }
+    source_rows.check(source.len())?;
+    destination_rows.check(destination.len())?;
+    if source_rows.row_length() == 0 {
+        // rows without pixels may be split into different amounts of strides
+        return Ok(());
+    }
+
+    // whole rows are converted on the same worker
+    for_each_chunk_zip(
+        &source[..source_rows.length()],
+        source_rows.stride.max(1),
+        &mut destination[..destination_rows.length()],
+        destination_rows.stride.max(1),
+        |source, destination| {
+            for (source_row, destination_row) in source_rows
+                .rows(source)
+                .zip(destination_rows.rows_mut(destination))
+            {
+                convert_pixel_chunk(
+                    source_row,
+                    source_rows.format,
+                    destination_row,
+                    destination_rows.format,
+                    alpha,
+                );
+            }
+        },
+    )
+    .map_err(length_mismatch)
+}
+
+fn length_mismatch(mismatch: LengthMismatch) -> BoxerError {
+    BoxerError::LengthMismatch {
+        expected: mismatch.expected,
+        actual: mismatch.actual,
+    }
+}
+
+/// Multiply the color channels by the alpha of each pixel
+pub fn premultiply_alpha(pixels: &mut [u8], format: PixelFormat) -> Result<()> {
+    map_alpha_pixels(pixels, format, |channel, alpha| {
+        ((channel as u32 * alpha as u32 + 127) / 255) as u8
+    })
+}
+
+/// Divide the color channels by the alpha of each pixel, fully transparent pixels become black
+pub fn unpremultiply_alpha(pixels: &mut [u8], format: PixelFormat) -> Result<()> {
+    map_alpha_pixels(pixels, format, |channel, alpha| match alpha {
+        0 => 0,
+        alpha => ((channel as u32 * 255 + alpha as u32 / 2) / alpha as u32).min(255) as u8,
+    })
+}
+
+fn map_alpha_pixels(
+    pixels: &mut [u8],
+    format: PixelFormat,
+    op: impl Fn(u8, u8) -> u8 + Send + Sync,
+) -> Result<()> {
+    let Some(alpha_index) = format.alpha_index() else {
+        return BoxerError::InvalidArgument(format!("The {:?} pixels have no alpha", format))
+            .into();
+    };
+    pixel_count(pixels, format)?;
+
+    let bytes_per_pixel = format.bytes_per_pixel();
+    for_each_chunk_mut(pixels, bytes_per_pixel, |pixels| {
+        for pixel in pixels.chunks_exact_mut(bytes_per_pixel) {
+            let alpha = pixel[alpha_index];
+            for (index, channel) in pixel.iter_mut().enumerate() {
+                if index != alpha_index {
+                    *channel = op(*channel, alpha);
+                }
+            }
+        }
+    });
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_convert_pixels() -> Result<()> {
+        let rgb = [10, 20, 30, 40, 50, 60];
+        let mut bgra = [0; 8];
+        convert_pixels(
+            &rgb,
+            PixelFormat::Rgb24,
+            &mut bgra,
+            PixelFormat::Bgra32,
+            255,
+        )?;
+        assert_eq!(bgra, [30, 20, 10, 255, 60, 50, 40, 255]);
+
+        convert_pixels_in_place(&mut bgra, PixelFormat::Bgra32, PixelFormat::Argb32)?;
+        assert_eq!(bgra, [255, 10, 20, 30, 255, 40, 50, 60]);
+
+        let mut gray = [0; 2];
+        convert_pixels(&bgra, PixelFormat::Argb32, &mut gray, PixelFormat::Gray8, 0)?;
+        assert_eq!(gray, [18, 48]);
+
+        assert!(matches!(
+            convert_pixels(
+                &rgb[..5],
+                PixelFormat::Rgb24,
+                &mut gray,
+                PixelFormat::Gray8,
+                0
+            ),
+            Err(BoxerError::InvalidArgument(_))
+        ));
+        assert!(matches!(
+            convert_pixels(
+                &rgb,
+                PixelFormat::Rgb24,
+                &mut bgra[..4],
+                PixelFormat::Rgba32,
+                0
+            ),
+            Err(BoxerError::LengthMismatch {
+                expected: 8,
+                actual: 4
+            })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_convert_pixel_rows() -> Result<()> {
+        // two rows of one gray pixel, padded to 4 bytes
+        let gray = [100, 0, 0, 0, 200];
+        let mut rgba = [0; 8];
+        convert_pixel_rows(
+            &gray,
+            PixelRows::new(1, 2, 4, PixelFormat::Gray8),
+            &mut rgba,
+            PixelRows::new(1, 2, 4, PixelFormat::Rgba32),
+            255,
+        )?;
+        assert_eq!(rgba, [100, 100, 100, 255, 200, 200, 200, 255]);
+
+        // large enough to be split between the workers
+        let (width, height) = (300, 200);
+        let gray: Vec<u8> = (0..height * 320).map(|index| (index % 320) as u8).collect();
+        let mut rgb = vec![0; (height - 1) * 903 + width * 3];
+        convert_pixel_rows(
+            &gray,
+            PixelRows::new(width, height, 320, PixelFormat::Gray8),
+            &mut rgb,
+            PixelRows::new(width, height, 903, PixelFormat::Rgb24),
+            255,
+        )?;
+        assert!(rgb.chunks(903).all(|row| row[..width * 3]
+            .chunks(3)
+            .enumerate()
+            .all(|(index, pixel)| pixel == [index as u8; 3])));
+
+        assert!(matches!(
+            PixelRows::new(2, 2, 4, PixelFormat::Rgb24).check(16),
+            Err(BoxerError::InvalidArgument(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_premultiply_alpha() -> Result<()> {
+        let mut rgba = [200, 100, 50, 128, 10, 20, 30, 0];
+        premultiply_alpha(&mut rgba, PixelFormat::Rgba32)?;
+        assert_eq!(rgba, [100, 50, 25, 128, 0, 0, 0, 0]);
+
+        unpremultiply_alpha(&mut rgba, PixelFormat::Rgba32)?;
+        assert_eq!(rgba, [199, 100, 50, 128, 0, 0, 0, 0]);
+
+        assert!(matches!(
+            premultiply_alpha(&mut rgba[..6], PixelFormat::Rgb24),
+            Err(BoxerError::InvalidArgument(_))
+        ));
+        Ok(())
+    }
+}
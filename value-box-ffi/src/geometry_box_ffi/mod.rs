@@ -15,4 +15,64 @@ pub mod size_u32;
 pub mod size_u64;
 
 pub use point::BoxerPointFFI;
+#[cfg(feature = "serde")]
+pub use serialization::*;
 pub use size::SizeBoxFFI;
+
+use crate::manifest::FfiManifest;
+
+#[cfg(feature = "serde")]
+mod serialization {
+    use crate::{serde_ffi, Serializable};
+    use geometry_box::{Point3Box, PointBox, SizeBox, U128Box};
+    use serde::Serialize;
+
+    impl<T: From<u8> + Default + Copy + Serialize> Serializable for PointBox<T> {}
+    impl<T: From<u8> + Default + Copy + Serialize> Serializable for Point3Box<T> {}
+    impl<T: From<u8> + Default + Copy + Serialize> Serializable for SizeBox<T> {}
+    impl Serializable for U128Box {}
+
+    serde_ffi!(PointBox<f32>, point_f32);
+    serde_ffi!(PointBox<f64>, point_f64);
+    serde_ffi!(PointBox<i32>, point_i32);
+    serde_ffi!(PointBox<u64>, point_u64);
+    serde_ffi!(Point3Box<f32>, point3_f32);
+    serde_ffi!(SizeBox<f32>, size_f32);
+    serde_ffi!(SizeBox<f64>, size_f64);
+    serde_ffi!(SizeBox<i32>, size_i32);
+    serde_ffi!(SizeBox<u32>, size_u32);
+    serde_ffi!(SizeBox<u64>, size_u64);
+    serde_ffi!(U128Box, number_uint128);
+}
+
+pub(crate) fn register_manifest(manifest: &mut FfiManifest) {
+    manifest.set_origin("geometry-box", "geometry-box");
+
+    number_uint128::register_manifest(manifest);
+    point3_f32::register_manifest(manifest);
+    point_f32::register_manifest(manifest);
+    point_f64::register_manifest(manifest);
+    point_i32::register_manifest(manifest);
+    point_u64::register_manifest(manifest);
+    range_usize::register_manifest(manifest);
+    size_f32::register_manifest(manifest);
+    size_f64::register_manifest(manifest);
+    size_i32::register_manifest(manifest);
+    size_u32::register_manifest(manifest);
+    size_u64::register_manifest(manifest);
+
+    #[cfg(feature = "serde")]
+    {
+        serialization::boxer_point_f32_register_serde_manifest(manifest);
+        serialization::boxer_point_f64_register_serde_manifest(manifest);
+        serialization::boxer_point_i32_register_serde_manifest(manifest);
+        serialization::boxer_point_u64_register_serde_manifest(manifest);
+        serialization::boxer_point3_f32_register_serde_manifest(manifest);
+        serialization::boxer_size_f32_register_serde_manifest(manifest);
+        serialization::boxer_size_f64_register_serde_manifest(manifest);
+        serialization::boxer_size_i32_register_serde_manifest(manifest);
+        serialization::boxer_size_u32_register_serde_manifest(manifest);
+        serialization::boxer_size_u64_register_serde_manifest(manifest);
+        serialization::boxer_number_uint128_register_serde_manifest(manifest);
+    }
+}
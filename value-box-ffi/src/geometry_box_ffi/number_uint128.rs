@@ -1,6 +1,8 @@
 use geometry_box::U128Box;
 use value_box::{ReturnBoxerResult, ValueBox, ValueBoxPointer};
 
+use crate::manifest::{ffi_function, FfiManifest};
+
 #[no_mangle]
 pub extern "C" fn boxer_number_uint128_create() -> *mut ValueBox<U128Box> {
     ValueBox::new(U128Box::default()).into_raw()
@@ -40,3 +42,17 @@ pub extern "C" fn boxer_number_uint128_set_max(number: *mut ValueBox<U128Box>) {
 pub extern "C" fn boxer_number_uint128_set_min(number: *mut ValueBox<U128Box>) {
     number.with_mut_ok(|number| number.set(u128::MIN)).log();
 }
+
+pub(crate) fn register_manifest(manifest: &mut FfiManifest) {
+    type Number = *mut ValueBox<U128Box>;
+
+    manifest.add(ffi_function!(Creates fn boxer_number_uint128_create() -> Number));
+    manifest.add(ffi_function!(Consumes fn boxer_number_uint128_drop(ptr: Number)));
+    manifest.add(ffi_function!(Borrows fn boxer_number_uint128_get_low(number: Number) -> u64));
+    manifest.add(ffi_function!(Borrows fn boxer_number_uint128_set_low(number: Number, low: u64)));
+    manifest.add(ffi_function!(Borrows fn boxer_number_uint128_get_high(number: Number) -> u64));
+    manifest
+        .add(ffi_function!(Borrows fn boxer_number_uint128_set_high(number: Number, high: u64)));
+    manifest.add(ffi_function!(Borrows fn boxer_number_uint128_set_max(number: Number)));
+    manifest.add(ffi_function!(Borrows fn boxer_number_uint128_set_min(number: Number)));
+}
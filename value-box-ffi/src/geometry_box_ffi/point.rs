@@ -1,6 +1,8 @@
 use geometry_box::PointBox;
 use std::any::Any;
-use value_box::{ReturnBoxerResult, ValueBox, ValueBoxPointer};
+use value_box::{BoxerStatus, ReturnBoxerResult, ReturnBoxerStatus, ValueBox, ValueBoxPointer};
+
+use crate::manifest::{ffi_function, FfiManifest, FfiType};
 
 pub trait BoxerPointFFI<T>
 where
@@ -19,6 +21,20 @@ where
     fn boxer_point_get_y(point_box: *mut ValueBox<PointBox<T>>) -> T;
 
     fn boxer_point_set_y(point_box: *mut ValueBox<PointBox<T>>, y: T);
+
+    fn boxer_point_get_x_checked(_ptr: *mut ValueBox<PointBox<T>>, x: *mut T) -> BoxerStatus;
+
+    fn boxer_point_set_x_checked(_ptr: *mut ValueBox<PointBox<T>>, x: T) -> BoxerStatus;
+
+    fn boxer_point_get_y_checked(_ptr: *mut ValueBox<PointBox<T>>, y: *mut T) -> BoxerStatus;
+
+    fn boxer_point_set_y_checked(_ptr: *mut ValueBox<PointBox<T>>, y: T) -> BoxerStatus;
+
+    /// Describe the functions exported for the boxed value with a given name prefix,
+    /// for example `boxer_point_f32`.
+    fn boxer_point_register_manifest(manifest: &mut FfiManifest, prefix: &str)
+    where
+        T: FfiType;
 }
 
 impl<T> BoxerPointFFI<T> for PointBox<T>
@@ -52,4 +68,37 @@ where
     fn boxer_point_set_y(point_box: *mut ValueBox<PointBox<T>>, y: T) {
         point_box.with_mut_ok(|point| point.y = y).log();
     }
+
+    fn boxer_point_get_x_checked(point_box: *mut ValueBox<PointBox<T>>, x: *mut T) -> BoxerStatus {
+        point_box.with_ref_ok(|point| point.x).write_to(x)
+    }
+
+    fn boxer_point_set_x_checked(point_box: *mut ValueBox<PointBox<T>>, x: T) -> BoxerStatus {
+        point_box.with_mut_ok(|point| point.x = x).status()
+    }
+
+    fn boxer_point_get_y_checked(point_box: *mut ValueBox<PointBox<T>>, y: *mut T) -> BoxerStatus {
+        point_box.with_ref_ok(|point| point.y).write_to(y)
+    }
+
+    fn boxer_point_set_y_checked(point_box: *mut ValueBox<PointBox<T>>, y: T) -> BoxerStatus {
+        point_box.with_mut_ok(|point| point.y = y).status()
+    }
+
+    fn boxer_point_register_manifest(manifest: &mut FfiManifest, prefix: &str)
+    where
+        T: FfiType,
+    {
+        manifest.add(ffi_function!(Creates fn [format!("{}_default", prefix)]() -> *mut ValueBox<PointBox<T>>));
+        manifest.add(ffi_function!(Creates fn [format!("{}_create", prefix)](x: T, y: T) -> *mut ValueBox<PointBox<T>>));
+        manifest.add(ffi_function!(Consumes fn [format!("{}_drop", prefix)](point: *mut ValueBox<PointBox<T>>)));
+        manifest.add(ffi_function!(Borrows fn [format!("{}_get_x", prefix)](point: *mut ValueBox<PointBox<T>>) -> T));
+        manifest.add(ffi_function!(Borrows fn [format!("{}_set_x", prefix)](point: *mut ValueBox<PointBox<T>>, x: T)));
+        manifest.add(ffi_function!(Borrows fn [format!("{}_get_y", prefix)](point: *mut ValueBox<PointBox<T>>) -> T));
+        manifest.add(ffi_function!(Borrows fn [format!("{}_set_y", prefix)](point: *mut ValueBox<PointBox<T>>, y: T)));
+        manifest.add(ffi_function!(Borrows fn [format!("{}_get_x_checked", prefix)](point: *mut ValueBox<PointBox<T>>, x: *mut T) -> BoxerStatus));
+        manifest.add(ffi_function!(Borrows fn [format!("{}_set_x_checked", prefix)](point: *mut ValueBox<PointBox<T>>, x: T) -> BoxerStatus));
+        manifest.add(ffi_function!(Borrows fn [format!("{}_get_y_checked", prefix)](point: *mut ValueBox<PointBox<T>>, y: *mut T) -> BoxerStatus));
+        manifest.add(ffi_function!(Borrows fn [format!("{}_set_y_checked", prefix)](point: *mut ValueBox<PointBox<T>>, y: T) -> BoxerStatus));
+    }
 }
@@ -2,6 +2,8 @@ use geometry_box::Point3Box;
 use std::any::Any;
 use value_box::{ReturnBoxerResult, ValueBox, ValueBoxPointer};
 
+use crate::manifest::{ffi_function, FfiManifest, FfiType};
+
 pub trait Point3BoxFFI<T>
 where
     T: From<u8> + Default + Copy + Any,
@@ -23,6 +25,12 @@ where
     fn boxer_point_get_z(_maybe_null_ptr: *mut ValueBox<Point3Box<T>>) -> T;
 
     fn boxer_point_set_z(_maybe_null_ptr: *mut ValueBox<Point3Box<T>>, z: T);
+
+    /// Describe the functions exported for the boxed value with a given name prefix,
+    /// for example `boxer_point3_f32`.
+    fn boxer_point_register_manifest(manifest: &mut FfiManifest, prefix: &str)
+    where
+        T: FfiType;
 }
 
 impl<T> Point3BoxFFI<T> for Point3Box<T>
@@ -64,4 +72,19 @@ where
     fn boxer_point_set_z(point: *mut ValueBox<Point3Box<T>>, z: T) {
         point.with_mut_ok(|point| point.z = z).log();
     }
+
+    fn boxer_point_register_manifest(manifest: &mut FfiManifest, prefix: &str)
+    where
+        T: FfiType,
+    {
+        manifest.add(ffi_function!(Creates fn [format!("{}_default", prefix)]() -> *mut ValueBox<Point3Box<T>>));
+        manifest.add(ffi_function!(Creates fn [format!("{}_create", prefix)](x: T, y: T, z: T) -> *mut ValueBox<Point3Box<T>>));
+        manifest.add(ffi_function!(Consumes fn [format!("{}_drop", prefix)](point: *mut ValueBox<Point3Box<T>>)));
+        manifest.add(ffi_function!(Borrows fn [format!("{}_get_x", prefix)](point: *mut ValueBox<Point3Box<T>>) -> T));
+        manifest.add(ffi_function!(Borrows fn [format!("{}_set_x", prefix)](point: *mut ValueBox<Point3Box<T>>, x: T)));
+        manifest.add(ffi_function!(Borrows fn [format!("{}_get_y", prefix)](point: *mut ValueBox<Point3Box<T>>) -> T));
+        manifest.add(ffi_function!(Borrows fn [format!("{}_set_y", prefix)](point: *mut ValueBox<Point3Box<T>>, y: T)));
+        manifest.add(ffi_function!(Borrows fn [format!("{}_get_z", prefix)](point: *mut ValueBox<Point3Box<T>>) -> T));
+        manifest.add(ffi_function!(Borrows fn [format!("{}_set_z", prefix)](point: *mut ValueBox<Point3Box<T>>, z: T)));
+    }
 }
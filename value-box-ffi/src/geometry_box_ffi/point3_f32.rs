@@ -1,6 +1,8 @@
 use geometry_box::Point3Box;
 use value_box::ValueBox;
 
+use crate::manifest::FfiManifest;
+
 use crate::point3::Point3BoxFFI;
 
 pub type BoxerPoint3F32 = Point3Box<f32>;
@@ -49,3 +51,7 @@ pub extern "C" fn boxer_point3_f32_get_z(_point_ptr: *mut ValueBox<BoxerPoint3F3
 pub extern "C" fn boxer_point3_f32_set_z(_point_ptr: *mut ValueBox<BoxerPoint3F32>, z: f32) {
     BoxerPoint3F32::boxer_point_set_z(_point_ptr, z);
 }
+
+pub(crate) fn register_manifest(manifest: &mut FfiManifest) {
+    BoxerPoint3F32::boxer_point_register_manifest(manifest, "boxer_point3_f32");
+}
@@ -1,5 +1,7 @@
 use geometry_box::PointBox;
-use value_box::ValueBox;
+use value_box::{BoxerStatus, ValueBox};
+
+use crate::manifest::FfiManifest;
 
 use crate::point::BoxerPointFFI;
 
@@ -39,3 +41,39 @@ pub extern "C" fn boxer_point_f64_get_y(_point_ptr: *mut ValueBox<BoxerPointF64>
 pub extern "C" fn boxer_point_f64_set_y(_point_ptr: *mut ValueBox<BoxerPointF64>, y: f64) {
     BoxerPointF64::boxer_point_set_y(_point_ptr, y);
 }
+
+#[no_mangle]
+pub extern "C" fn boxer_point_f64_get_x_checked(
+    _point_ptr: *mut ValueBox<BoxerPointF64>,
+    x: *mut f64,
+) -> BoxerStatus {
+    BoxerPointF64::boxer_point_get_x_checked(_point_ptr, x)
+}
+
+#[no_mangle]
+pub extern "C" fn boxer_point_f64_set_x_checked(
+    _point_ptr: *mut ValueBox<BoxerPointF64>,
+    x: f64,
+) -> BoxerStatus {
+    BoxerPointF64::boxer_point_set_x_checked(_point_ptr, x)
+}
+
+#[no_mangle]
+pub extern "C" fn boxer_point_f64_get_y_checked(
+    _point_ptr: *mut ValueBox<BoxerPointF64>,
+    y: *mut f64,
+) -> BoxerStatus {
+    BoxerPointF64::boxer_point_get_y_checked(_point_ptr, y)
+}
+
+#[no_mangle]
+pub extern "C" fn boxer_point_f64_set_y_checked(
+    _point_ptr: *mut ValueBox<BoxerPointF64>,
+    y: f64,
+) -> BoxerStatus {
+    BoxerPointF64::boxer_point_set_y_checked(_point_ptr, y)
+}
+
+pub(crate) fn register_manifest(manifest: &mut FfiManifest) {
+    BoxerPointF64::boxer_point_register_manifest(manifest, "boxer_point_f64");
+}
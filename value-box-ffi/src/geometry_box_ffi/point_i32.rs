@@ -1,5 +1,7 @@
 use geometry_box::PointBox;
-use value_box::ValueBox;
+use value_box::{BoxerStatus, ValueBox};
+
+use crate::manifest::FfiManifest;
 
 use crate::point::BoxerPointFFI;
 
@@ -39,3 +41,39 @@ pub extern "C" fn boxer_point_i32_get_y(point_ptr: *mut ValueBox<BoxerPointI32>)
 pub extern "C" fn boxer_point_i32_set_y(_point_ptr: *mut ValueBox<BoxerPointI32>, y: i32) {
     BoxerPointI32::boxer_point_set_y(_point_ptr, y);
 }
+
+#[no_mangle]
+pub extern "C" fn boxer_point_i32_get_x_checked(
+    point_ptr: *mut ValueBox<BoxerPointI32>,
+    x: *mut i32,
+) -> BoxerStatus {
+    BoxerPointI32::boxer_point_get_x_checked(point_ptr, x)
+}
+
+#[no_mangle]
+pub extern "C" fn boxer_point_i32_set_x_checked(
+    point_ptr: *mut ValueBox<BoxerPointI32>,
+    x: i32,
+) -> BoxerStatus {
+    BoxerPointI32::boxer_point_set_x_checked(point_ptr, x)
+}
+
+#[no_mangle]
+pub extern "C" fn boxer_point_i32_get_y_checked(
+    point_ptr: *mut ValueBox<BoxerPointI32>,
+    y: *mut i32,
+) -> BoxerStatus {
+    BoxerPointI32::boxer_point_get_y_checked(point_ptr, y)
+}
+
+#[no_mangle]
+pub extern "C" fn boxer_point_i32_set_y_checked(
+    point_ptr: *mut ValueBox<BoxerPointI32>,
+    y: i32,
+) -> BoxerStatus {
+    BoxerPointI32::boxer_point_set_y_checked(point_ptr, y)
+}
+
+pub(crate) fn register_manifest(manifest: &mut FfiManifest) {
+    BoxerPointI32::boxer_point_register_manifest(manifest, "boxer_point_i32");
+}
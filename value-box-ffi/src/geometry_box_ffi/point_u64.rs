@@ -1,5 +1,7 @@
 use geometry_box::PointBox;
-use value_box::ValueBox;
+use value_box::{BoxerStatus, ValueBox};
+
+use crate::manifest::FfiManifest;
 
 use crate::point::BoxerPointFFI;
 
@@ -39,3 +41,39 @@ pub extern "C" fn boxer_point_u64_get_y(_point_ptr: *mut ValueBox<BoxerPointU64>
 pub extern "C" fn boxer_point_u64_set_y(_point_ptr: *mut ValueBox<BoxerPointU64>, y: u64) {
     BoxerPointU64::boxer_point_set_y(_point_ptr, y);
 }
+
+#[no_mangle]
+pub extern "C" fn boxer_point_u64_get_x_checked(
+    _point_ptr: *mut ValueBox<BoxerPointU64>,
+    x: *mut u64,
+) -> BoxerStatus {
+    BoxerPointU64::boxer_point_get_x_checked(_point_ptr, x)
+}
+
+#[no_mangle]
+pub extern "C" fn boxer_point_u64_set_x_checked(
+    _point_ptr: *mut ValueBox<BoxerPointU64>,
+    x: u64,
+) -> BoxerStatus {
+    BoxerPointU64::boxer_point_set_x_checked(_point_ptr, x)
+}
+
+#[no_mangle]
+pub extern "C" fn boxer_point_u64_get_y_checked(
+    _point_ptr: *mut ValueBox<BoxerPointU64>,
+    y: *mut u64,
+) -> BoxerStatus {
+    BoxerPointU64::boxer_point_get_y_checked(_point_ptr, y)
+}
+
+#[no_mangle]
+pub extern "C" fn boxer_point_u64_set_y_checked(
+    _point_ptr: *mut ValueBox<BoxerPointU64>,
+    y: u64,
+) -> BoxerStatus {
+    BoxerPointU64::boxer_point_set_y_checked(_point_ptr, y)
+}
+
+pub(crate) fn register_manifest(manifest: &mut FfiManifest) {
+    BoxerPointU64::boxer_point_register_manifest(manifest, "boxer_point_u64");
+}
@@ -2,6 +2,8 @@ use std::ops::Range;
 
 use value_box::{ReturnBoxerResult, ValueBox, ValueBoxPointer};
 
+use crate::manifest::{ffi_function, FfiManifest};
+
 #[no_mangle]
 pub extern "C" fn boxer_range_usize_create() -> *mut ValueBox<Range<usize>> {
     ValueBox::new(0..0).into_raw()
@@ -31,3 +33,17 @@ pub extern "C" fn boxer_range_usize_get_end(range: *mut ValueBox<Range<usize>>)
 pub extern "C" fn boxer_range_usize_set_end(range: *mut ValueBox<Range<usize>>, end: usize) {
     range.with_mut_ok(|range| range.end = end).log();
 }
+
+pub(crate) fn register_manifest(manifest: &mut FfiManifest) {
+    type BoxerRange = *mut ValueBox<Range<usize>>;
+
+    manifest.add(ffi_function!(Creates fn boxer_range_usize_create() -> BoxerRange));
+    manifest.add(ffi_function!(Consumes fn boxer_range_usize_drop(range: BoxerRange)));
+    manifest.add(ffi_function!(Borrows fn boxer_range_usize_get_start(range: BoxerRange) -> usize));
+    manifest.add(
+        ffi_function!(Borrows fn boxer_range_usize_set_start(range: BoxerRange, start: usize)),
+    );
+    manifest.add(ffi_function!(Borrows fn boxer_range_usize_get_end(range: BoxerRange) -> usize));
+    manifest
+        .add(ffi_function!(Borrows fn boxer_range_usize_set_end(range: BoxerRange, end: usize)));
+}
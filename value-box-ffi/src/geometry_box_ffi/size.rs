@@ -1,6 +1,8 @@
 use geometry_box::SizeBox;
 use std::any::Any;
-use value_box::{ReturnBoxerResult, ValueBox, ValueBoxPointer};
+use value_box::{BoxerStatus, ReturnBoxerResult, ReturnBoxerStatus, ValueBox, ValueBoxPointer};
+
+use crate::manifest::{ffi_function, FfiManifest, FfiType};
 
 pub trait SizeBoxFFI<T>
 where
@@ -17,6 +19,23 @@ where
     fn boxer_size_get_height(_ptr: *mut ValueBox<SizeBox<T>>) -> T;
 
     fn boxer_size_set_height(_ptr: *mut ValueBox<SizeBox<T>>, height: T);
+
+    fn boxer_size_get_width_checked(_ptr: *mut ValueBox<SizeBox<T>>, width: *mut T) -> BoxerStatus;
+
+    fn boxer_size_set_width_checked(_ptr: *mut ValueBox<SizeBox<T>>, width: T) -> BoxerStatus;
+
+    fn boxer_size_get_height_checked(
+        _ptr: *mut ValueBox<SizeBox<T>>,
+        height: *mut T,
+    ) -> BoxerStatus;
+
+    fn boxer_size_set_height_checked(_ptr: *mut ValueBox<SizeBox<T>>, height: T) -> BoxerStatus;
+
+    /// Describe the functions exported for the boxed value with a given name prefix,
+    /// for example `boxer_size_f32`.
+    fn boxer_size_register_manifest(manifest: &mut FfiManifest, prefix: &str)
+    where
+        T: FfiType;
 }
 
 impl<T> SizeBoxFFI<T> for SizeBox<T>
@@ -46,4 +65,41 @@ where
     fn boxer_size_set_height(size: *mut ValueBox<SizeBox<T>>, height: T) {
         size.with_mut_ok(|size| size.height = height).log();
     }
+
+    fn boxer_size_get_width_checked(size: *mut ValueBox<SizeBox<T>>, width: *mut T) -> BoxerStatus {
+        size.with_ref_ok(|size| size.width).write_to(width)
+    }
+
+    fn boxer_size_set_width_checked(size: *mut ValueBox<SizeBox<T>>, width: T) -> BoxerStatus {
+        size.with_mut_ok(|size| size.width = width).status()
+    }
+
+    fn boxer_size_get_height_checked(
+        size: *mut ValueBox<SizeBox<T>>,
+        height: *mut T,
+    ) -> BoxerStatus {
+        size.with_ref_ok(|size| size.height).write_to(height)
+    }
+
+    fn boxer_size_set_height_checked(size: *mut ValueBox<SizeBox<T>>, height: T) -> BoxerStatus {
+        size.with_mut_ok(|size| size.height = height).status()
+    }
+
+    fn boxer_size_register_manifest(manifest: &mut FfiManifest, prefix: &str)
+    where
+        T: FfiType,
+    {
+        manifest.add(
+            ffi_function!(Creates fn [format!("{}_create", prefix)]() -> *mut ValueBox<SizeBox<T>>),
+        );
+        manifest.add(ffi_function!(Consumes fn [format!("{}_drop", prefix)](size: *mut ValueBox<SizeBox<T>>)));
+        manifest.add(ffi_function!(Borrows fn [format!("{}_get_width", prefix)](size: *mut ValueBox<SizeBox<T>>) -> T));
+        manifest.add(ffi_function!(Borrows fn [format!("{}_set_width", prefix)](size: *mut ValueBox<SizeBox<T>>, width: T)));
+        manifest.add(ffi_function!(Borrows fn [format!("{}_get_height", prefix)](size: *mut ValueBox<SizeBox<T>>) -> T));
+        manifest.add(ffi_function!(Borrows fn [format!("{}_set_height", prefix)](size: *mut ValueBox<SizeBox<T>>, height: T)));
+        manifest.add(ffi_function!(Borrows fn [format!("{}_get_width_checked", prefix)](size: *mut ValueBox<SizeBox<T>>, width: *mut T) -> BoxerStatus));
+        manifest.add(ffi_function!(Borrows fn [format!("{}_set_width_checked", prefix)](size: *mut ValueBox<SizeBox<T>>, width: T) -> BoxerStatus));
+        manifest.add(ffi_function!(Borrows fn [format!("{}_get_height_checked", prefix)](size: *mut ValueBox<SizeBox<T>>, height: *mut T) -> BoxerStatus));
+        manifest.add(ffi_function!(Borrows fn [format!("{}_set_height_checked", prefix)](size: *mut ValueBox<SizeBox<T>>, height: T) -> BoxerStatus));
+    }
 }
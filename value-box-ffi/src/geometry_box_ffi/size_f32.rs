@@ -1,5 +1,7 @@
 use geometry_box::SizeBox;
-use value_box::ValueBox;
+use value_box::{BoxerStatus, ValueBox};
+
+use crate::manifest::FfiManifest;
 
 use crate::size::SizeBoxFFI;
 
@@ -34,3 +36,39 @@ pub extern "C" fn boxer_size_f32_get_height(ptr: *mut ValueBox<BoxerSizeF32>) ->
 pub extern "C" fn boxer_size_f32_set_height(ptr: *mut ValueBox<BoxerSizeF32>, height: f32) {
     BoxerSizeF32::boxer_size_set_height(ptr, height);
 }
+
+#[no_mangle]
+pub extern "C" fn boxer_size_f32_get_width_checked(
+    ptr: *mut ValueBox<BoxerSizeF32>,
+    width: *mut f32,
+) -> BoxerStatus {
+    BoxerSizeF32::boxer_size_get_width_checked(ptr, width)
+}
+
+#[no_mangle]
+pub extern "C" fn boxer_size_f32_set_width_checked(
+    ptr: *mut ValueBox<BoxerSizeF32>,
+    width: f32,
+) -> BoxerStatus {
+    BoxerSizeF32::boxer_size_set_width_checked(ptr, width)
+}
+
+#[no_mangle]
+pub extern "C" fn boxer_size_f32_get_height_checked(
+    ptr: *mut ValueBox<BoxerSizeF32>,
+    height: *mut f32,
+) -> BoxerStatus {
+    BoxerSizeF32::boxer_size_get_height_checked(ptr, height)
+}
+
+#[no_mangle]
+pub extern "C" fn boxer_size_f32_set_height_checked(
+    ptr: *mut ValueBox<BoxerSizeF32>,
+    height: f32,
+) -> BoxerStatus {
+    BoxerSizeF32::boxer_size_set_height_checked(ptr, height)
+}
+
+pub(crate) fn register_manifest(manifest: &mut FfiManifest) {
+    BoxerSizeF32::boxer_size_register_manifest(manifest, "boxer_size_f32");
+}
@@ -1,5 +1,7 @@
 use geometry_box::SizeBox;
-use value_box::ValueBox;
+use value_box::{BoxerStatus, ValueBox};
+
+use crate::manifest::FfiManifest;
 
 use crate::size::SizeBoxFFI;
 
@@ -34,3 +36,39 @@ pub extern "C" fn boxer_size_i32_get_height(ptr: *mut ValueBox<BoxerSizeI32>) ->
 pub extern "C" fn boxer_size_i32_set_height(ptr: *mut ValueBox<BoxerSizeI32>, height: i32) {
     BoxerSizeI32::boxer_size_set_height(ptr, height);
 }
+
+#[no_mangle]
+pub extern "C" fn boxer_size_i32_get_width_checked(
+    ptr: *mut ValueBox<BoxerSizeI32>,
+    width: *mut i32,
+) -> BoxerStatus {
+    BoxerSizeI32::boxer_size_get_width_checked(ptr, width)
+}
+
+#[no_mangle]
+pub extern "C" fn boxer_size_i32_set_width_checked(
+    ptr: *mut ValueBox<BoxerSizeI32>,
+    width: i32,
+) -> BoxerStatus {
+    BoxerSizeI32::boxer_size_set_width_checked(ptr, width)
+}
+
+#[no_mangle]
+pub extern "C" fn boxer_size_i32_get_height_checked(
+    ptr: *mut ValueBox<BoxerSizeI32>,
+    height: *mut i32,
+) -> BoxerStatus {
+    BoxerSizeI32::boxer_size_get_height_checked(ptr, height)
+}
+
+#[no_mangle]
+pub extern "C" fn boxer_size_i32_set_height_checked(
+    ptr: *mut ValueBox<BoxerSizeI32>,
+    height: i32,
+) -> BoxerStatus {
+    BoxerSizeI32::boxer_size_set_height_checked(ptr, height)
+}
+
+pub(crate) fn register_manifest(manifest: &mut FfiManifest) {
+    BoxerSizeI32::boxer_size_register_manifest(manifest, "boxer_size_i32");
+}
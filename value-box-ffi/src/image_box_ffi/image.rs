@@ -0,0 +1,382 @@
+use array_box::ArrayBox;
+use geometry_box::SizeBox;
+use value_box::{
+    BoxerStatus, Result, ReturnBoxerResult, ReturnBoxerStatus, ValueBox, ValueBoxIntoRaw,
+    ValueBoxPointer,
+};
+
+use crate::array::check_valid;
+use crate::image_box::ImageBox;
+use crate::manifest::{ffi_function, FfiManifest};
+use crate::pixels::PixelFormat;
+
+/// Create an image of transparent black pixels of a given `PixelFormat` with tightly packed rows
+#[no_mangle]
+pub extern "C" fn boxer_image_create(
+    width: u32,
+    height: u32,
+    format: u8,
+) -> *mut ValueBox<ImageBox> {
+    PixelFormat::try_from(format)
+        .and_then(|format| ImageBox::new(SizeBox::new(width, height), format))
+        .map(ValueBox::new)
+        .into_raw()
+}
+
+/// Create an image with a copy of the pixels of a given array whose rows are padded to a stride
+#[no_mangle]
+pub extern "C" fn boxer_image_create_from_array(
+    array: *mut ValueBox<ArrayBox<u8>>,
+    width: u32,
+    height: u32,
+    stride: usize,
+    format: u8,
+) -> *mut ValueBox<ImageBox> {
+    array
+        .with_ref(|array| {
+            check_valid(array)?;
+            ImageBox::from_pixels(
+                SizeBox::new(width, height),
+                stride,
+                format.try_into()?,
+                ArrayBox::from_array(array.to_slice()),
+            )
+            .map(ValueBox::new)
+        })
+        .into_raw()
+}
+
+#[no_mangle]
+pub extern "C" fn boxer_image_drop(image: *mut ValueBox<ImageBox>) {
+    image.release();
+}
+
+#[no_mangle]
+pub extern "C" fn boxer_image_get_width(image: *mut ValueBox<ImageBox>) -> u32 {
+    image.with_ref_ok(|image| image.width()).or_log(0)
+}
+
+#[no_mangle]
+pub extern "C" fn boxer_image_get_height(image: *mut ValueBox<ImageBox>) -> u32 {
+    image.with_ref_ok(|image| image.height()).or_log(0)
+}
+
+/// Return a new size box with the width and height of an image
+#[no_mangle]
+pub extern "C" fn boxer_image_get_size(
+    image: *mut ValueBox<ImageBox>,
+) -> *mut ValueBox<SizeBox<u32>> {
+    image
+        .with_ref_ok(|image| ValueBox::new(image.size))
+        .into_raw()
+}
+
+/// Return the amount of bytes between the starts of two rows
+#[no_mangle]
+pub extern "C" fn boxer_image_get_stride(image: *mut ValueBox<ImageBox>) -> usize {
+    image.with_ref_ok(|image| image.stride).or_log(0)
+}
+
+/// Return the `PixelFormat` of an image or 255, which is not a format, if the image is not there
+#[no_mangle]
+pub extern "C" fn boxer_image_get_format(image: *mut ValueBox<ImageBox>) -> u8 {
+    image
+        .with_ref_ok(|image| image.format as u8)
+        .or_log(u8::MAX)
+}
+
+/// Return a pointer to the pixels of an image, it is valid until the image is dropped,
+/// rotated or converted to a format of another pixel size
+#[no_mangle]
+pub extern "C" fn boxer_image_get_data(image: *mut ValueBox<ImageBox>) -> *mut u8 {
+    image
+        .with_ref(|image| image.check().map(|_| image.pixels.data))
+        .or_log(std::ptr::null_mut())
+}
+
+/// Return the amount of bytes of the pixels of an image
+#[no_mangle]
+pub extern "C" fn boxer_image_get_length(image: *mut ValueBox<ImageBox>) -> usize {
+    image.with_ref_ok(|image| image.pixels.length).or_log(0)
+}
+
+/// Return a pixel as an `0xAARRGGBB` color, pixels without alpha are opaque
+#[no_mangle]
+pub extern "C" fn boxer_image_get_pixel(image: *mut ValueBox<ImageBox>, x: u32, y: u32) -> u32 {
+    image.with_ref(|image| image.pixel(x, y)).or_log(0)
+}
+
+/// Set a pixel to an `0xAARRGGBB` color, formats without alpha ignore the alpha
+#[no_mangle]
+pub extern "C" fn boxer_image_set_pixel(image: *mut ValueBox<ImageBox>, x: u32, y: u32, argb: u32) {
+    image.with_mut(|image| image.set_pixel(x, y, argb)).log();
+}
+
+/// Create a new image with tightly packed rows from a region of a given image
+#[no_mangle]
+pub extern "C" fn boxer_image_crop(
+    image: *mut ValueBox<ImageBox>,
+    x: u32,
+    y: u32,
+    width: u32,
+    height: u32,
+) -> *mut ValueBox<ImageBox> {
+    image
+        .with_ref(|image| image.crop(x, y, width, height).map(ValueBox::new))
+        .into_raw()
+}
+
+/// Copy a region of a source image into a destination image converting the pixels
+/// to the format of the destination, the source may be the destination.
+#[no_mangle]
+pub extern "C" fn boxer_image_copy_region(
+    source: *mut ValueBox<ImageBox>,
+    x: u32,
+    y: u32,
+    width: u32,
+    height: u32,
+    destination: *mut ValueBox<ImageBox>,
+    to_x: u32,
+    to_y: u32,
+) {
+    copy_region(source, x, y, width, height, destination, to_x, to_y).log();
+}
+
+/// Mirror an image from left to right
+#[no_mangle]
+pub extern "C" fn boxer_image_flip_horizontally(image: *mut ValueBox<ImageBox>) {
+    image.with_mut(|image| image.flip_horizontally()).log();
+}
+
+/// Mirror an image from top to bottom
+#[no_mangle]
+pub extern "C" fn boxer_image_flip_vertically(image: *mut ValueBox<ImageBox>) {
+    image.with_mut(|image| image.flip_vertically()).log();
+}
+
+/// Rotate an image by 90 degrees clockwise, its rows become tightly packed
+#[no_mangle]
+pub extern "C" fn boxer_image_rotate_clockwise(image: *mut ValueBox<ImageBox>) {
+    image.with_mut(|image| image.rotate_clockwise()).log();
+}
+
+/// Rotate an image by 90 degrees counter-clockwise, its rows become tightly packed
+#[no_mangle]
+pub extern "C" fn boxer_image_rotate_counter_clockwise(image: *mut ValueBox<ImageBox>) {
+    image
+        .with_mut(|image| image.rotate_counter_clockwise())
+        .log();
+}
+
+/// Convert the pixels of an image to another `PixelFormat`, pixels without alpha get a given alpha
+#[no_mangle]
+pub extern "C" fn boxer_image_convert_format(
+    image: *mut ValueBox<ImageBox>,
+    format: u8,
+    alpha: u8,
+) {
+    image
+        .with_mut(|image| image.convert_format(format.try_into()?, alpha))
+        .log();
+}
+
+#[no_mangle]
+pub extern "C" fn boxer_image_get_pixel_checked(
+    image: *mut ValueBox<ImageBox>,
+    x: u32,
+    y: u32,
+    argb: *mut u32,
+) -> BoxerStatus {
+    image.with_ref(|image| image.pixel(x, y)).write_to(argb)
+}
+
+#[no_mangle]
+pub extern "C" fn boxer_image_set_pixel_checked(
+    image: *mut ValueBox<ImageBox>,
+    x: u32,
+    y: u32,
+    argb: u32,
+) -> BoxerStatus {
+    image.with_mut(|image| image.set_pixel(x, y, argb)).status()
+}
+
+#[no_mangle]
+pub extern "C" fn boxer_image_copy_region_checked(
+    source: *mut ValueBox<ImageBox>,
+    x: u32,
+    y: u32,
+    width: u32,
+    height: u32,
+    destination: *mut ValueBox<ImageBox>,
+    to_x: u32,
+    to_y: u32,
+) -> BoxerStatus {
+    copy_region(source, x, y, width, height, destination, to_x, to_y).status()
+}
+
+#[no_mangle]
+pub extern "C" fn boxer_image_convert_format_checked(
+    image: *mut ValueBox<ImageBox>,
+    format: u8,
+    alpha: u8,
+) -> BoxerStatus {
+    image
+        .with_mut(|image| image.convert_format(format.try_into()?, alpha))
+        .status()
+}
+
+#[allow(clippy::too_many_arguments)]
+fn copy_region(
+    source: *mut ValueBox<ImageBox>,
+    x: u32,
+    y: u32,
+    width: u32,
+    height: u32,
+    destination: *mut ValueBox<ImageBox>,
+    to_x: u32,
+    to_y: u32,
+) -> Result<()> {
+    if source == destination {
+        return destination
+            .with_mut(|image| image.copy_region_within(x, y, width, height, to_x, to_y));
+    }
+
+    destination.with_mut(|destination| {
+        source.with_ref(|source| {
+            destination.copy_region_from(source, x, y, width, height, to_x, to_y)
+        })
+    })
+}
+
+pub(crate) fn register_manifest(manifest: &mut FfiManifest) {
+    type Image = *mut ValueBox<ImageBox>;
+    type Status = BoxerStatus;
+    type Format = u8;
+
+    manifest.add(ffi_function!(Creates fn boxer_image_create(
+        width: u32,
+        height: u32,
+        format: Format,
+    ) -> Image));
+    manifest.add(ffi_function!(Creates fn boxer_image_create_from_array(
+        array: *mut ValueBox<ArrayBox<u8>>,
+        width: u32,
+        height: u32,
+        stride: usize,
+        format: Format,
+    ) -> Image));
+    manifest.add(ffi_function!(Consumes fn boxer_image_drop(image: Image)));
+    manifest.add(ffi_function!(Borrows fn boxer_image_get_width(image: Image) -> u32));
+    manifest.add(ffi_function!(Borrows fn boxer_image_get_height(image: Image) -> u32));
+    manifest.add(
+        ffi_function!(Creates fn boxer_image_get_size(image: Image) -> *mut ValueBox<SizeBox<u32>>),
+    );
+    manifest.add(ffi_function!(Borrows fn boxer_image_get_stride(image: Image) -> usize));
+    manifest.add(ffi_function!(Borrows fn boxer_image_get_format(image: Image) -> Format));
+    manifest.add(ffi_function!(Borrows fn boxer_image_get_data(image: Image) -> *mut u8));
+    manifest.add(ffi_function!(Borrows fn boxer_image_get_length(image: Image) -> usize));
+    manifest
+        .add(ffi_function!(Borrows fn boxer_image_get_pixel(image: Image, x: u32, y: u32) -> u32));
+    manifest.add(
+        ffi_function!(Borrows fn boxer_image_set_pixel(image: Image, x: u32, y: u32, argb: u32)),
+    );
+    manifest.add(ffi_function!(Creates fn boxer_image_crop(
+        image: Image,
+        x: u32,
+        y: u32,
+        width: u32,
+        height: u32,
+    ) -> Image));
+    manifest.add(ffi_function!(Borrows fn boxer_image_copy_region(
+        source: Image,
+        x: u32,
+        y: u32,
+        width: u32,
+        height: u32,
+        destination: Image,
+        to_x: u32,
+        to_y: u32,
+    )));
+    manifest.add(ffi_function!(Borrows fn boxer_image_flip_horizontally(image: Image)));
+    manifest.add(ffi_function!(Borrows fn boxer_image_flip_vertically(image: Image)));
+    manifest.add(ffi_function!(Borrows fn boxer_image_rotate_clockwise(image: Image)));
+    manifest.add(ffi_function!(Borrows fn boxer_image_rotate_counter_clockwise(image: Image)));
+    manifest.add(ffi_function!(Borrows fn boxer_image_convert_format(image: Image, format: Format, alpha: u8)));
+    manifest.add(ffi_function!(Borrows fn boxer_image_get_pixel_checked(
+        image: Image,
+        x: u32,
+        y: u32,
+        argb: *mut u32,
+    ) -> Status));
+    manifest.add(ffi_function!(Borrows fn boxer_image_set_pixel_checked(
+        image: Image,
+        x: u32,
+        y: u32,
+        argb: u32,
+    ) -> Status));
+    manifest.add(ffi_function!(Borrows fn boxer_image_copy_region_checked(
+        source: Image,
+        x: u32,
+        y: u32,
+        width: u32,
+        height: u32,
+        destination: Image,
+        to_x: u32,
+        to_y: u32,
+    ) -> Status));
+    manifest.add(ffi_function!(Borrows fn boxer_image_convert_format_checked(
+        image: Image,
+        format: Format,
+        alpha: u8,
+    ) -> Status));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::*;
+    use value_box::BoxerErrorKind;
+
+    #[test]
+    fn test_image() {
+        let pixels = boxer_array_u8_create_with(0, 6);
+        boxer_array_u8_at_put(pixels, 5, 200);
+        let image = boxer_image_create_from_array(pixels, 1, 2, 3, PixelFormat::Rgb24 as u8);
+        boxer_array_u8_drop(pixels);
+        assert_eq!(boxer_image_get_pixel(image, 0, 1), 0xFF0000C8);
+
+        boxer_image_rotate_clockwise(image);
+        assert_eq!(boxer_image_get_width(image), 2);
+        assert_eq!(boxer_image_get_pixel(image, 0, 0), 0xFF0000C8);
+
+        boxer_image_set_pixel(image, 1, 0, 0x80102030);
+        boxer_image_convert_format(image, PixelFormat::Argb32 as u8, 0x40);
+        assert_eq!(boxer_image_get_format(image), PixelFormat::Argb32 as u8);
+        assert_eq!(boxer_image_get_stride(image), 8);
+        assert_eq!(boxer_image_get_pixel(image, 1, 0), 0x40102030);
+
+        boxer_image_copy_region(image, 1, 0, 1, 1, image, 0, 0);
+        let crop = boxer_image_crop(image, 0, 0, 1, 1);
+        let mut argb = 0;
+        assert_eq!(
+            boxer_image_get_pixel_checked(crop, 0, 0, &mut argb),
+            BoxerStatus::Ok
+        );
+        assert_eq!(argb, 0x40102030);
+        assert_eq!(
+            boxer_image_get_pixel_checked(crop, 1, 0, &mut argb),
+            BoxerErrorKind::OutOfBounds
+        );
+        assert_eq!(
+            boxer_image_convert_format_checked(crop, 7, 0),
+            BoxerErrorKind::InvalidArgument
+        );
+        assert!(boxer_image_create(1, 1, 7).is_null());
+        assert!(boxer_image_create(u32::MAX, u32::MAX, PixelFormat::Argb32 as u8).is_null());
+        assert_eq!(boxer_last_error_status(), BoxerErrorKind::InvalidArgument);
+        assert_eq!(boxer_image_get_format(std::ptr::null_mut()), u8::MAX);
+
+        boxer_image_drop(crop);
+        boxer_image_drop(image);
+    }
+}
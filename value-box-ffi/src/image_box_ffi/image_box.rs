@@ -0,0 +1,415 @@
+use array_box::ArrayBox;
+use geometry_box::SizeBox;
+use value_box::{BoxerError, Result};
+
+use crate::array::check_valid;
+use crate::pixels::{convert_pixel_rows, convert_pixels_in_place, PixelFormat, PixelRows};
+
+/// A bitmap that stores its pixels row by row in a byte array,
+/// rows may be padded to a stride in bytes.
+/// Pixels are read and written as `0xAARRGGBB` colors independent of the format.
+#[derive(Debug)]
+pub struct ImageBox {
+    pub size: SizeBox<u32>,
+    pub stride: usize,
+    pub format: PixelFormat,
+    pub pixels: ArrayBox<u8>,
+}
+
+impl ImageBox {
+    /// Create an image of transparent black (or black without alpha) pixels with tightly packed rows,
+    /// fails if the pixels of the size do not fit into memory
+    pub fn new(size: SizeBox<u32>, format: PixelFormat) -> Result<Self> {
+        let stride = (size.width as usize).checked_mul(format.bytes_per_pixel());
+        let length = stride
+            .and_then(|stride| stride.checked_mul(size.height as usize))
+            .filter(|length| *length <= isize::MAX as usize);
+        match (stride, length) {
+            (Some(stride), Some(length)) => Ok(Self {
+                size,
+                stride,
+                format,
+                pixels: ArrayBox::from_vector(vec![0; length]),
+            }),
+            _ => BoxerError::InvalidArgument(format!(
+                "The {:?} pixels of a {}x{} image do not fit into memory",
+                format, size.width, size.height
+            ))
+            .into(),
+        }
+    }
+
+    /// Create an image of the given pixels, fails if the rows do not fit into the pixels
+    pub fn from_pixels(
+        size: SizeBox<u32>,
+        stride: usize,
+        format: PixelFormat,
+        pixels: ArrayBox<u8>,
+    ) -> Result<Self> {
+        let image = Self {
+            size,
+            stride,
+            format,
+            pixels,
+        };
+        image.check()?;
+        Ok(image)
+    }
+
+    pub fn width(&self) -> u32 {
+        self.size.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.size.height
+    }
+
+    pub fn rows(&self) -> PixelRows {
+        PixelRows::new(
+            self.size.width as usize,
+            self.size.height as usize,
+            self.stride,
+            self.format,
+        )
+    }
+
+    /// Fail if my pixels were invalidated or are too short for my rows
+    pub fn check(&self) -> Result<()> {
+        check_valid(&self.pixels)?;
+        self.rows().check(self.pixels.length)
+    }
+
+    /// Return the color of a pixel
+    pub fn pixel(&self, x: u32, y: u32) -> Result<u32> {
+        let offset = self.pixel_offset(x, y)?;
+        let pixel = &self.pixels.to_slice()[offset..offset + self.format.bytes_per_pixel()];
+        Ok(self.format.read_argb(pixel))
+    }
+
+    /// Set the color of a pixel, formats without alpha ignore the alpha of the color
+    pub fn set_pixel(&mut self, x: u32, y: u32, argb: u32) -> Result<()> {
+        let offset = self.pixel_offset(x, y)?;
+        let pixel = &mut self.pixels.to_slice()[offset..offset + self.format.bytes_per_pixel()];
+        self.format.write_argb(argb, pixel);
+        Ok(())
+    }
+
+    /// Create a new image with tightly packed rows from a region of me
+    pub fn crop(&self, x: u32, y: u32, width: u32, height: u32) -> Result<ImageBox> {
+        self.check_region(x, y, width, height)?;
+        let mut image = ImageBox::new(SizeBox::new(width, height), self.format)?;
+        image.copy_region_from(self, x, y, width, height, 0, 0)?;
+        Ok(image)
+    }
+
+    /// Copy a region of another image to a given position within me,
+    /// the pixels are converted to my format and opaque if the other image has no alpha.
+    #[allow(clippy::too_many_arguments)]
+    pub fn copy_region_from(
+        &mut self,
+        source: &ImageBox,
+        x: u32,
+        y: u32,
+        width: u32,
+        height: u32,
+        to_x: u32,
+        to_y: u32,
+    ) -> Result<()> {
+        self.check()?;
+        source.check()?;
+        source.check_region(x, y, width, height)?;
+        self.check_region(to_x, to_y, width, height)?;
+        if width == 0 || height == 0 {
+            return Ok(());
+        }
+
+        let (width, height) = (width as usize, height as usize);
+        convert_pixel_rows(
+            &source.pixels.to_slice()[source.offset(x, y)..],
+            PixelRows::new(width, height, source.stride, source.format),
+            &mut self.pixels.to_slice()[self.offset(to_x, to_y)..],
+            PixelRows::new(width, height, self.stride, self.format),
+            u8::MAX,
+        )
+    }
+
+    /// Copy a region of me to a given position, the regions may overlap
+    pub fn copy_region_within(
+        &mut self,
+        x: u32,
+        y: u32,
+        width: u32,
+        height: u32,
+        to_x: u32,
+        to_y: u32,
+    ) -> Result<()> {
+        let region = self.crop(x, y, width, height)?;
+        self.copy_region_from(&region, 0, 0, width, height, to_x, to_y)
+    }
+
+    /// Mirror me from left to right
+    pub fn flip_horizontally(&mut self) -> Result<()> {
+        self.check()?;
+        let bytes_per_pixel = self.format.bytes_per_pixel();
+        for row in self.rows().rows_mut(self.pixels.to_slice()) {
+            // reversing the bytes of a row reverses the pixels and their channels
+            row.reverse();
+            for pixel in row.chunks_exact_mut(bytes_per_pixel) {
+                pixel.reverse();
+            }
+        }
+        Ok(())
+    }
+
+    /// Mirror me from top to bottom
+    pub fn flip_vertically(&mut self) -> Result<()> {
+        self.check()?;
+        let rows = self.rows();
+        let pixels = self.pixels.to_slice();
+        for top in 0..rows.height / 2 {
+            let bottom = rows.height - 1 - top;
+            let (upper, lower) = pixels.split_at_mut(bottom * rows.stride);
+            upper[top * rows.stride..][..rows.row_length()]
+                .swap_with_slice(&mut lower[..rows.row_length()]);
+        }
+        Ok(())
+    }
+
+    /// Rotate me by 90 degrees clockwise, my rows become tightly packed
+    pub fn rotate_clockwise(&mut self) -> Result<()> {
+        self.rotate(true)
+    }
+
+    /// Rotate me by 90 degrees counter-clockwise, my rows become tightly packed
+    pub fn rotate_counter_clockwise(&mut self) -> Result<()> {
+        self.rotate(false)
+    }
+
+    fn rotate(&mut self, clockwise: bool) -> Result<()> {
+        self.check()?;
+        let (width, height) = (self.size.width as usize, self.size.height as usize);
+        let bytes_per_pixel = self.format.bytes_per_pixel();
+        let stride = height * bytes_per_pixel;
+        let mut rotated = vec![0; stride * width];
+
+        let pixels = self.pixels.to_slice();
+        for y in 0..height {
+            for x in 0..width {
+                let (to_x, to_y) = if clockwise {
+                    (height - 1 - y, x)
+                } else {
+                    (y, width - 1 - x)
+                };
+                let from = y * self.stride + x * bytes_per_pixel;
+                let to = to_y * stride + to_x * bytes_per_pixel;
+                rotated[to..to + bytes_per_pixel]
+                    .copy_from_slice(&pixels[from..from + bytes_per_pixel]);
+            }
+        }
+
+        self.size = SizeBox::new(self.size.height, self.size.width);
+        self.stride = stride;
+        self.pixels.set_vector(rotated);
+        Ok(())
+    }
+
+    /// Convert my pixels to another format, in place if both formats have the same pixel size.
+    /// Pixels converted from a format without alpha get a given alpha.
+    pub fn convert_format(&mut self, format: PixelFormat, alpha: u8) -> Result<()> {
+        self.check()?;
+        if format == self.format {
+            return Ok(());
+        }
+
+        let rows = self.rows();
+        if format.bytes_per_pixel() == self.format.bytes_per_pixel() {
+            if rows.stride == rows.row_length() {
+                convert_pixels_in_place(
+                    &mut self.pixels.to_slice()[..rows.length()],
+                    self.format,
+                    format,
+                )?;
+            } else {
+                for row in rows.rows_mut(self.pixels.to_slice()) {
+                    convert_pixels_in_place(row, self.format, format)?;
+                }
+            }
+            self.format = format;
+            return Ok(());
+        }
+
+        let converted = ImageBox::new(self.size, format)?;
+        convert_pixel_rows(
+            self.pixels.to_slice(),
+            rows,
+            converted.pixels.to_slice(),
+            converted.rows(),
+            alpha,
+        )?;
+        *self = converted;
+        Ok(())
+    }
+
+    fn pixel_offset(&self, x: u32, y: u32) -> Result<usize> {
+        self.check()?;
+        if x >= self.size.width {
+            return BoxerError::OutOfBounds {
+                index: x as usize,
+                length: self.size.width as usize,
+            }
+            .into();
+        }
+        if y >= self.size.height {
+            return BoxerError::OutOfBounds {
+                index: y as usize,
+                length: self.size.height as usize,
+            }
+            .into();
+        }
+        Ok(self.offset(x, y))
+    }
+
+    fn offset(&self, x: u32, y: u32) -> usize {
+        y as usize * self.stride + x as usize * self.format.bytes_per_pixel()
+    }
+
+    /// Fail with the last column or row of a region that is outside of me
+    fn check_region(&self, x: u32, y: u32, width: u32, height: u32) -> Result<()> {
+        check_span(x, width, self.size.width)?;
+        check_span(y, height, self.size.height)
+    }
+}
+
+fn check_span(start: u32, length: u32, limit: u32) -> Result<()> {
+    let end = start as usize + length as usize;
+    if end <= limit as usize {
+        Ok(())
+    } else {
+        BoxerError::OutOfBounds {
+            index: end - 1,
+            length: limit as usize,
+        }
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn numbered(width: u32, height: u32, format: PixelFormat) -> Result<ImageBox> {
+        let mut image = ImageBox::new(SizeBox::new(width, height), format)?;
+        for y in 0..height {
+            for x in 0..width {
+                image.set_pixel(x, y, 0xFF000000 | (y * width + x))?;
+            }
+        }
+        Ok(image)
+    }
+
+    fn numbers(image: &ImageBox) -> Result<Vec<u32>> {
+        let mut numbers = vec![];
+        for y in 0..image.height() {
+            for x in 0..image.width() {
+                numbers.push(image.pixel(x, y)? & 0xFFFFFF);
+            }
+        }
+        Ok(numbers)
+    }
+
+    #[test]
+    fn test_pixels() -> Result<()> {
+        let mut image = ImageBox::new(SizeBox::new(2, 1), PixelFormat::Rgba32)?;
+        image.set_pixel(1, 0, 0x80102030)?;
+        assert_eq!(
+            image.pixels.to_slice(),
+            &[0, 0, 0, 0, 0x10, 0x20, 0x30, 0x80]
+        );
+        assert_eq!(image.pixel(1, 0)?, 0x80102030);
+        assert!(matches!(
+            image.pixel(0, 1),
+            Err(BoxerError::OutOfBounds {
+                index: 1,
+                length: 1
+            })
+        ));
+
+        let padded = ImageBox::from_pixels(
+            SizeBox::new(1, 2),
+            2,
+            PixelFormat::Gray8,
+            ArrayBox::from_vector(vec![10, 0, 20]),
+        )?;
+        assert_eq!(padded.pixel(0, 1)?, 0xFF141414);
+        assert!(ImageBox::from_pixels(
+            SizeBox::new(2, 2),
+            2,
+            PixelFormat::Gray8,
+            ArrayBox::from_vector(vec![0; 3]),
+        )
+        .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_regions() -> Result<()> {
+        let mut image = numbered(3, 2, PixelFormat::Rgb24)?;
+        let crop = image.crop(1, 0, 2, 2)?;
+        assert_eq!(numbers(&crop)?, vec![1, 2, 4, 5]);
+        assert!(matches!(
+            image.crop(2, 0, 2, 1),
+            Err(BoxerError::OutOfBounds {
+                index: 3,
+                length: 3
+            })
+        ));
+
+        image.copy_region_within(0, 0, 2, 1, 1, 0)?;
+        assert_eq!(numbers(&image)?, vec![0, 0, 1, 3, 4, 5]);
+
+        let mut white = ImageBox::new(SizeBox::new(1, 1), PixelFormat::Rgb24)?;
+        white.set_pixel(0, 0, 0xFFFFFFFF)?;
+        let mut gray = ImageBox::new(SizeBox::new(2, 2), PixelFormat::Gray8)?;
+        gray.copy_region_from(&white, 0, 0, 1, 1, 1, 1)?;
+        assert_eq!(gray.pixels.to_slice(), &[0, 0, 0, 255]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_flip_and_rotate() -> Result<()> {
+        let mut image = numbered(3, 2, PixelFormat::Argb32)?;
+        image.flip_horizontally()?;
+        assert_eq!(numbers(&image)?, vec![2, 1, 0, 5, 4, 3]);
+        image.flip_vertically()?;
+        assert_eq!(numbers(&image)?, vec![5, 4, 3, 2, 1, 0]);
+
+        let mut image = numbered(3, 2, PixelFormat::Bgr24)?;
+        image.rotate_clockwise()?;
+        assert_eq!((image.width(), image.height(), image.stride), (2, 3, 6));
+        assert_eq!(numbers(&image)?, vec![3, 0, 4, 1, 5, 2]);
+        image.rotate_counter_clockwise()?;
+        assert_eq!(numbers(&image)?, vec![0, 1, 2, 3, 4, 5]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_convert_format() -> Result<()> {
+        let mut image = ImageBox::from_pixels(
+            SizeBox::new(1, 2),
+            5,
+            PixelFormat::Argb32,
+            ArrayBox::from_vector(vec![0x80, 1, 2, 3, 0, 0x40, 4, 5, 6]),
+        )?;
+        image.convert_format(PixelFormat::Bgra32, 0)?;
+        assert_eq!(image.stride, 5);
+        assert_eq!(image.pixels.to_slice(), &[3, 2, 1, 0x80, 0, 6, 5, 4, 0x40]);
+
+        image.convert_format(PixelFormat::Rgb24, 0)?;
+        assert_eq!(image.stride, 3);
+        assert_eq!(image.pixels.to_slice(), &[1, 2, 3, 4, 5, 6]);
+
+        image.convert_format(PixelFormat::Rgba32, 0x20)?;
+        assert_eq!(image.pixel(0, 1)?, 0x20040506);
+        Ok(())
+    }
+}
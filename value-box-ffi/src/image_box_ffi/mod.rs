@@ -0,0 +1,10 @@
+pub mod image;
+pub mod image_box;
+
+use crate::manifest::FfiManifest;
+
+pub(crate) fn register_manifest(manifest: &mut FfiManifest) {
+    manifest.set_origin("value-box-ffi", "image-box");
+
+    image::register_manifest(manifest);
+}
@@ -13,19 +13,25 @@ extern crate string_box;
 #[cfg(feature = "phlow")]
 use phlow_extensions::CoreExtensions;
 
+pub use crate::manifest::*;
 #[cfg(feature = "value-box")]
 pub use crate::value_box_ffi::*;
 #[cfg(feature = "array-box")]
 pub use array_box_ffi::*;
 #[cfg(feature = "geometry-box")]
 pub use geometry_box_ffi::*;
+#[cfg(feature = "image-box")]
+pub use image_box_ffi::*;
 #[cfg(feature = "string-box")]
 pub use string_box_ffi::*;
 
 #[cfg(feature = "array-box")]
 mod array_box_ffi;
+mod manifest;
 #[cfg(feature = "geometry-box")]
 mod geometry_box_ffi;
+#[cfg(feature = "image-box")]
+mod image_box_ffi;
 #[cfg(feature = "string-box")]
 mod string_box_ffi;
 #[cfg(feature = "value-box")]
@@ -39,28 +45,25 @@ pub extern "C" fn boxer_test() -> bool {
 #[cfg(feature = "phlow")]
 import_extensions!(CoreExtensions);
 
+/// Return a new box with the phlow object describing the value of a given box,
+/// or null if there is no value or the library was built without the `phlow` feature.
 #[no_mangle]
-#[cfg(feature = "phlow")]
-pub fn boxer_value_box_to_phlow_object(
-    value_box: *mut value_box::ValueBox<&'static (dyn std::any::Any + 'static)>,
+#[cfg_attr(not(feature = "phlow"), allow(unused_variables))]
+pub extern "C" fn boxer_value_box_to_phlow_object(
+    value_box: *mut value_box::ValueBox<std::ffi::c_void>,
 ) -> *mut std::ffi::c_void {
-    if value_box.is_null() {
-        return std::ptr::null_mut()
+    #[cfg(feature = "phlow")]
+    if !value_box.is_null() {
+        let value_box =
+            value_box as *mut value_box::ValueBox<&'static (dyn std::any::Any + 'static)>;
+        let mut value_box = std::mem::ManuallyDrop::new(unsafe { Box::from_raw(value_box) });
+        return value_box
+            .phlow_object()
+            .map(|phlow_object| {
+                value_box::ValueBox::new(phlow_object).into_raw() as *mut std::ffi::c_void
+            })
+            .unwrap_or(std::ptr::null_mut());
     }
 
-    let mut value_box = std::mem::ManuallyDrop::new(unsafe { Box::from_raw(value_box) });
-    value_box
-        .phlow_object()
-        .map(|phlow_object| {
-            value_box::ValueBox::new(phlow_object).into_raw() as *mut std::ffi::c_void
-        })
-        .unwrap_or(std::ptr::null_mut())
-}
-
-#[no_mangle]
-#[cfg(not(feature = "phlow"))]
-pub fn boxer_value_box_to_phlow_object(
-    _value_box: *mut value_box::ValueBox<std::ffi::c_void>,
-) -> *mut std::ffi::c_void {
     std::ptr::null_mut()
 }
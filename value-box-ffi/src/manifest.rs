@@ -0,0 +1,415 @@
+use std::any::Any;
+use std::fmt::Write;
+use std::ops::Range;
+
+#[cfg(feature = "string-box")]
+use string_box::StringBox;
+use value_box::{
+    BoxerErrorKind, BoxerStatus, CallbackAffinity, CallbackBox, FutureBox, FutureBoxState, ValueBox,
+};
+
+use crate::{boxer_test, boxer_value_box_to_phlow_object};
+
+/// How a function treats the boxes passed to or returned from it.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum FfiOwnership {
+    /// The function does not work with boxes
+    None,
+    /// Returns a new box that must be released by the caller
+    Creates,
+    /// Uses the boxes without taking the ownership
+    Borrows,
+    /// Releases the box, it must not be used afterwards
+    Consumes,
+}
+
+impl FfiOwnership {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FfiOwnership::None => "none",
+            FfiOwnership::Creates => "creates",
+            FfiOwnership::Borrows => "borrows",
+            FfiOwnership::Consumes => "consumes",
+        }
+    }
+}
+
+/// A Rust type that can be passed over ffi, described by its name.
+pub trait FfiType {
+    fn ffi_type() -> String;
+}
+
+macro_rules! ffi_primitive_type {
+    ($($ty:ty),*) => {
+        $(impl FfiType for $ty {
+            fn ffi_type() -> String {
+                stringify!($ty).to_string()
+            }
+        })*
+    };
+}
+
+ffi_primitive_type!(u8, i8, u16, i16, u32, i32, u64, i64, usize, isize, f32, f64, bool);
+
+impl FfiType for () {
+    fn ffi_type() -> String {
+        "()".to_string()
+    }
+}
+
+impl FfiType for std::ffi::c_void {
+    fn ffi_type() -> String {
+        "c_void".to_string()
+    }
+}
+
+impl<T: FfiType> FfiType for *mut T {
+    fn ffi_type() -> String {
+        format!("*mut {}", T::ffi_type())
+    }
+}
+
+impl<T: FfiType> FfiType for *const T {
+    fn ffi_type() -> String {
+        format!("*const {}", T::ffi_type())
+    }
+}
+
+impl<T: FfiType + Any> FfiType for ValueBox<T> {
+    fn ffi_type() -> String {
+        format!("ValueBox<{}>", T::ffi_type())
+    }
+}
+
+impl<T: FfiType> FfiType for Range<T> {
+    fn ffi_type() -> String {
+        format!("Range<{}>", T::ffi_type())
+    }
+}
+
+impl FfiType for BoxerStatus {
+    fn ffi_type() -> String {
+        "BoxerStatus".to_string()
+    }
+}
+
+impl FfiType for BoxerErrorKind {
+    fn ffi_type() -> String {
+        "BoxerErrorKind".to_string()
+    }
+}
+
+impl FfiType for FutureBox {
+    fn ffi_type() -> String {
+        "FutureBox".to_string()
+    }
+}
+
+impl FfiType for FutureBoxState {
+    fn ffi_type() -> String {
+        "FutureBoxState".to_string()
+    }
+}
+
+impl FfiType for CallbackBox {
+    fn ffi_type() -> String {
+        "CallbackBox".to_string()
+    }
+}
+
+impl FfiType for CallbackAffinity {
+    fn ffi_type() -> String {
+        "CallbackAffinity".to_string()
+    }
+}
+
+impl<T: FfiType> FfiType for Option<T> {
+    fn ffi_type() -> String {
+        format!("Option<{}>", T::ffi_type())
+    }
+}
+
+macro_rules! ffi_function_pointer_type {
+    ($($argument:ident),*) => {
+        impl<$($argument: FfiType,)* R: FfiType> FfiType for extern "C" fn($($argument),*) -> R {
+            fn ffi_type() -> String {
+                let arguments: Vec<String> = vec![$($argument::ffi_type()),*];
+                format!("extern \"C\" fn({}) -> {}", arguments.join(", "), R::ffi_type())
+            }
+        }
+    };
+}
+
+ffi_function_pointer_type!();
+ffi_function_pointer_type!(A);
+ffi_function_pointer_type!(A, B);
+ffi_function_pointer_type!(A, B, C);
+ffi_function_pointer_type!(A, B, C, D);
+
+#[cfg(feature = "array-box")]
+impl<T: FfiType> FfiType for array_box::ArrayBox<T> {
+    fn ffi_type() -> String {
+        format!("ArrayBox<{}>", T::ffi_type())
+    }
+}
+
+#[cfg(feature = "array-box")]
+impl FfiType for crate::pixels::PixelFormat {
+    fn ffi_type() -> String {
+        "PixelFormat".to_string()
+    }
+}
+
+#[cfg(feature = "geometry-box")]
+impl<T: FfiType + From<u8> + Default + Copy> FfiType for geometry_box::PointBox<T> {
+    fn ffi_type() -> String {
+        format!("PointBox<{}>", T::ffi_type())
+    }
+}
+
+#[cfg(feature = "geometry-box")]
+impl<T: FfiType + From<u8> + Default + Copy> FfiType for geometry_box::Point3Box<T> {
+    fn ffi_type() -> String {
+        format!("Point3Box<{}>", T::ffi_type())
+    }
+}
+
+#[cfg(feature = "geometry-box")]
+impl<T: FfiType + From<u8> + Default + Copy> FfiType for geometry_box::SizeBox<T> {
+    fn ffi_type() -> String {
+        format!("SizeBox<{}>", T::ffi_type())
+    }
+}
+
+#[cfg(feature = "geometry-box")]
+impl FfiType for geometry_box::U128Box {
+    fn ffi_type() -> String {
+        "U128Box".to_string()
+    }
+}
+
+#[cfg(feature = "image-box")]
+impl FfiType for crate::image_box::ImageBox {
+    fn ffi_type() -> String {
+        "ImageBox".to_string()
+    }
+}
+
+#[cfg(feature = "string-box")]
+impl FfiType for StringBox {
+    fn ffi_type() -> String {
+        "StringBox".to_string()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FfiArgument {
+    pub name: String,
+    pub ty: String,
+}
+
+/// The description of an exported `boxer_*` function.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FfiFunction {
+    pub name: String,
+    pub arguments: Vec<FfiArgument>,
+    pub returns: String,
+    pub ownership: FfiOwnership,
+    pub crate_name: &'static str,
+    pub feature: &'static str,
+}
+
+impl FfiFunction {
+    pub fn new(name: impl Into<String>, ownership: FfiOwnership) -> Self {
+        Self {
+            name: name.into(),
+            arguments: vec![],
+            returns: <()>::ffi_type(),
+            ownership,
+            crate_name: "",
+            feature: "",
+        }
+    }
+
+    pub fn argument<T: FfiType>(mut self, name: &str) -> Self {
+        self.arguments.push(FfiArgument {
+            name: name.to_string(),
+            ty: T::ffi_type(),
+        });
+        self
+    }
+
+    pub fn returns<T: FfiType>(mut self) -> Self {
+        self.returns = T::ffi_type();
+        self
+    }
+}
+
+/// Describe a function for the [`FfiManifest`], for example
+/// `ffi_function!(Borrows fn boxer_range_usize_get_start(range: *mut ValueBox<Range<usize>>) -> usize)`.
+/// The signature of functions given by name is checked by the compiler,
+/// functions with a computed name are given as `fn [name](...)`.
+macro_rules! ffi_function {
+    ($ownership:ident fn [$name:expr] ($($argument:ident : $ty:ty),* $(,)?) $(-> $returns:ty)?) => {
+        $crate::manifest::FfiFunction::new($name, $crate::manifest::FfiOwnership::$ownership)
+            $(.argument::<$ty>(stringify!($argument)))*
+            $(.returns::<$returns>())?
+    };
+    ($ownership:ident fn $name:ident ($($argument:ident : $ty:ty),* $(,)?) $(-> $returns:ty)?) => {{
+        let _: extern "C" fn($($ty),*) $(-> $returns)? = $name;
+        $crate::manifest::ffi_function!($ownership fn [stringify!($name)] ($($argument: $ty),*) $(-> $returns)?)
+    }};
+}
+
+pub(crate) use ffi_function;
+
+/// The registry of all functions exported by the library.
+#[derive(Debug, Clone, Default)]
+pub struct FfiManifest {
+    functions: Vec<FfiFunction>,
+    crate_name: &'static str,
+    feature: &'static str,
+}
+
+impl FfiManifest {
+    /// Functions added afterwards come from a given crate and require a given cargo feature.
+    pub fn set_origin(&mut self, crate_name: &'static str, feature: &'static str) {
+        self.crate_name = crate_name;
+        self.feature = feature;
+    }
+
+    pub fn add(&mut self, mut function: FfiFunction) {
+        function.crate_name = self.crate_name;
+        function.feature = self.feature;
+        self.functions.push(function);
+    }
+
+    pub fn functions(&self) -> &[FfiFunction] {
+        self.functions.as_slice()
+    }
+
+    pub fn function(&self, name: &str) -> Option<&FfiFunction> {
+        self.functions.iter().find(|function| function.name == name)
+    }
+
+    pub fn to_json(&self) -> String {
+        let mut json = String::from("{\"functions\":[");
+        for (index, function) in self.functions.iter().enumerate() {
+            if index > 0 {
+                json.push(',');
+            }
+            write!(
+                json,
+                "{{\"name\":{},\"crate\":{},\"feature\":{},\"ownership\":{},\"arguments\":[",
+                json_string(&function.name),
+                json_string(function.crate_name),
+                json_string(function.feature),
+                json_string(function.ownership.as_str())
+            )
+            .unwrap();
+            for (index, argument) in function.arguments.iter().enumerate() {
+                if index > 0 {
+                    json.push(',');
+                }
+                write!(
+                    json,
+                    "{{\"name\":{},\"type\":{}}}",
+                    json_string(&argument.name),
+                    json_string(&argument.ty)
+                )
+                .unwrap();
+            }
+            write!(json, "],\"returns\":{}}}", json_string(&function.returns)).unwrap();
+        }
+        json.push_str("]}");
+        json
+    }
+}
+
+fn json_string(string: &str) -> String {
+    let mut json = String::with_capacity(string.len() + 2);
+    json.push('"');
+    for character in string.chars() {
+        match character {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            character if (character as u32) < 0x20 => {
+                write!(json, "\\u{:04x}", character as u32).unwrap()
+            }
+            character => json.push(character),
+        }
+    }
+    json.push('"');
+    json
+}
+
+/// Describe all functions exported with the enabled features.
+pub fn ffi_manifest() -> FfiManifest {
+    let mut manifest = FfiManifest::default();
+
+    manifest.set_origin("value-box-ffi", "");
+    manifest.add(ffi_function!(None fn boxer_test() -> bool));
+    #[cfg(feature = "phlow")]
+    manifest.set_origin("value-box-ffi", "phlow");
+    manifest.add(ffi_function!(Creates fn boxer_value_box_to_phlow_object(
+        value_box: *mut ValueBox<std::ffi::c_void>,
+    ) -> *mut std::ffi::c_void));
+    #[cfg(feature = "string-box")]
+    {
+        manifest.set_origin("value-box-ffi", "string-box");
+        manifest.add(ffi_function!(Creates fn boxer_ffi_manifest() -> *mut ValueBox<StringBox>));
+    }
+
+    #[cfg(feature = "value-box")]
+    crate::value_box_ffi::register_manifest(&mut manifest);
+    #[cfg(feature = "array-box")]
+    crate::array_box_ffi::register_manifest(&mut manifest);
+    #[cfg(feature = "geometry-box")]
+    crate::geometry_box_ffi::register_manifest(&mut manifest);
+    #[cfg(feature = "image-box")]
+    crate::image_box_ffi::register_manifest(&mut manifest);
+    #[cfg(feature = "string-box")]
+    crate::string_box_ffi::register_manifest(&mut manifest);
+
+    manifest
+}
+
+/// Return the description of all exported functions as a JSON document.
+#[no_mangle]
+#[cfg(feature = "string-box")]
+pub extern "C" fn boxer_ffi_manifest() -> *mut ValueBox<StringBox> {
+    ValueBox::new(StringBox::from_string(ffi_manifest().to_json())).into_raw()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn manifest_to_json() {
+        let mut manifest = FfiManifest::default();
+        manifest.set_origin("value-box-ffi", "");
+        manifest.add(
+            FfiFunction::new("boxer_\"example\"", FfiOwnership::Borrows)
+                .argument::<*mut ValueBox<Range<usize>>>("range")
+                .returns::<usize>(),
+        );
+
+        assert_eq!(
+            manifest.to_json(),
+            "{\"functions\":[{\"name\":\"boxer_\\\"example\\\"\",\"crate\":\"value-box-ffi\",\"feature\":\"\",\"ownership\":\"borrows\",\
+            \"arguments\":[{\"name\":\"range\",\"type\":\"*mut ValueBox<Range<usize>>\"}],\"returns\":\"usize\"}]}"
+        );
+    }
+
+    #[test]
+    fn manifest_contains_test_function() {
+        let manifest = ffi_manifest();
+        let function = manifest.function("boxer_test").unwrap();
+        assert_eq!(function.returns, "bool");
+        assert_eq!(function.ownership, FfiOwnership::None);
+    }
+}
@@ -1,3 +1,24 @@
+#[cfg(feature = "serde")]
+pub use serialization::*;
 pub use string::*;
 
 mod string;
+
+use crate::manifest::FfiManifest;
+
+#[cfg(feature = "serde")]
+mod serialization {
+    use crate::{serde_ffi, Serializable};
+    use string_box::StringBox;
+
+    impl Serializable for StringBox {}
+
+    serde_ffi!(StringBox, string);
+}
+
+pub(crate) fn register_manifest(manifest: &mut FfiManifest) {
+    manifest.set_origin("string-box", "string-box");
+    string::register_manifest(manifest);
+    #[cfg(feature = "serde")]
+    serialization::boxer_string_register_serde_manifest(manifest);
+}
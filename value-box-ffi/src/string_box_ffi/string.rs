@@ -1,6 +1,10 @@
 use std::ops::Range;
 use string_box::StringBox;
-use value_box::{value_box, ReturnBoxerResult, ValueBox, ValueBoxPointer};
+use value_box::{
+    value_box, BoxerStatus, ReturnBoxerResult, ReturnBoxerStatus, ValueBox, ValueBoxPointer,
+};
+
+use crate::manifest::{ffi_function, FfiManifest};
 
 #[no_mangle]
 pub extern "C" fn boxer_string_create() -> *mut ValueBox<StringBox> {
@@ -104,3 +108,68 @@ pub extern "C" fn boxer_string_utf16_position_to_char_index(
         .with_ref_ok(|string| string.utf16_position_to_char_index(index))
         .or_log(0)
 }
+
+#[no_mangle]
+pub extern "C" fn boxer_string_get_len_checked(
+    string_box: *mut ValueBox<StringBox>,
+    length: *mut usize,
+) -> BoxerStatus {
+    string_box
+        .with_ref_ok(|string| string.len())
+        .write_to(length)
+}
+
+#[no_mangle]
+pub extern "C" fn boxer_string_get_char_count_checked(
+    string_box: *mut ValueBox<StringBox>,
+    char_count: *mut usize,
+) -> BoxerStatus {
+    string_box
+        .with_ref_ok(|string| string.char_count())
+        .write_to(char_count)
+}
+
+#[no_mangle]
+pub extern "C" fn boxer_string_get_ptr_checked(
+    string_box: *mut ValueBox<StringBox>,
+    data: *mut *const u8,
+) -> BoxerStatus {
+    string_box
+        .with_ref_ok(|string| string.as_ptr())
+        .write_to(data)
+}
+
+#[no_mangle]
+pub extern "C" fn boxer_string_utf16_position_to_char_index_checked(
+    string_box: *mut ValueBox<StringBox>,
+    index: usize,
+    char_index: *mut usize,
+) -> BoxerStatus {
+    string_box
+        .with_ref_ok(|string| string.utf16_position_to_char_index(index))
+        .write_to(char_index)
+}
+
+pub(crate) fn register_manifest(manifest: &mut FfiManifest) {
+    type BoxerString = *mut ValueBox<StringBox>;
+    type BoxerRange = *mut ValueBox<Range<usize>>;
+
+    manifest.add(ffi_function!(Creates fn boxer_string_create() -> BoxerString));
+    manifest.add(ffi_function!(Creates fn boxer_string_from_byte_string(data: *const u8, length: usize) -> BoxerString));
+    manifest.add(ffi_function!(Creates fn boxer_string_from_wide_string(data: *const u32, length: usize) -> BoxerString));
+    manifest.add(ffi_function!(Creates fn boxer_string_from_utf8_string(data: *const u8, length: usize) -> BoxerString));
+    manifest.add(ffi_function!(Consumes fn boxer_string_drop(string_box: BoxerString)));
+    manifest.add(ffi_function!(Borrows fn boxer_string_get_len(string_box: BoxerString) -> usize));
+    manifest.add(
+        ffi_function!(Borrows fn boxer_string_get_char_count(string_box: BoxerString) -> usize),
+    );
+    manifest
+        .add(ffi_function!(Borrows fn boxer_string_get_ptr(string_box: BoxerString) -> *const u8));
+    manifest.add(ffi_function!(Borrows fn boxer_string_char_index_to_byte_range(string_ptr: BoxerString, index: usize, range_ptr: BoxerRange)));
+    manifest.add(ffi_function!(Borrows fn boxer_string_char_index_to_utf16_range(string_box: BoxerString, index: usize, range_ptr: BoxerRange)));
+    manifest.add(ffi_function!(Borrows fn boxer_string_utf16_position_to_char_index(string_box: BoxerString, index: usize) -> usize));
+    manifest.add(ffi_function!(Borrows fn boxer_string_get_len_checked(string_box: BoxerString, length: *mut usize) -> BoxerStatus));
+    manifest.add(ffi_function!(Borrows fn boxer_string_get_char_count_checked(string_box: BoxerString, char_count: *mut usize) -> BoxerStatus));
+    manifest.add(ffi_function!(Borrows fn boxer_string_get_ptr_checked(string_box: BoxerString, data: *mut *const u8) -> BoxerStatus));
+    manifest.add(ffi_function!(Borrows fn boxer_string_utf16_position_to_char_index_checked(string_box: BoxerString, index: usize, char_index: *mut usize) -> BoxerStatus));
+}
@@ -1,5 +1,7 @@
 use std::os::raw::c_void;
-use value_box::{ValueBox, ValueBoxPointer};
+use value_box::{AnyValueBoxPointer, ReturnBoxerResult, ValueBox};
+
+use crate::manifest::{ffi_function, FfiManifest};
 
 #[no_mangle]
 pub extern "C" fn boxer_value_box_get_pointer(ptr: *mut ValueBox<c_void>) -> *const c_void {
@@ -8,13 +10,65 @@ pub extern "C" fn boxer_value_box_get_pointer(ptr: *mut ValueBox<c_void>) -> *co
 
 #[no_mangle]
 pub extern "C" fn boxer_value_box_is_valid(ptr: *mut ValueBox<c_void>) -> bool {
-    ptr.has_value()
+    ptr.has_any_value()
+}
+
+/// Create another box sharing the value with a given one, or return null if
+/// the box was not created shared. The value is dropped once all boxes sharing it are released.
+#[no_mangle]
+pub extern "C" fn boxer_value_box_retain(ptr: *mut ValueBox<c_void>) -> *mut ValueBox<c_void> {
+    ptr.retain_any().or_log(std::ptr::null_mut())
+}
+
+/// Create a weak box that refers to the value of a given box without keeping it alive.
+/// The weak box must be released.
+#[no_mangle]
+pub extern "C" fn boxer_value_box_downgrade(ptr: *mut ValueBox<c_void>) -> *mut ValueBox<c_void> {
+    ptr.downgrade_any().or_log(std::ptr::null_mut())
+}
+
+/// Create a strong box from a weak one, or return null if the value is gone
+/// or the box is not weak.
+#[no_mangle]
+pub extern "C" fn boxer_value_box_upgrade(ptr: *mut ValueBox<c_void>) -> *mut ValueBox<c_void> {
+    ptr.upgrade_any().or_log(std::ptr::null_mut())
+}
+
+pub(crate) fn register_manifest(manifest: &mut FfiManifest) {
+    manifest.add(ffi_function!(Borrows fn boxer_value_box_get_pointer(ptr: *mut ValueBox<c_void>) -> *const c_void));
+    manifest.add(
+        ffi_function!(Borrows fn boxer_value_box_is_valid(ptr: *mut ValueBox<c_void>) -> bool),
+    );
+    manifest.add(ffi_function!(Creates fn boxer_value_box_retain(ptr: *mut ValueBox<c_void>) -> *mut ValueBox<c_void>));
+    manifest.add(ffi_function!(Creates fn boxer_value_box_downgrade(ptr: *mut ValueBox<c_void>) -> *mut ValueBox<c_void>));
+    manifest.add(ffi_function!(Creates fn boxer_value_box_upgrade(ptr: *mut ValueBox<c_void>) -> *mut ValueBox<c_void>));
 }
 
 #[test]
 pub fn test_is_valid() {
     let ptr = ValueBox::new(42).into_raw();
 
-    let void_ptr: *mut ValueBox<c_void> = unsafe { std::mem::transmute(ptr) };
+    let void_ptr = ptr as *mut ValueBox<c_void>;
     assert!(boxer_value_box_is_valid(void_ptr));
 }
+
+#[test]
+pub fn test_retain_downgrade_upgrade() {
+    use value_box::ValueBoxPointer;
+
+    let ptr = ValueBox::new_shared(42).into_raw() as *mut ValueBox<c_void>;
+    let retained = boxer_value_box_retain(ptr);
+    let weak = boxer_value_box_downgrade(retained);
+    assert!(boxer_value_box_upgrade(retained).is_null());
+    (ptr as *mut ValueBox<i32>).release();
+    (retained as *mut ValueBox<i32>).release();
+
+    assert!(!boxer_value_box_is_valid(weak));
+    assert!(boxer_value_box_upgrade(weak).is_null());
+    (weak as *mut ValueBox<i32>).release();
+
+    let ptr = ValueBox::new(42).into_raw() as *mut ValueBox<c_void>;
+    assert!(boxer_value_box_retain(ptr).is_null());
+    assert!(boxer_value_box_downgrade(ptr).is_null());
+    (ptr as *mut ValueBox<i32>).release();
+}
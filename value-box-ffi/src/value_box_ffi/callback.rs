@@ -0,0 +1,100 @@
+use std::os::raw::c_void;
+use value_box::{
+    CallbackAffinity, CallbackBox, CallbackDestructor, CallbackFunction, ReturnBoxerResult,
+    ValueBox, ValueBoxIntoRaw, ValueBoxPointer,
+};
+
+use crate::manifest::{ffi_function, FfiManifest};
+
+/// Create a callback that calls a given function with the user data and an argument.
+/// The destructor, if not null, is called with the user data once the callback is dropped.
+/// Return null if the affinity is not a `CallbackAffinity`, the user data is not released then.
+#[no_mangle]
+pub extern "C" fn boxer_callback_create(
+    callback: CallbackFunction,
+    user_data: *mut c_void,
+    destructor: CallbackDestructor,
+    affinity: u8,
+) -> *mut ValueBox<CallbackBox> {
+    CallbackAffinity::try_from(affinity)
+        .map(|affinity| ValueBox::new(CallbackBox::new(callback, user_data, destructor, affinity)))
+        .into_raw()
+}
+
+#[no_mangle]
+pub extern "C" fn boxer_callback_call(callback: *mut ValueBox<CallbackBox>, argument: *mut c_void) {
+    callback.with_ref(|callback| callback.call(argument)).log();
+}
+
+#[no_mangle]
+pub extern "C" fn boxer_callback_get_affinity(
+    callback: *mut ValueBox<CallbackBox>,
+) -> CallbackAffinity {
+    callback
+        .with_ref_ok(|callback| callback.affinity())
+        .or_log(CallbackAffinity::AnyThread)
+}
+
+#[no_mangle]
+pub extern "C" fn boxer_callback_drop(callback: *mut ValueBox<CallbackBox>) {
+    callback.release();
+}
+
+pub(crate) fn register_manifest(manifest: &mut FfiManifest) {
+    manifest.add(ffi_function!(Creates fn boxer_callback_create(
+        callback: CallbackFunction,
+        user_data: *mut c_void,
+        destructor: CallbackDestructor,
+        affinity: u8,
+    ) -> *mut ValueBox<CallbackBox>));
+    manifest.add(ffi_function!(Borrows fn boxer_callback_call(
+        callback: *mut ValueBox<CallbackBox>,
+        argument: *mut c_void,
+    )));
+    manifest.add(ffi_function!(Borrows fn boxer_callback_get_affinity(
+        callback: *mut ValueBox<CallbackBox>,
+    ) -> CallbackAffinity));
+    manifest
+        .add(ffi_function!(Consumes fn boxer_callback_drop(callback: *mut ValueBox<CallbackBox>)));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static RECEIVED: AtomicUsize = AtomicUsize::new(0);
+    static RELEASED: AtomicUsize = AtomicUsize::new(0);
+
+    extern "C" fn receive(_user_data: *mut c_void, _argument: *mut c_void) {
+        RECEIVED.fetch_add(1, Ordering::SeqCst);
+    }
+
+    extern "C" fn release(_user_data: *mut c_void) {
+        RELEASED.fetch_add(1, Ordering::SeqCst);
+    }
+
+    #[test]
+    fn call_and_drop() {
+        let callback = boxer_callback_create(
+            receive,
+            std::ptr::null_mut(),
+            Some(release),
+            CallbackAffinity::CreatingThread as u8,
+        );
+        assert_eq!(
+            boxer_callback_get_affinity(callback),
+            CallbackAffinity::CreatingThread
+        );
+
+        boxer_callback_call(callback, std::ptr::null_mut());
+        assert_eq!(RECEIVED.load(Ordering::SeqCst), 1);
+
+        boxer_callback_drop(callback);
+        assert_eq!(RELEASED.load(Ordering::SeqCst), 1);
+
+        let callback = boxer_callback_create(receive, std::ptr::null_mut(), Some(release), 2);
+        assert!(callback.is_null());
+        assert_eq!(RELEASED.load(Ordering::SeqCst), 1);
+    }
+}
@@ -0,0 +1,58 @@
+use string_box::StringBox;
+use value_box::ValueBox;
+
+use crate::manifest::{ffi_function, FfiManifest};
+
+/// Start or stop capturing a backtrace for every box passed to the foreign side.
+#[no_mangle]
+pub extern "C" fn boxer_census_capture_backtraces(enabled: bool) {
+    value_box::census_capture_backtraces(enabled);
+}
+
+/// Return the amount of boxes that were not released yet.
+#[no_mangle]
+pub extern "C" fn boxer_census_live_count() -> usize {
+    value_box::census_live_count()
+}
+
+/// Return the amount of distinct types of live boxes.
+#[no_mangle]
+pub extern "C" fn boxer_census_type_count() -> usize {
+    value_box::census_count_per_type().len()
+}
+
+/// Return the amount of live boxes holding a value of a given type.
+/// The type name must be a UTF-8 string without zero-byte.
+#[no_mangle]
+pub extern "C" fn boxer_census_live_count_of_type(type_name: *const u8, length: usize) -> usize {
+    read_type_name(type_name, length)
+        .and_then(|type_name| {
+            value_box::census_count_per_type()
+                .get(type_name.as_str())
+                .copied()
+        })
+        .unwrap_or(0)
+}
+
+/// Copy a type name of the foreign side, or None if the name is a null pointer
+fn read_type_name(type_name: *const u8, length: usize) -> Option<String> {
+    if type_name.is_null() {
+        return None;
+    }
+    let type_name = unsafe { std::slice::from_raw_parts(type_name, length) };
+    Some(String::from_utf8_lossy(type_name).into_owned())
+}
+
+/// Describe all live boxes, including their creation backtraces if captured.
+#[no_mangle]
+pub extern "C" fn boxer_census_dump() -> *mut ValueBox<StringBox> {
+    ValueBox::new(StringBox::from_string(value_box::census_dump())).into_raw()
+}
+
+pub(crate) fn register_manifest(manifest: &mut FfiManifest) {
+    manifest.add(ffi_function!(None fn boxer_census_capture_backtraces(enabled: bool)));
+    manifest.add(ffi_function!(None fn boxer_census_live_count() -> usize));
+    manifest.add(ffi_function!(None fn boxer_census_type_count() -> usize));
+    manifest.add(ffi_function!(None fn boxer_census_live_count_of_type(type_name: *const u8, length: usize) -> usize));
+    manifest.add(ffi_function!(Creates fn boxer_census_dump() -> *mut ValueBox<StringBox>));
+}
@@ -0,0 +1,85 @@
+use value_box::{BoxerErrorKind, BoxerStatus, ReturnBoxerStatus};
+#[cfg(feature = "string-box")]
+use {string_box::StringBox, value_box::ValueBox};
+
+use crate::manifest::{ffi_function, FfiManifest};
+
+/// Return the status of the last error that happened on the current thread
+/// or 0 (`BoxerStatus::Ok`) if there was no error since it was last cleared.
+#[no_mangle]
+pub extern "C" fn boxer_last_error_status() -> BoxerStatus {
+    value_box::last_error()
+        .map(|error| error.kind.into())
+        .unwrap_or(BoxerStatus::Ok)
+}
+
+/// Write the kind of the error reported by a given status into `kind`.
+/// Return false if the status is 0 (`BoxerStatus::Ok`) or unknown, in which case `kind` is left untouched.
+#[no_mangle]
+pub extern "C" fn boxer_status_error_kind(status: BoxerStatus, kind: *mut BoxerErrorKind) -> bool {
+    status
+        .kind()
+        .map(|error_kind| Ok(error_kind).write_to(kind).is_ok())
+        .unwrap_or(false)
+}
+
+/// Return the description of the last error that happened on the current thread
+/// or null if there was no error since it was last cleared.
+#[no_mangle]
+#[cfg(feature = "string-box")]
+pub extern "C" fn boxer_last_error_message() -> *mut ValueBox<StringBox> {
+    value_box::last_error()
+        .map(|error| ValueBox::new(StringBox::from_string(error.message)).into_raw())
+        .unwrap_or(std::ptr::null_mut())
+}
+
+/// Forget the last error that happened on the current thread.
+#[no_mangle]
+pub extern "C" fn boxer_last_error_clear() {
+    value_box::clear_last_error();
+}
+
+pub(crate) fn register_manifest(manifest: &mut FfiManifest) {
+    manifest.add(ffi_function!(None fn boxer_last_error_status() -> BoxerStatus));
+    manifest.add(ffi_function!(None fn boxer_status_error_kind(status: BoxerStatus, kind: *mut BoxerErrorKind) -> bool));
+    #[cfg(feature = "string-box")]
+    manifest.add(ffi_function!(Creates fn boxer_last_error_message() -> *mut ValueBox<StringBox>));
+    manifest.add(ffi_function!(None fn boxer_last_error_clear()));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use value_box::{ReturnBoxerResult, ValueBox, ValueBoxPointer};
+
+    #[test]
+    fn test_last_error() {
+        boxer_last_error_clear();
+        assert_eq!(boxer_last_error_status(), BoxerStatus::Ok);
+
+        let null: *mut ValueBox<u8> = std::ptr::null_mut();
+        null.with_ref_ok(|value| *value).or_log(0);
+        assert_eq!(boxer_last_error_status(), BoxerErrorKind::NullPointer);
+
+        let mut kind = BoxerErrorKind::AnyError;
+        assert!(boxer_status_error_kind(
+            boxer_last_error_status(),
+            &mut kind
+        ));
+        assert_eq!(kind, BoxerErrorKind::NullPointer);
+        assert!(!boxer_status_error_kind(BoxerStatus::Ok, &mut kind));
+
+        #[cfg(feature = "string-box")]
+        {
+            let message = boxer_last_error_message();
+            assert_eq!(
+                message.with_ref_ok(|message| message.to_string()).unwrap(),
+                "The pointer to the box of type u8 is null"
+            );
+            message.release();
+        }
+
+        boxer_last_error_clear();
+        assert_eq!(boxer_last_error_status(), BoxerStatus::Ok);
+    }
+}
@@ -0,0 +1,104 @@
+use std::os::raw::c_void;
+use value_box::{
+    FutureBox, FutureBoxState, FutureWakeCallback, ReturnBoxerResult, ValueBox, ValueBoxPointer,
+};
+
+use crate::manifest::{ffi_function, FfiManifest};
+
+/// Call a given function with the user data whenever the future can make progress
+/// and should be polled again. The callback may be called from any thread.
+/// It should be registered before the future is polled for the first time.
+#[no_mangle]
+pub extern "C" fn boxer_future_set_wake_callback(
+    future: *mut ValueBox<FutureBox>,
+    callback: FutureWakeCallback,
+    user_data: *mut c_void,
+) {
+    future
+        .with_mut_ok(|future| future.set_wake_callback(callback, user_data))
+        .log();
+}
+
+/// Poll the future once. A future that can not be polled is reported as `FutureBoxState_Taken`.
+#[no_mangle]
+pub extern "C" fn boxer_future_poll(future: *mut ValueBox<FutureBox>) -> FutureBoxState {
+    future
+        .with_mut_ok(|future| future.poll())
+        .or_log(FutureBoxState::Taken)
+}
+
+#[no_mangle]
+pub extern "C" fn boxer_future_is_ready(future: *mut ValueBox<FutureBox>) -> bool {
+    future.with_ref_ok(|future| future.is_ready()).or_log(false)
+}
+
+/// Take the result of a complete future as a box of its output type,
+/// or return null if the future is not complete yet.
+#[no_mangle]
+pub extern "C" fn boxer_future_take_result(
+    future: *mut ValueBox<FutureBox>,
+) -> *mut ValueBox<c_void> {
+    future
+        .with_mut(|future| future.take_result_box())
+        .or_log(std::ptr::null_mut())
+}
+
+#[no_mangle]
+pub extern "C" fn boxer_future_drop(future: *mut ValueBox<FutureBox>) {
+    future.release();
+}
+
+pub(crate) fn register_manifest(manifest: &mut FfiManifest) {
+    manifest.add(ffi_function!(Borrows fn boxer_future_set_wake_callback(
+        future: *mut ValueBox<FutureBox>,
+        callback: FutureWakeCallback,
+        user_data: *mut c_void,
+    )));
+    manifest.add(ffi_function!(Borrows fn boxer_future_poll(future: *mut ValueBox<FutureBox>) -> FutureBoxState));
+    manifest.add(
+        ffi_function!(Borrows fn boxer_future_is_ready(future: *mut ValueBox<FutureBox>) -> bool),
+    );
+    manifest.add(ffi_function!(Creates fn boxer_future_take_result(future: *mut ValueBox<FutureBox>) -> *mut ValueBox<c_void>));
+    manifest.add(ffi_function!(Consumes fn boxer_future_drop(future: *mut ValueBox<FutureBox>)));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    static WOKEN: AtomicBool = AtomicBool::new(false);
+
+    extern "C" fn wake(_user_data: *mut c_void) {
+        WOKEN.store(true, Ordering::SeqCst);
+    }
+
+    #[test]
+    fn poll_future() {
+        let mut yielded = false;
+        let future = ValueBox::new(FutureBox::new(std::future::poll_fn(move |context| {
+            if yielded {
+                std::task::Poll::Ready(String::from("done"))
+            } else {
+                yielded = true;
+                context.waker().wake_by_ref();
+                std::task::Poll::Pending
+            }
+        })))
+        .into_raw();
+
+        boxer_future_set_wake_callback(future, wake, std::ptr::null_mut());
+        assert_eq!(boxer_future_poll(future), FutureBoxState::Pending);
+        assert!(WOKEN.load(Ordering::SeqCst));
+        assert!(!boxer_future_is_ready(future));
+        assert!(boxer_future_take_result(future).is_null());
+
+        assert_eq!(boxer_future_poll(future), FutureBoxState::Ready);
+        let result = boxer_future_take_result(future) as *mut ValueBox<String>;
+        assert_eq!(result.with_ref_ok(|result| result.clone()).unwrap(), "done");
+        assert_eq!(boxer_future_poll(future), FutureBoxState::Taken);
+
+        result.release();
+        boxer_future_drop(future);
+    }
+}
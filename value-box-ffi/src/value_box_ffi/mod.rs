@@ -1,3 +1,33 @@
 mod boxes;
+mod callback;
+#[cfg(feature = "census")]
+mod census;
+mod errors;
+mod future;
+#[cfg(feature = "serde")]
+mod serialization;
 
 pub use boxes::*;
+pub use callback::*;
+#[cfg(feature = "census")]
+pub use census::*;
+pub use errors::*;
+pub use future::*;
+#[cfg(feature = "serde")]
+pub use serialization::*;
+
+use crate::manifest::FfiManifest;
+
+pub(crate) fn register_manifest(manifest: &mut FfiManifest) {
+    manifest.set_origin("value-box", "value-box");
+    boxes::register_manifest(manifest);
+    callback::register_manifest(manifest);
+    errors::register_manifest(manifest);
+    future::register_manifest(manifest);
+
+    #[cfg(feature = "census")]
+    {
+        manifest.set_origin("value-box", "census");
+        census::register_manifest(manifest);
+    }
+}
@@ -0,0 +1,141 @@
+use array_box::ArrayBox;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::any::Any;
+use value_box::{
+    deserialize_value, serialize_value, Result, SerializationFormat, ValueBox, ValueBoxPointer,
+};
+
+/// A value exported by [`serde_ffi`], which is checked before it is serialized.
+pub trait Serializable: Serialize {
+    /// Fail if the value can not be read anymore, for example a view of a freed array.
+    fn check_valid(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Serialize the value of a box into a new array of bytes.
+pub fn boxer_serialize<T: Any + Serializable>(
+    value: *mut ValueBox<T>,
+    format: SerializationFormat,
+) -> Result<*mut ValueBox<ArrayBox<u8>>> {
+    value
+        .with_ref(|value| {
+            value.check_valid()?;
+            serialize_value(value, format)
+        })
+        .map(|bytes| ValueBox::new(ArrayBox::from_vector(bytes)).into_raw())
+}
+
+/// Deserialize a value from an array of bytes into a new box.
+pub fn boxer_deserialize<T: Any + DeserializeOwned>(
+    bytes: *mut ValueBox<ArrayBox<u8>>,
+    format: SerializationFormat,
+) -> Result<*mut ValueBox<T>> {
+    bytes
+        .with_ref(|bytes| {
+            crate::array::check_valid(bytes)?;
+            deserialize_value::<T>(bytes.to_slice(), format)
+        })
+        .map(|value| ValueBox::new(value).into_raw())
+}
+
+/// Export functions that serialize a boxed value of a given type into an array of bytes
+/// and deserialize it back, for example `serde_ffi!(geometry_box::PointBox<f32>, point_f32)`
+/// exports `boxer_point_f32_serialize()` and `boxer_point_f32_deserialize()`.
+#[macro_export]
+macro_rules! serde_ffi {
+    ($ty:ty, $name:ident) => {
+        paste::paste! {
+            /// Serialize the boxed value into a new array of bytes of a given `SerializationFormat`.
+            #[no_mangle]
+            pub extern "C" fn [<boxer_ $name _serialize>](
+                value: *mut value_box::ValueBox<$ty>,
+                format: u8,
+            ) -> *mut value_box::ValueBox<array_box::ArrayBox<u8>> {
+                use value_box::ReturnBoxerResult;
+                value_box::SerializationFormat::try_from(format)
+                    .and_then(|format| $crate::boxer_serialize(value, format))
+                    .or_log(std::ptr::null_mut())
+            }
+
+            /// Create a new box with a value deserialized from an array of bytes of a given `SerializationFormat`.
+            #[no_mangle]
+            pub extern "C" fn [<boxer_ $name _deserialize>](
+                bytes: *mut value_box::ValueBox<array_box::ArrayBox<u8>>,
+                format: u8,
+            ) -> *mut value_box::ValueBox<$ty> {
+                use value_box::ReturnBoxerResult;
+                value_box::SerializationFormat::try_from(format)
+                    .and_then(|format| $crate::boxer_deserialize(bytes, format))
+                    .or_log(std::ptr::null_mut())
+            }
+
+            pub(crate) fn [<boxer_ $name _register_serde_manifest>](manifest: &mut $crate::manifest::FfiManifest) {
+                type Bytes = *mut value_box::ValueBox<array_box::ArrayBox<u8>>;
+                type Format = u8;
+                use $crate::manifest::ffi_function;
+
+                manifest.add(ffi_function!(Creates fn [<boxer_ $name _serialize>](value: *mut value_box::ValueBox<$ty>, format: Format) -> Bytes));
+                manifest.add(ffi_function!(Creates fn [<boxer_ $name _deserialize>](bytes: Bytes, format: Format) -> *mut value_box::ValueBox<$ty>));
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "geometry-box")]
+    fn serialize_point() {
+        use crate::geometry_box_ffi::point_f32::*;
+        use crate::*;
+
+        let point = boxer_point_f32_create(1.5, -2.0);
+        for format in [
+            SerializationFormat::Json,
+            SerializationFormat::Cbor,
+            SerializationFormat::Bincode,
+        ] {
+            let bytes = boxer_point_f32_serialize(point, format as u8);
+            let copy = boxer_point_f32_deserialize(bytes, format as u8);
+            assert_eq!(boxer_point_f32_get_x(copy), 1.5);
+            assert_eq!(boxer_point_f32_get_y(copy), -2.0);
+            boxer_point_f32_drop(copy);
+            boxer_array_u8_drop(bytes);
+        }
+        boxer_point_f32_drop(point);
+    }
+
+    #[test]
+    fn serialize_freed_view() {
+        use crate::*;
+
+        let array = boxer_array_u8_create_with(7, 5);
+        let view = boxer_array_u8_sub_array(array, 1, 3);
+        let bytes = boxer_array_u8_serialize(view, SerializationFormat::Json as u8);
+        assert_eq!(boxer_array_u8_get_length(bytes), 5);
+        boxer_array_u8_drop(bytes);
+
+        boxer_array_u8_drop(array);
+        assert!(boxer_array_u8_serialize(view, SerializationFormat::Json as u8).is_null());
+        assert_eq!(
+            boxer_last_error_status(),
+            value_box::BoxerErrorKind::Invalidated
+        );
+        boxer_array_u8_drop(view);
+    }
+
+    #[test]
+    #[cfg(feature = "string-box")]
+    fn deserialize_invalid_bytes() {
+        use crate::*;
+
+        let bytes = boxer_array_u8_create_with(0xff, 3);
+        assert!(boxer_string_deserialize(bytes, SerializationFormat::Json as u8).is_null());
+        assert!(boxer_string_deserialize(bytes, 3).is_null());
+        boxer_array_u8_drop(bytes);
+    }
+}
@@ -0,0 +1,191 @@
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+use syn::{Data, DeriveInput, Field, Fields, LitStr, Result, Type};
+
+use crate::snake_case;
+
+/// The options given with `#[boxer(...)]` on the struct.
+struct StructOptions {
+    prefix: String,
+}
+
+impl StructOptions {
+    fn parse(input: &DeriveInput) -> Result<Self> {
+        let mut prefix = None;
+        for attribute in &input.attrs {
+            if attribute.path().is_ident("boxer") {
+                attribute.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("prefix") {
+                        prefix = Some(meta.value()?.parse::<LitStr>()?.value());
+                        Ok(())
+                    } else {
+                        Err(meta.error("expected `prefix = \"...\"`"))
+                    }
+                })?;
+            }
+        }
+
+        Ok(Self {
+            prefix: prefix
+                .unwrap_or_else(|| format!("boxer_{}", snake_case(&input.ident.to_string()))),
+        })
+    }
+}
+
+/// A field of the struct that gets a getter and a setter.
+struct ExportedField {
+    name: Ident,
+    ty: Type,
+}
+
+fn is_skipped(field: &Field) -> Result<bool> {
+    let mut skip = false;
+    for attribute in &field.attrs {
+        if attribute.path().is_ident("boxer") {
+            attribute.parse_nested_meta(|meta| {
+                if meta.path.is_ident("skip") {
+                    skip = true;
+                    Ok(())
+                } else {
+                    Err(meta.error("expected `skip`"))
+                }
+            })?;
+        }
+    }
+    Ok(skip)
+}
+
+pub fn expand(input: DeriveInput) -> Result<TokenStream> {
+    let options = StructOptions::parse(&input)?;
+
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.generics,
+            "BoxerFfi can not export functions for generic structs",
+        ));
+    }
+
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &input.ident,
+                    "BoxerFfi is only supported for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "BoxerFfi is only supported for structs",
+            ))
+        }
+    };
+
+    let mut exported = vec![];
+    let mut skipped = vec![];
+    for field in fields {
+        let name = field.ident.clone().unwrap();
+        if is_skipped(field)? {
+            skipped.push(name);
+        } else {
+            exported.push(ExportedField {
+                name,
+                ty: field.ty.clone(),
+            });
+        }
+    }
+
+    let boxed = &input.ident;
+    let prefix = options.prefix.as_str();
+    let function = |suffix: &str| Ident::new(&format!("{}_{}", prefix, suffix), Span::call_site());
+
+    let create = function("create");
+    let drop = function("drop");
+    let clone = function("clone");
+    let names = exported.iter().map(|field| &field.name).collect::<Vec<_>>();
+    let types = exported.iter().map(|field| &field.ty).collect::<Vec<_>>();
+
+    let accessors = exported.iter().map(|field| {
+        let name = &field.name;
+        let ty = &field.ty;
+        let get = function(&format!("get_{}", name));
+        let set = function(&format!("set_{}", name));
+        let get_checked = function(&format!("get_{}_checked", name));
+        let set_checked = function(&format!("set_{}_checked", name));
+        let get_doc = format!(
+            "Return `{}`, or its default value if the box is not valid.",
+            name
+        );
+        let set_doc = format!("Set `{}`.", name);
+        let get_checked_doc = format!("Write `{}` into a given out parameter.", name);
+
+        quote! {
+            #[doc = #get_doc]
+            #[no_mangle]
+            pub extern "C" fn #get(boxed_ptr: *mut ::value_box::ValueBox<#boxed>) -> #ty {
+                use ::value_box::{ReturnBoxerResult, ValueBoxPointer};
+                boxed_ptr.with_ref_ok(|this| this.#name.clone())
+                    .or_log(::core::default::Default::default())
+            }
+
+            #[doc = #set_doc]
+            #[no_mangle]
+            pub extern "C" fn #set(boxed_ptr: *mut ::value_box::ValueBox<#boxed>, #name: #ty) {
+                use ::value_box::{ReturnBoxerResult, ValueBoxPointer};
+                boxed_ptr.with_mut_ok(|this| this.#name = #name).log();
+            }
+
+            #[doc = #get_checked_doc]
+            #[no_mangle]
+            pub extern "C" fn #get_checked(
+                boxed_ptr: *mut ::value_box::ValueBox<#boxed>,
+                #name: *mut #ty,
+            ) -> ::value_box::BoxerStatus {
+                use ::value_box::{ReturnBoxerStatus, ValueBoxPointer};
+                boxed_ptr.with_ref_ok(|this| this.#name.clone()).write_to(#name)
+            }
+
+            #[doc = #set_doc]
+            #[no_mangle]
+            pub extern "C" fn #set_checked(
+                boxed_ptr: *mut ::value_box::ValueBox<#boxed>,
+                #name: #ty,
+            ) -> ::value_box::BoxerStatus {
+                use ::value_box::{ReturnBoxerStatus, ValueBoxPointer};
+                boxed_ptr.with_mut_ok(|this| this.#name = #name).status()
+            }
+        }
+    });
+
+    Ok(quote! {
+        #[doc = "Box a new value that must be released by the caller."]
+        #[allow(clippy::too_many_arguments)]
+        #[no_mangle]
+        pub extern "C" fn #create(#(#names: #types),*) -> *mut ::value_box::ValueBox<#boxed> {
+            ::value_box::ValueBox::new(#boxed {
+                #(#names,)*
+                #(#skipped: ::core::default::Default::default(),)*
+            })
+            .into_raw()
+        }
+
+        #[doc = "Release the box, it must not be used afterwards."]
+        #[no_mangle]
+        pub extern "C" fn #drop(boxed_ptr: *mut ::value_box::ValueBox<#boxed>) {
+            use ::value_box::ValueBoxPointer;
+            boxed_ptr.release();
+        }
+
+        #[doc = "Box a clone of the value that must be released by the caller."]
+        #[no_mangle]
+        pub extern "C" fn #clone(boxed_ptr: *mut ::value_box::ValueBox<#boxed>) -> *mut ::value_box::ValueBox<#boxed> {
+            use ::value_box::{ReturnBoxerResult, ValueBoxPointer};
+            boxed_ptr.with_clone_ok(|value| ::value_box::ValueBox::new(value).into_raw())
+                .or_log(::core::ptr::null_mut())
+        }
+
+        #(#accessors)*
+    })
+}
@@ -0,0 +1,313 @@
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::ext::IdentExt;
+use syn::parse::Parser;
+use syn::visit_mut::{self, VisitMut};
+use syn::{
+    Attribute, FnArg, GenericArgument, ImplItem, ImplItemFn, ItemImpl, LitStr, Pat, PathArguments,
+    Result, ReturnType, Type, Visibility,
+};
+
+use crate::snake_case;
+
+/// Types that are passed over ffi by value, any other returned type is boxed.
+const PRIMITIVE_TYPES: [&str; 13] = [
+    "bool", "u8", "i8", "u16", "i16", "u32", "i32", "u64", "i64", "usize", "isize", "f32", "f64",
+];
+
+/// How the exported function gets to the value the method is called on.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Receiver {
+    /// An associated function without `self`
+    None,
+    /// `&self`, the boxed value is borrowed
+    Ref,
+    /// `&mut self`, the boxed value is borrowed mutably
+    Mut,
+    /// `self`, the value is taken out of the box
+    Value,
+    /// `self` marked with `#[boxer(clone)]`, the method is called on a clone of the boxed value
+    Clone,
+}
+
+/// The options given with `#[boxer(...)]` on a method.
+#[derive(Debug, Default)]
+struct MethodOptions {
+    skip: bool,
+    clone: bool,
+}
+
+impl MethodOptions {
+    /// Parse and remove the `#[boxer(...)]` attributes of a method,
+    /// they are not known to the compiler once the impl block is emitted.
+    fn take(method: &mut ImplItemFn) -> Result<Self> {
+        let mut options = Self::default();
+        let mut result = Ok(());
+        method.attrs.retain(|attribute| {
+            if !attribute.path().is_ident("boxer") {
+                return true;
+            }
+            let parsed = attribute.parse_nested_meta(|meta| {
+                if meta.path.is_ident("skip") {
+                    options.skip = true;
+                    Ok(())
+                } else if meta.path.is_ident("clone") {
+                    options.clone = true;
+                    Ok(())
+                } else {
+                    Err(meta.error("expected `skip` or `clone`"))
+                }
+            });
+            if let Err(error) = parsed {
+                result = Err(error);
+            }
+            false
+        });
+        result.map(|_| options)
+    }
+}
+
+pub fn expand(arguments: TokenStream, mut input: ItemImpl) -> Result<TokenStream> {
+    let mut prefix = None;
+    syn::meta::parser(|meta| {
+        if meta.path.is_ident("prefix") {
+            prefix = Some(meta.value()?.parse::<LitStr>()?.value());
+            Ok(())
+        } else {
+            Err(meta.error("expected `prefix = \"...\"`"))
+        }
+    })
+    .parse2(arguments)?;
+
+    if let Some((_, path, _)) = &input.trait_ {
+        return Err(syn::Error::new_spanned(
+            path,
+            "only inherent impl blocks can be exported",
+        ));
+    }
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.generics,
+            "generic impl blocks can not be exported",
+        ));
+    }
+
+    let self_ty = input.self_ty.as_ref().clone();
+    let type_name = match &self_ty {
+        Type::Path(path) if path.qself.is_none() => path
+            .path
+            .segments
+            .last()
+            .map(|segment| segment.ident.to_string())
+            .unwrap_or_default(),
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &self_ty,
+                "only impl blocks of named types can be exported",
+            ))
+        }
+    };
+    let prefix = prefix.unwrap_or_else(|| format!("boxer_{}", snake_case(&type_name)));
+
+    let mut functions = vec![];
+    for item in &mut input.items {
+        if let ImplItem::Fn(method) = item {
+            let options = MethodOptions::take(method)?;
+            if options.skip || !matches!(method.vis, Visibility::Public(_)) {
+                continue;
+            }
+            functions.push(export_method(method, &self_ty, &prefix, options.clone)?);
+        }
+    }
+
+    Ok(quote! {
+        #input
+
+        #(#functions)*
+    })
+}
+
+fn export_method(
+    method: &ImplItemFn,
+    self_ty: &Type,
+    prefix: &str,
+    clone: bool,
+) -> Result<TokenStream> {
+    let signature = &method.sig;
+    if !signature.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &signature.generics,
+            "generic methods can not be exported, mark them with #[boxer(skip)]",
+        ));
+    }
+    if signature.asyncness.is_some() || signature.unsafety.is_some() {
+        return Err(syn::Error::new_spanned(
+            signature,
+            "async or unsafe methods can not be exported, mark them with #[boxer(skip)]",
+        ));
+    }
+
+    let mut receiver = Receiver::None;
+    let mut names = vec![];
+    let mut types = vec![];
+    for (index, input) in signature.inputs.iter().enumerate() {
+        match input {
+            FnArg::Receiver(self_receiver) => {
+                receiver = match (&self_receiver.reference, &self_receiver.mutability) {
+                    (None, _) if clone => Receiver::Clone,
+                    (None, _) => Receiver::Value,
+                    (Some(_), Some(_)) => Receiver::Mut,
+                    (Some(_), None) => Receiver::Ref,
+                }
+            }
+            FnArg::Typed(argument) => {
+                let ty = replace_self(argument.ty.as_ref(), self_ty);
+                if matches!(ty, Type::Reference(_)) {
+                    return Err(syn::Error::new_spanned(
+                        &argument.ty,
+                        "arguments passed by reference can not be exported, mark the method with #[boxer(skip)]",
+                    ));
+                }
+                names.push(match argument.pat.as_ref() {
+                    Pat::Ident(pattern) => pattern.ident.clone(),
+                    _ => format_ident!("argument_{}", index),
+                });
+                types.push(ty);
+            }
+        }
+    }
+
+    let method_name = &signature.ident;
+    let function = Ident::new(
+        &format!("{}_{}", prefix, method_name.unraw()),
+        Span::call_site(),
+    );
+    let docs = method
+        .attrs
+        .iter()
+        .filter(|attribute| attribute.path().is_ident("doc"))
+        .collect::<Vec<&Attribute>>();
+
+    let (output, is_result) = match &signature.output {
+        ReturnType::Default => (None, false),
+        ReturnType::Type(_, ty) => {
+            let ty = replace_self(ty, self_ty);
+            match result_ok_type(&ty) {
+                Some(ok) => (Some(ok), true),
+                None => (Some(ty), false),
+            }
+        }
+    };
+    let output = output.filter(|ty| !matches!(ty, Type::Tuple(tuple) if tuple.elems.is_empty()));
+
+    let call = match receiver {
+        Receiver::None => quote! { <#self_ty>::#method_name(#(#names),*) },
+        _ => quote! { this.#method_name(#(#names),*) },
+    };
+    let value = if is_result {
+        quote! { #call.map_err(::value_box::BoxerError::from) }
+    } else {
+        quote! { Ok(#call) }
+    };
+    let result = match receiver {
+        Receiver::None => quote! { ::value_box::catch_panic(|| #value) },
+        Receiver::Ref => quote! { boxed_ptr.with_ref(|this| #value) },
+        Receiver::Mut => quote! { boxed_ptr.with_mut(|this| #value) },
+        Receiver::Value => quote! {
+            boxed_ptr
+                .take_value()
+                .and_then(|this| ::value_box::catch_panic(|| #value))
+        },
+        Receiver::Clone => quote! { boxed_ptr.with_clone(|this| #value) },
+    };
+
+    let (return_type, body) = match output {
+        None => (quote! {}, quote! { #result.log() }),
+        Some(Type::Ptr(pointer)) => {
+            let null = if pointer.mutability.is_some() {
+                quote! { ::core::ptr::null_mut() }
+            } else {
+                quote! { ::core::ptr::null() }
+            };
+            (quote! { -> #pointer }, quote! { #result.or_log(#null) })
+        }
+        Some(ty) if is_primitive(&ty) => (
+            quote! { -> #ty },
+            quote! { #result.or_log(::core::default::Default::default()) },
+        ),
+        Some(ty) => (
+            quote! { -> *mut ::value_box::ValueBox<#ty> },
+            quote! {
+                #result
+                    .map(|value| ::value_box::ValueBox::new(value).into_raw())
+                    .or_log(::core::ptr::null_mut())
+            },
+        ),
+    };
+
+    let (box_argument, imports) = match receiver {
+        Receiver::None => (quote! {}, quote! { ::value_box::ReturnBoxerResult }),
+        _ => (
+            quote! { boxed_ptr: *mut ::value_box::ValueBox<#self_ty>, },
+            quote! { ::value_box::{ReturnBoxerResult, ValueBoxPointer} },
+        ),
+    };
+
+    Ok(quote! {
+        #(#docs)*
+        #[no_mangle]
+        #[allow(clippy::too_many_arguments)]
+        pub extern "C" fn #function(#box_argument #(#names: #types),*) #return_type {
+            use #imports;
+            #body
+        }
+    })
+}
+
+/// The type of the successful value if a given type is a `Result`.
+fn result_ok_type(ty: &Type) -> Option<Type> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    if segment.ident != "Result" {
+        return None;
+    }
+    match &segment.arguments {
+        PathArguments::AngleBracketed(arguments) => match arguments.args.first()? {
+            GenericArgument::Type(ok) => Some(ok.clone()),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn is_primitive(ty: &Type) -> bool {
+    match ty {
+        Type::Path(path) if path.qself.is_none() => path
+            .path
+            .get_ident()
+            .is_some_and(|ident| PRIMITIVE_TYPES.contains(&ident.to_string().as_str())),
+        _ => false,
+    }
+}
+
+/// Replace `Self` with the type of the impl block, the exported functions are declared outside of it.
+fn replace_self(ty: &Type, self_ty: &Type) -> Type {
+    struct ReplaceSelf<'a>(&'a Type);
+
+    impl VisitMut for ReplaceSelf<'_> {
+        fn visit_type_mut(&mut self, ty: &mut Type) {
+            match ty {
+                Type::Path(path) if path.qself.is_none() && path.path.is_ident("Self") => {
+                    *ty = self.0.clone();
+                }
+                _ => visit_mut::visit_type_mut(self, ty),
+            }
+        }
+    }
+
+    let mut ty = ty.clone();
+    ReplaceSelf(self_ty).visit_type_mut(&mut ty);
+    ty
+}
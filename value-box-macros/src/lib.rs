@@ -0,0 +1,73 @@
+mod derive;
+mod export;
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput, ItemImpl};
+
+/// Generate `extern "C"` functions to create, drop and clone a boxed struct
+/// and to get and set each of its fields:
+///
+/// ```ignore
+/// #[derive(Clone, BoxerFfi)]
+/// #[boxer(prefix = "library_color")]
+/// pub struct Color {
+///     pub red: u8,
+///     #[boxer(skip)]
+///     pub name: String,
+/// }
+/// ```
+///
+/// exports `library_color_create(red)`, `library_color_drop(color)`, `library_color_clone(color)`,
+/// `library_color_get_red(color)`, `library_color_set_red(color, red)` and the `_checked`
+/// variants of the accessors returning a `BoxerStatus`.
+/// Skipped fields are initialized with their `Default` value.
+#[proc_macro_derive(BoxerFfi, attributes(boxer))]
+pub fn derive_boxer_ffi(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    derive::expand(input)
+        .unwrap_or_else(|error| error.to_compile_error())
+        .into()
+}
+
+/// Export the public methods of an `impl` block as `extern "C"` functions
+/// that take the boxed value as the first argument:
+///
+/// ```ignore
+/// use value_box_macros as boxer;
+///
+/// #[boxer::export(prefix = "library_object")]
+/// impl MyObject {
+///     pub fn new() -> Self { ... }
+///     pub fn is_something(&self) -> bool { ... }
+///     pub fn by_mut(&mut self) { ... }
+/// }
+/// ```
+///
+/// exports `library_object_new()`, `library_object_is_something(object)` and `library_object_by_mut(object)`.
+/// Methods taking `&self` or `&mut self` borrow the boxed value, methods taking `self` take it out of the box
+/// unless marked with `#[boxer(clone)]`, in which case they are evaluated with a clone of the value.
+/// Returned structs are boxed, `Result`s are converted into a `BoxerError` and logged.
+/// Methods marked with `#[boxer(skip)]` are not exported.
+#[proc_macro_attribute]
+pub fn export(arguments: TokenStream, input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as ItemImpl);
+    export::expand(arguments.into(), input)
+        .unwrap_or_else(|error| error.to_compile_error())
+        .into()
+}
+
+/// Convert a type name such as `MyObject` to `my_object`.
+pub(crate) fn snake_case(name: &str) -> String {
+    let mut snake = String::new();
+    for (index, character) in name.chars().enumerate() {
+        if character.is_uppercase() {
+            if index > 0 {
+                snake.push('_');
+            }
+            snake.extend(character.to_lowercase());
+        } else {
+            snake.push(character);
+        }
+    }
+    snake
+}
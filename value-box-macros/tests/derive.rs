@@ -0,0 +1,69 @@
+use value_box::{BoxerErrorKind, BoxerStatus, ValueBox, ValueBoxPointer};
+use value_box_macros::BoxerFfi;
+
+#[derive(Debug, Clone, BoxerFfi)]
+#[boxer(prefix = "test_color")]
+pub struct Color {
+    pub red: u8,
+    pub green: u8,
+    pub alpha: f32,
+    #[boxer(skip)]
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Default, BoxerFfi)]
+pub struct UnitSize {
+    pub width: u32,
+}
+
+#[test]
+fn create_and_access() {
+    let color = test_color_create(255, 128, 0.5);
+    assert_eq!(test_color_get_red(color), 255);
+    assert_eq!(test_color_get_green(color), 128);
+    assert_eq!(test_color_get_alpha(color), 0.5);
+    assert_eq!(color.with_ref_ok(|color| color.name.clone()).unwrap(), "");
+
+    test_color_set_green(color, 64);
+    assert_eq!(test_color_get_green(color), 64);
+
+    test_color_drop(color);
+}
+
+#[test]
+fn clone_is_independent() {
+    let color = test_color_create(1, 2, 1.0);
+    let clone = test_color_clone(color);
+    test_color_set_red(color, 10);
+
+    assert_eq!(test_color_get_red(clone), 1);
+    assert_eq!(test_color_get_red(color), 10);
+
+    test_color_drop(color);
+    test_color_drop(clone);
+}
+
+#[test]
+fn checked_accessors() {
+    let color = test_color_create(1, 2, 1.0);
+    let mut red = 0u8;
+    assert_eq!(test_color_set_red_checked(color, 42), BoxerStatus::Ok);
+    assert_eq!(test_color_get_red_checked(color, &mut red), BoxerStatus::Ok);
+    assert_eq!(red, 42);
+    test_color_drop(color);
+
+    let null = std::ptr::null_mut::<ValueBox<Color>>();
+    assert_eq!(
+        test_color_get_red_checked(null, &mut red),
+        BoxerErrorKind::NullPointer
+    );
+    assert_eq!(test_color_get_red(null), 0);
+    assert!(test_color_clone(null).is_null());
+}
+
+#[test]
+fn default_prefix() {
+    let size = boxer_unit_size_create(10);
+    assert_eq!(boxer_unit_size_get_width(size), 10);
+    boxer_unit_size_drop(size);
+}
@@ -0,0 +1,104 @@
+use value_box::{BoxerError, ValueBox, ValueBoxPointer};
+use value_box_macros as boxer;
+
+#[derive(Debug, Clone, Default)]
+pub struct Counter {
+    count: u32,
+}
+
+#[boxer::export(prefix = "test_counter")]
+impl Counter {
+    /// Create a counter starting at a given value.
+    pub fn new(count: u32) -> Self {
+        Self { count }
+    }
+
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+
+    pub fn increment(&mut self) {
+        self.count += 1;
+    }
+
+    pub fn doubled(&self) -> Counter {
+        Self {
+            count: self.count * 2,
+        }
+    }
+
+    pub fn finish(self) -> u32 {
+        self.count
+    }
+
+    #[boxer(clone)]
+    pub fn peek(self) -> u32 {
+        self.count
+    }
+
+    pub fn checked_decrement(&mut self) -> Result<u32, BoxerError> {
+        self.count = self
+            .count
+            .checked_sub(1)
+            .ok_or_else(|| BoxerError::from("The counter is zero"))?;
+        Ok(self.count)
+    }
+
+    pub fn try_new(count: i64) -> Result<Self, String> {
+        u32::try_from(count)
+            .map(Self::new)
+            .map_err(|error| error.to_string())
+    }
+
+    #[boxer(skip)]
+    pub fn add(&mut self, other: &Counter) {
+        self.count += other.count;
+    }
+}
+
+#[test]
+fn borrow_by_receiver() {
+    let counter = test_counter_new(1);
+    test_counter_increment(counter);
+    assert_eq!(test_counter_count(counter), 2);
+    assert_eq!(test_counter_peek(counter), 2);
+    assert!(counter.has_value());
+
+    assert_eq!(test_counter_finish(counter), 2);
+    assert!(!counter.has_value());
+    assert_eq!(test_counter_count(counter), 0);
+
+    counter.release();
+}
+
+#[test]
+fn box_returned_structs() {
+    let counter = test_counter_new(3);
+    let doubled = test_counter_doubled(counter);
+    assert_eq!(test_counter_count(doubled), 6);
+
+    counter.release();
+    doubled.release();
+}
+
+#[test]
+fn convert_results() {
+    let counter = test_counter_new(1);
+    assert_eq!(test_counter_checked_decrement(counter), 0);
+    assert_eq!(test_counter_checked_decrement(counter), 0);
+    assert_eq!(test_counter_count(counter), 0);
+    counter.release();
+
+    assert!(test_counter_try_new(-5).is_null());
+    let created = test_counter_try_new(5);
+    assert_eq!(test_counter_count(created), 5);
+    created.release();
+}
+
+#[test]
+fn null_pointer() {
+    let null = std::ptr::null_mut::<ValueBox<Counter>>();
+    assert_eq!(test_counter_count(null), 0);
+    assert!(test_counter_doubled(null).is_null());
+    test_counter_increment(null);
+}
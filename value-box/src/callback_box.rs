@@ -0,0 +1,187 @@
+use std::any::type_name;
+use std::ffi::c_void;
+use std::thread::{self, ThreadId};
+
+use crate::{BoxerError, Result, ReturnBoxerResult};
+
+/// A function of the foreign side that is called with the user data and an argument,
+/// for example a pointer to a box with the details of an event.
+pub type CallbackFunction = extern "C" fn(*mut c_void, *mut c_void);
+
+/// A function of the foreign side that releases the user data of a callback,
+/// it is called once the callback is dropped.
+pub type CallbackDestructor = Option<extern "C" fn(*mut c_void)>;
+
+/// On which threads a [`CallbackBox`] may be called.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[repr(u8)]
+pub enum CallbackAffinity {
+    /// The callback is thread-safe and may be called from any thread
+    AnyThread = 0,
+    /// The callback may only be called from the thread that created it,
+    /// calling it from another thread fails with [`BoxerError::WrongThread`]
+    CreatingThread = 1,
+}
+
+impl TryFrom<u8> for CallbackAffinity {
+    type Error = BoxerError;
+
+    fn try_from(value: u8) -> Result<Self> {
+        match value {
+            0 => Ok(Self::AnyThread),
+            1 => Ok(Self::CreatingThread),
+            _ => BoxerError::InvalidArgument(format!("Unknown callback affinity {}", value)).into(),
+        }
+    }
+}
+
+/// A function of the foreign side together with its user data,
+/// that Rust code can call to notify the host, for example about progress or events.
+/// The user data is released with the destructor when the callback is dropped.
+pub struct CallbackBox<A: Copy = *mut c_void> {
+    callback: extern "C" fn(*mut c_void, A),
+    user_data: *mut c_void,
+    destructor: CallbackDestructor,
+    thread: Option<ThreadId>,
+}
+
+// calling the callback from other threads is checked against its affinity
+unsafe impl<A: Copy> Send for CallbackBox<A> {}
+unsafe impl<A: Copy> Sync for CallbackBox<A> {}
+
+impl<A: Copy + 'static> CallbackBox<A> {
+    pub fn new(
+        callback: extern "C" fn(*mut c_void, A),
+        user_data: *mut c_void,
+        destructor: CallbackDestructor,
+        affinity: CallbackAffinity,
+    ) -> Self {
+        Self {
+            callback,
+            user_data,
+            destructor,
+            thread: match affinity {
+                CallbackAffinity::AnyThread => None,
+                CallbackAffinity::CreatingThread => Some(thread::current().id()),
+            },
+        }
+    }
+
+    pub fn affinity(&self) -> CallbackAffinity {
+        if self.thread.is_some() {
+            CallbackAffinity::CreatingThread
+        } else {
+            CallbackAffinity::AnyThread
+        }
+    }
+
+    /// Call the foreign function with the user data and a given argument.
+    pub fn call(&self, argument: A) -> Result<()> {
+        self.ensure_on_creating_thread()?;
+        (self.callback)(self.user_data, argument);
+        Ok(())
+    }
+
+    /// Turn the callback into a closure that can be stored in other Rust structs.
+    /// Errors of the calls, such as a call from the wrong thread, are logged.
+    pub fn into_fn(self) -> impl Fn(A) + Send + Sync + 'static {
+        move |argument| self.call(argument).log()
+    }
+
+    fn ensure_on_creating_thread(&self) -> Result<()> {
+        match self.thread {
+            Some(thread) if thread != thread::current().id() => {
+                BoxerError::WrongThread(type_name::<Self>().to_string()).into()
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl<A: Copy> Drop for CallbackBox<A> {
+    fn drop(&mut self) {
+        let Some(destructor) = self.destructor else {
+            return;
+        };
+        match self.thread {
+            // the user data is leaked rather than released on a thread the host does not expect
+            Some(thread) if thread != thread::current().id() => error!(
+                "The user data of a callback can not be released on another thread than the one that created it"
+            ),
+            _ => destructor(self.user_data),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    extern "C" fn add(user_data: *mut c_void, amount: usize) {
+        let counter = unsafe { &*(user_data as *const AtomicUsize) };
+        counter.fetch_add(amount, Ordering::SeqCst);
+    }
+
+    extern "C" fn add_one(user_data: *mut c_void, _argument: *mut c_void) {
+        add(user_data, 1);
+    }
+
+    static RELEASED: AtomicUsize = AtomicUsize::new(0);
+
+    extern "C" fn release(_user_data: *mut c_void) {
+        RELEASED.fetch_add(1, Ordering::SeqCst);
+    }
+
+    struct Download {
+        on_progress: Box<dyn Fn(usize) + Send + Sync>,
+    }
+
+    #[test]
+    fn call_as_closure() {
+        let counter = AtomicUsize::new(0);
+        let callback = CallbackBox::new(
+            add,
+            &counter as *const AtomicUsize as *mut c_void,
+            None,
+            CallbackAffinity::AnyThread,
+        );
+
+        let download = Download {
+            on_progress: Box::new(callback.into_fn()),
+        };
+        (download.on_progress)(10);
+        (download.on_progress)(32);
+        assert_eq!(counter.load(Ordering::SeqCst), 42);
+    }
+
+    #[test]
+    fn call_from_another_thread() -> Result<()> {
+        let counter = AtomicUsize::new(0);
+        let user_data = &counter as *const AtomicUsize as *mut c_void;
+
+        let any_thread = CallbackBox::new(add_one, user_data, None, CallbackAffinity::AnyThread);
+        let creating_thread = CallbackBox::new(
+            add_one,
+            user_data,
+            Some(release),
+            CallbackAffinity::CreatingThread,
+        );
+
+        thread::scope(|scope| {
+            scope.spawn(|| {
+                assert!(any_thread.call(std::ptr::null_mut()).is_ok());
+                assert!(matches!(
+                    creating_thread.call(std::ptr::null_mut()),
+                    Err(BoxerError::WrongThread(_))
+                ));
+            });
+        });
+        creating_thread.call(std::ptr::null_mut())?;
+        assert_eq!(counter.load(Ordering::SeqCst), 2);
+
+        drop(creating_thread);
+        assert_eq!(RELEASED.load(Ordering::SeqCst), 1);
+        Ok(())
+    }
+}
@@ -1,5 +1,7 @@
 use crate::ValueBox;
-use std::any::Any;
+use std::any::{type_name, Any};
+use std::cell::RefCell;
+use std::panic::AssertUnwindSafe;
 use thiserror::Error;
 use user_error::{UserFacingError, UFE};
 
@@ -13,6 +15,35 @@ pub enum BoxerError {
     NullPointer(String),
     #[error("There is no value of type {0} in the box")]
     NoValue(String),
+    #[error("The handle {1:#x} to the box of type {0} is stale or invalid")]
+    InvalidHandle(String, u64),
+    #[error("Expected a box of type {expected}, but the box holds a value of type {actual}")]
+    WrongType { expected: String, actual: String },
+    #[error("The box of type {0} is locked by another thread")]
+    WouldBlock(String),
+    #[error("Panicked: {0}")]
+    Panic(String),
+    #[error("The index {index} is out of bounds of {length}")]
+    OutOfBounds { index: usize, length: usize },
+    #[error("The box of type {0} is already borrowed")]
+    AlreadyBorrowed(String),
+    #[error("The {0} can only be used on the thread that created it")]
+    WrongThread(String),
+    #[error("The data of the {0} was invalidated by its owner")]
+    Invalidated(String),
+    #[error("Expected {expected} items, but got {actual}")]
+    LengthMismatch { expected: usize, actual: usize },
+    #[error("Could not convert all items from {from} to {to} without loss")]
+    LossyConversion { from: String, to: String },
+    #[error("The box of type {0} was not created shared")]
+    NotShared(String),
+    #[error("The box of type {0} is not weak")]
+    NotWeak(String),
+    #[error("Invalid argument: {0}")]
+    InvalidArgument(String),
+    #[error("Could not serialize or deserialize a value: {0}")]
+    #[cfg(feature = "serde")]
+    SerializationError(String),
     #[error("There was an error")]
     #[cfg(feature = "anyhow")]
     AnyhowError(#[from] anyhow::Error),
@@ -22,6 +53,156 @@ pub enum BoxerError {
     AnyError(#[from] Box<dyn std::error::Error>),
 }
 
+/// A C-friendly identifier of the [`BoxerError`] variant.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[repr(u8)]
+pub enum BoxerErrorKind {
+    NullPointer = 0,
+    NoValue = 1,
+    InvalidHandle = 2,
+    WrongType = 3,
+    WouldBlock = 4,
+    Panic = 5,
+    AnyhowError = 6,
+    IOError = 7,
+    AnyError = 8,
+    OutOfBounds = 9,
+    AlreadyBorrowed = 10,
+    WrongThread = 11,
+    SerializationError = 12,
+    Invalidated = 13,
+    LengthMismatch = 14,
+    LossyConversion = 15,
+    NotShared = 16,
+    NotWeak = 17,
+    InvalidArgument = 18,
+}
+
+impl TryFrom<u8> for BoxerErrorKind {
+    type Error = BoxerError;
+
+    fn try_from(value: u8) -> Result<Self> {
+        Ok(match value {
+            0 => Self::NullPointer,
+            1 => Self::NoValue,
+            2 => Self::InvalidHandle,
+            3 => Self::WrongType,
+            4 => Self::WouldBlock,
+            5 => Self::Panic,
+            6 => Self::AnyhowError,
+            7 => Self::IOError,
+            8 => Self::AnyError,
+            9 => Self::OutOfBounds,
+            10 => Self::AlreadyBorrowed,
+            11 => Self::WrongThread,
+            12 => Self::SerializationError,
+            13 => Self::Invalidated,
+            14 => Self::LengthMismatch,
+            15 => Self::LossyConversion,
+            16 => Self::NotShared,
+            17 => Self::NotWeak,
+            18 => Self::InvalidArgument,
+            _ => {
+                return BoxerError::InvalidArgument(format!("Unknown error kind {}", value)).into();
+            }
+        })
+    }
+}
+
+impl BoxerError {
+    pub fn kind(&self) -> BoxerErrorKind {
+        match self {
+            BoxerError::NullPointer(_) => BoxerErrorKind::NullPointer,
+            BoxerError::NoValue(_) => BoxerErrorKind::NoValue,
+            BoxerError::InvalidHandle(_, _) => BoxerErrorKind::InvalidHandle,
+            BoxerError::WrongType { .. } => BoxerErrorKind::WrongType,
+            BoxerError::WouldBlock(_) => BoxerErrorKind::WouldBlock,
+            BoxerError::Panic(_) => BoxerErrorKind::Panic,
+            BoxerError::OutOfBounds { .. } => BoxerErrorKind::OutOfBounds,
+            BoxerError::AlreadyBorrowed(_) => BoxerErrorKind::AlreadyBorrowed,
+            BoxerError::WrongThread(_) => BoxerErrorKind::WrongThread,
+            BoxerError::Invalidated(_) => BoxerErrorKind::Invalidated,
+            BoxerError::LengthMismatch { .. } => BoxerErrorKind::LengthMismatch,
+            BoxerError::LossyConversion { .. } => BoxerErrorKind::LossyConversion,
+            BoxerError::NotShared(_) => BoxerErrorKind::NotShared,
+            BoxerError::NotWeak(_) => BoxerErrorKind::NotWeak,
+            BoxerError::InvalidArgument(_) => BoxerErrorKind::InvalidArgument,
+            #[cfg(feature = "serde")]
+            BoxerError::SerializationError(_) => BoxerErrorKind::SerializationError,
+            #[cfg(feature = "anyhow")]
+            BoxerError::AnyhowError(_) => BoxerErrorKind::AnyhowError,
+            BoxerError::IOError(_) => BoxerErrorKind::IOError,
+            BoxerError::AnyError(_) => BoxerErrorKind::AnyError,
+        }
+    }
+}
+
+/// The outcome of an ffi function that reports errors as a status code
+/// and returns its results through out parameters.
+/// The status is [`BoxerStatus::Ok`] (0) on success, otherwise it is the [`BoxerErrorKind`] of the error plus one.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct BoxerStatus(u8);
+
+impl BoxerStatus {
+    #[allow(non_upper_case_globals)]
+    pub const Ok: BoxerStatus = BoxerStatus(0);
+
+    pub fn is_ok(&self) -> bool {
+        *self == Self::Ok
+    }
+
+    /// The kind of the error, or `None` if the status is [`BoxerStatus::Ok`].
+    pub fn kind(&self) -> Option<BoxerErrorKind> {
+        self.0
+            .checked_sub(1)
+            .and_then(|kind| BoxerErrorKind::try_from(kind).ok())
+    }
+}
+
+impl From<BoxerErrorKind> for BoxerStatus {
+    fn from(kind: BoxerErrorKind) -> Self {
+        Self(kind as u8 + 1)
+    }
+}
+
+impl PartialEq<BoxerErrorKind> for BoxerStatus {
+    fn eq(&self, kind: &BoxerErrorKind) -> bool {
+        *self == Self::from(*kind)
+    }
+}
+
+/// The last error reported by [`ReturnBoxerResult`] on the current thread.
+#[derive(Debug, Clone)]
+pub struct LastError {
+    pub kind: BoxerErrorKind,
+    pub message: String,
+}
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<LastError>> = const { RefCell::new(None) };
+}
+
+/// Return the last error that was logged or printed on the current thread, if any.
+/// The error stays there until it is cleared or replaced by another one.
+pub fn last_error() -> Option<LastError> {
+    LAST_ERROR.with(|last_error| last_error.borrow().clone())
+}
+
+/// Forget the last error of the current thread.
+pub fn clear_last_error() {
+    LAST_ERROR.with(|last_error| last_error.borrow_mut().take());
+}
+
+fn set_last_error(kind: BoxerErrorKind, error: &UserFacingError) {
+    let mut message = error.summary();
+    for reason in error.reasons().unwrap_or_default() {
+        message.push_str("\n - ");
+        message.push_str(&reason);
+    }
+    LAST_ERROR.with(|last_error| last_error.replace(Some(LastError { kind, message })));
+}
+
 impl<T> From<BoxerError> for core::result::Result<T, BoxerError> {
     fn from(error: BoxerError) -> Self {
         Err(error)
@@ -42,6 +223,21 @@ impl From<&str> for BoxerError {
 
 pub type Result<T> = core::result::Result<T, BoxerError>;
 
+/// Evaluate a given function and turn a panic into [`BoxerError::Panic`],
+/// so that it does not unwind across the ffi boundary.
+pub fn catch_panic<R>(op: impl FnOnce() -> Result<R>) -> Result<R> {
+    std::panic::catch_unwind(AssertUnwindSafe(op)).unwrap_or_else(|payload| {
+        let message = if let Some(message) = payload.downcast_ref::<&str>() {
+            message.to_string()
+        } else if let Some(message) = payload.downcast_ref::<String>() {
+            message.clone()
+        } else {
+            "Unknown panic".to_string()
+        };
+        BoxerError::Panic(message).into()
+    })
+}
+
 pub trait ReturnBoxerResult<Return: Any> {
     fn log(self);
     fn or_log(self, value: Return) -> Return;
@@ -68,8 +264,9 @@ impl<Return: Any> ReturnBoxerResult<Return> for Result<Return> {
 
     fn or_print(self, value: Return) -> Return {
         self.map_err(|error| {
-            let error: Box<dyn std::error::Error> = Box::new(error);
-            let user_facing_error: UserFacingError = error.into();
+            let kind = error.kind();
+            let user_facing_error = to_user_facing_error(error);
+            set_last_error(kind, &user_facing_error);
             user_facing_error
         })
         .unwrap_or_else(|error| {
@@ -82,6 +279,40 @@ impl<Return: Any> ReturnBoxerResult<Return> for Result<Return> {
     }
 }
 
+pub trait ReturnBoxerStatus<Return: Any> {
+    /// Log the error if there is one and return the corresponding status.
+    fn status(self) -> BoxerStatus;
+    /// Write the value into a given out parameter,
+    /// or log the error if there is one and return the corresponding status.
+    fn write_to(self, out: *mut Return) -> BoxerStatus;
+}
+
+impl<Return: Any> ReturnBoxerStatus<Return> for Result<Return> {
+    fn status(self) -> BoxerStatus {
+        match self {
+            Ok(_) => BoxerStatus::Ok,
+            Err(error) => {
+                let status = error.kind().into();
+                log_boxer_error(error);
+                status
+            }
+        }
+    }
+
+    fn write_to(self, out: *mut Return) -> BoxerStatus {
+        self.and_then(|value| write_out_parameter(out, value))
+            .status()
+    }
+}
+
+fn write_out_parameter<Return: Any>(out: *mut Return, value: Return) -> Result<()> {
+    if out.is_null() {
+        return BoxerError::NullPointer(type_name::<Return>().to_string()).into();
+    }
+    unsafe { out.write(value) };
+    Ok(())
+}
+
 impl<Return: Any> ValueBoxIntoRaw<Return> for Result<ValueBox<Return>> {
     fn into_raw(self) -> *mut ValueBox<Return> {
         self.map(|value| value.into_raw())
@@ -101,10 +332,15 @@ impl<Return: Any> ValueBoxIntoRaw<Return> for Result<Option<ValueBox<Return>>> {
 }
 
 fn log_boxer_error(error: BoxerError) {
-    match &error {
-        BoxerError::NullPointer(_) => warn_user_facing_error(to_user_facing_error(error)),
-        BoxerError::NoValue(_) => warn_user_facing_error(to_user_facing_error(error)),
-        _ => error_user_facing_error(to_user_facing_error(error)),
+    let kind = error.kind();
+    let user_facing_error = to_user_facing_error(error);
+    set_last_error(kind, &user_facing_error);
+
+    match kind {
+        BoxerErrorKind::NullPointer => warn_user_facing_error(user_facing_error),
+        BoxerErrorKind::NoValue => warn_user_facing_error(user_facing_error),
+        BoxerErrorKind::WouldBlock => warn_user_facing_error(user_facing_error),
+        _ => error_user_facing_error(user_facing_error),
     };
 }
 
@@ -142,3 +378,60 @@ fn pretty_reasons(reasons: Option<Vec<String>>) -> Option<String> {
         [&reason_strings.join("\n"), RESET].concat()
     })
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ValueBoxPointer;
+
+    #[test]
+    fn last_error_is_set_by_or_log() {
+        clear_last_error();
+        assert!(last_error().is_none());
+
+        let null: *mut ValueBox<u32> = std::ptr::null_mut();
+        assert_eq!(null.with_ref_ok(|value| *value).or_log(0), 0);
+
+        let error = last_error().unwrap();
+        assert_eq!(error.kind, BoxerErrorKind::NullPointer);
+        assert_eq!(error.message, "The pointer to the box of type u32 is null");
+
+        Result::<()>::Err("Something went wrong".into()).log();
+        let error = last_error().unwrap();
+        assert_eq!(error.kind, BoxerErrorKind::AnyError);
+        assert!(error.message.contains("Something went wrong"));
+
+        clear_last_error();
+        assert!(last_error().is_none());
+    }
+
+    #[test]
+    fn write_to_out_parameter() {
+        let ptr = ValueBox::new(42u32).into_raw();
+
+        let mut value = 0u32;
+        let status = ptr.with_ref_ok(|value| *value).write_to(&mut value);
+        assert_eq!(status, BoxerStatus::Ok);
+        assert_eq!(value, 42);
+
+        let status = ptr
+            .with_ref_ok(|value| *value)
+            .write_to(std::ptr::null_mut());
+        assert_eq!(status, BoxerErrorKind::NullPointer);
+
+        let status = ptr
+            .with_ref(|_| {
+                BoxerError::OutOfBounds {
+                    index: 1,
+                    length: 0,
+                }
+                .into()
+            })
+            .write_to(&mut value);
+        assert_eq!(status, BoxerErrorKind::OutOfBounds);
+        assert_eq!(status.kind(), Some(BoxerErrorKind::OutOfBounds));
+        assert_eq!(value, 42);
+
+        ptr.release();
+    }
+}
@@ -0,0 +1,209 @@
+use std::any::{type_name, Any};
+use std::ffi::c_void;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake, Waker};
+
+use crate::{BoxerError, Result, ValueBox};
+
+/// A function of the foreign side that is called with the given user data
+/// when a pending [`FutureBox`] should be polled again.
+/// It may be called from any thread, for example to signal a semaphore with a given index.
+pub type FutureWakeCallback = extern "C" fn(*mut c_void);
+
+/// The state of a [`FutureBox`] after it was polled.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[repr(u8)]
+pub enum FutureBoxState {
+    /// The future is not complete, it will wake up the registered waker once it can make progress
+    Pending = 0,
+    /// The future is complete and its result can be taken
+    Ready = 1,
+    /// The result of the future was already taken
+    Taken = 2,
+}
+
+type BoxedFuture = Pin<Box<dyn Future<Output = Box<dyn Any>>>>;
+
+/// A Rust future that is driven by the foreign side, without the need for an async runtime.
+/// The host registers a waker, polls the future until it is ready and takes its result
+/// as a box of the output type.
+pub struct FutureBox {
+    future: Option<BoxedFuture>,
+    result: Option<Box<dyn Any>>,
+    into_raw: fn(Box<dyn Any>) -> *mut ValueBox<c_void>,
+    output_type_name: &'static str,
+    waker: Waker,
+}
+
+impl FutureBox {
+    pub fn new<T: Any>(future: impl Future<Output = T> + 'static) -> Self {
+        Self {
+            future: Some(Box::pin(
+                async move { Box::new(future.await) as Box<dyn Any> },
+            )),
+            result: None,
+            into_raw: erased_into_raw::<T>,
+            output_type_name: type_name::<T>(),
+            waker: Waker::noop().clone(),
+        }
+    }
+
+    /// Use a given waker for the next polls of the future.
+    /// It should be set before the future is polled for the first time.
+    pub fn set_waker(&mut self, waker: Waker) {
+        self.waker = waker;
+    }
+
+    /// Call a given foreign function with the user data when the future should be polled again.
+    pub fn set_wake_callback(&mut self, callback: FutureWakeCallback, user_data: *mut c_void) {
+        self.set_waker(Waker::from(Arc::new(ForeignWaker {
+            callback,
+            user_data,
+        })));
+    }
+
+    /// Poll the future once, unless it is already complete.
+    pub fn poll(&mut self) -> FutureBoxState {
+        if let Some(future) = self.future.as_mut() {
+            let mut context = Context::from_waker(&self.waker);
+            if let Poll::Ready(result) = future.as_mut().poll(&mut context) {
+                self.future = None;
+                self.result = Some(result);
+            }
+        }
+        self.state()
+    }
+
+    pub fn state(&self) -> FutureBoxState {
+        if self.future.is_some() {
+            FutureBoxState::Pending
+        } else if self.result.is_some() {
+            FutureBoxState::Ready
+        } else {
+            FutureBoxState::Taken
+        }
+    }
+
+    pub fn is_ready(&self) -> bool {
+        self.state() == FutureBoxState::Ready
+    }
+
+    /// Take the result of a complete future.
+    pub fn take_result<T: Any>(&mut self) -> Result<T> {
+        let result = self.take_any_result()?;
+        result
+            .downcast::<T>()
+            .map(|result| *result)
+            .map_err(|result| {
+                // put the result back so that it can be taken with the right type
+                self.result = Some(result);
+                BoxerError::WrongType {
+                    expected: type_name::<T>().to_string(),
+                    actual: self.output_type_name.to_string(),
+                }
+            })
+    }
+
+    /// Take the result of a complete future as a box of the output type,
+    /// which must be released by the caller.
+    pub fn take_result_box(&mut self) -> Result<*mut ValueBox<c_void>> {
+        let result = self.take_any_result()?;
+        Ok((self.into_raw)(result))
+    }
+
+    fn take_any_result(&mut self) -> Result<Box<dyn Any>> {
+        self.result
+            .take()
+            .ok_or_else(|| BoxerError::NoValue(self.output_type_name.to_string()))
+    }
+}
+
+fn erased_into_raw<T: Any>(value: Box<dyn Any>) -> *mut ValueBox<c_void> {
+    let value = value.downcast::<T>().unwrap_or_else(|_| {
+        unreachable!("The result of the future is of type {}", type_name::<T>())
+    });
+    ValueBox::new(*value).into_raw() as *mut ValueBox<c_void>
+}
+
+struct ForeignWaker {
+    callback: FutureWakeCallback,
+    user_data: *mut c_void,
+}
+
+// the foreign side is responsible for the callback being safe to call from any thread
+unsafe impl Send for ForeignWaker {}
+unsafe impl Sync for ForeignWaker {}
+
+impl Wake for ForeignWaker {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        (self.callback)(self.user_data);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ValueBoxPointer;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// A future that is pending until it was polled a given amount of times
+    struct Countdown {
+        remaining: usize,
+    }
+
+    impl Future for Countdown {
+        type Output = ();
+
+        fn poll(mut self: Pin<&mut Self>, context: &mut Context<'_>) -> Poll<()> {
+            if self.remaining == 0 {
+                Poll::Ready(())
+            } else {
+                self.remaining -= 1;
+                context.waker().wake_by_ref();
+                Poll::Pending
+            }
+        }
+    }
+
+    static WAKE_UPS: AtomicUsize = AtomicUsize::new(0);
+
+    extern "C" fn count_wake_ups(user_data: *mut c_void) {
+        assert!(user_data.is_null());
+        WAKE_UPS.fetch_add(1, Ordering::SeqCst);
+    }
+
+    #[test]
+    fn poll_until_ready() -> Result<()> {
+        let countdown = Countdown { remaining: 2 };
+        let mut future = FutureBox::new(async move {
+            countdown.await;
+            42u32
+        });
+        future.set_wake_callback(count_wake_ups, std::ptr::null_mut());
+
+        assert!(matches!(
+            future.take_result::<u32>(),
+            Err(BoxerError::NoValue(_))
+        ));
+        assert_eq!(future.poll(), FutureBoxState::Pending);
+        assert_eq!(future.poll(), FutureBoxState::Pending);
+        assert_eq!(future.poll(), FutureBoxState::Ready);
+        assert_eq!(WAKE_UPS.load(Ordering::SeqCst), 2);
+
+        assert!(matches!(
+            future.take_result::<i64>(),
+            Err(BoxerError::WrongType { .. })
+        ));
+        let result = future.take_result_box()? as *mut ValueBox<u32>;
+        assert_eq!(result.with_ref_ok(|result| *result)?, 42);
+        assert_eq!(future.state(), FutureBoxState::Taken);
+        result.release();
+        Ok(())
+    }
+}
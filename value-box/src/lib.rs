@@ -7,16 +7,33 @@ extern crate log;
 #[cfg(feature = "phlow")]
 extern crate phlow;
 
+pub use callback_box::*;
 pub use error::*;
+pub use future_box::*;
 
 pub use self::value_box::*;
+#[cfg(feature = "census")]
+pub use self::value_box_census::*;
 use self::value_box_container::*;
+pub use self::value_box_handle::*;
+pub use self::value_box_refs::*;
+#[cfg(feature = "serde")]
+pub use self::value_box_serde::*;
 #[cfg(feature = "phlow")]
 use self::value_box_phlow::*;
 
+mod callback_box;
 mod error;
+mod future_box;
 mod value_box;
+mod value_box_borrow;
+#[cfg(feature = "census")]
+mod value_box_census;
 mod value_box_container;
+mod value_box_handle;
+mod value_box_refs;
+#[cfg(feature = "serde")]
+mod value_box_serde;
 #[cfg(feature = "phlow")]
 mod value_box_phlow;
 
@@ -1,46 +1,204 @@
-use std::any::{type_name, Any};
+use std::any::{type_name, Any, TypeId};
+use std::ffi::c_void;
 use std::fmt::{Debug, Formatter};
-use std::mem::ManuallyDrop;
+use std::sync::{Arc, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard, TryLockError, Weak};
+
+use crate::value_box_borrow::{BorrowFlag, BorrowGuard};
+use crate::value_box_handle::SlotPin;
+use crate::{
+    catch_panic, BoxerError, Result, ReturnBoxerResult, SharedValueBoxContainer, ValueBoxContainer,
+};
+
+/// Describes the type of the value stored in a [`ValueBox`].
+/// It is always the first field of the box, which means it can be read
+/// without knowing the actual type of the boxed value.
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub struct ValueBoxType {
+    type_id: TypeId,
+    type_name: &'static str,
+    operations: &'static ValueBoxOperations,
+}
+
+impl ValueBoxType {
+    pub fn of<T: Any>() -> Self {
+        Self {
+            type_id: TypeId::of::<T>(),
+            type_name: type_name::<T>(),
+            operations: &<T as TypedValueBoxOperations>::OPERATIONS,
+        }
+    }
+
+    pub fn type_id(&self) -> TypeId {
+        self.type_id
+    }
+
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    pub fn is<T: Any>(&self) -> bool {
+        self.type_id == TypeId::of::<T>()
+    }
+}
+
+impl Debug for ValueBoxType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ValueBoxType")
+            .field("type_id", &self.type_id)
+            .field("type_name", &self.type_name)
+            .finish()
+    }
+}
+
+/// Operations on a value box with a type-erased pointer,
+/// monomorphized for the actual type of the boxed value.
+struct ValueBoxOperations {
+    has_value: fn(*const c_void) -> bool,
+    value_ptr: fn(*const c_void) -> *const c_void,
+    retain: fn(*const c_void) -> Result<*mut c_void>,
+    downgrade: fn(*const c_void) -> Result<*mut c_void>,
+    upgrade: fn(*const c_void) -> Result<*mut c_void>,
+}
+
+trait TypedValueBoxOperations {
+    const OPERATIONS: ValueBoxOperations;
+}
+
+impl<T: Any> TypedValueBoxOperations for T {
+    const OPERATIONS: ValueBoxOperations = ValueBoxOperations {
+        has_value: erased_has_value::<T>,
+        value_ptr: erased_value_ptr::<T>,
+        retain: erased_retain::<T>,
+        downgrade: erased_downgrade::<T>,
+        upgrade: erased_upgrade::<T>,
+    };
+}
+
+fn erased_has_value<T: Any>(value_box: *const c_void) -> bool {
+    unsafe { &*(value_box as *const ValueBox<T>) }.has_value()
+}
+
+fn erased_value_ptr<T: Any>(value_box: *const c_void) -> *const c_void {
+    (value_box as *mut ValueBox<T>)
+        .with_ref_ok(|value| value as *const T as *const c_void)
+        .unwrap_or(std::ptr::null())
+}
+
+// only boxes created with `ValueBox::new_shared` of a `Send + Sync` value can be retained,
+// therefore the type-erased operations do not need the bounds
+fn erased_retain<T: Any>(value_box: *const c_void) -> Result<*mut c_void> {
+    (value_box as *mut ValueBox<T>)
+        .to_ref()
+        .and_then(|box_ref| box_ref.retain_shared())
+        .map(|value_box| value_box.into_raw() as *mut c_void)
+}
+
+fn erased_downgrade<T: Any>(value_box: *const c_void) -> Result<*mut c_void> {
+    (value_box as *mut ValueBox<T>)
+        .to_ref()
+        .and_then(|box_ref| box_ref.downgrade_shared())
+        .map(|value_box| value_box.into_raw() as *mut c_void)
+}
+
+fn erased_upgrade<T: Any>(value_box: *const c_void) -> Result<*mut c_void> {
+    (value_box as *mut ValueBox<T>)
+        .to_ref()
+        .and_then(|box_ref| box_ref.upgrade_shared())
+        .map(|value_box| value_box.into_raw() as *mut c_void)
+}
 
-use crate::{BoxerError, Result, ReturnBoxerResult, ValueBoxContainer};
+/// The value of a shared box, see [`ValueBox::new_shared`].
+type SharedValue<T> = RwLock<Option<Box<T>>>;
+
+#[repr(C)]
+pub struct ValueBox<T: Any> {
+    value_type: ValueBoxType,
+    value: ValueBoxValue<T>,
+    borrow: BorrowFlag,
+}
 
 #[repr(C, u8)]
-pub enum ValueBox<T: Any> {
+pub(crate) enum ValueBoxValue<T: Any> {
     Value(Option<Box<T>>),
+    Shared(Arc<SharedValue<T>>),
+    Weak(Weak<SharedValue<T>>),
     #[cfg(feature = "phlow")]
     PhlowValue(Box<crate::PhlowValue>),
 }
 
 impl<T: Any> ValueBox<T> {
     pub fn new(object: T) -> Self {
-        Self::Value(Some(Box::new(object)))
+        Self::with_value(ValueBoxValue::Value(Some(Box::new(object))))
     }
 
     #[cfg(feature = "phlow")]
     pub fn new_phlow(object: T, phlow_type_fn: fn() -> phlow::PhlowType) -> Self {
-        Self::PhlowValue(Box::new(crate::PhlowValue::new(object, phlow_type_fn)))
+        Self::with_value(ValueBoxValue::PhlowValue(Box::new(crate::PhlowValue::new(
+            object,
+            phlow_type_fn,
+        ))))
+    }
+
+    /// Create a box whose value can be accessed from multiple threads.
+    /// [`ValueBoxPointer::with_ref`] and [`ValueBoxPointer::with_mut`] acquire
+    /// a read or write lock respectively, while [`ValueBoxPointer::try_with_ref`]
+    /// and [`ValueBoxPointer::try_with_mut`] fail with [`BoxerError::WouldBlock`]
+    /// instead of waiting for the lock.
+    /// The value is reference counted and can be shared by multiple boxes,
+    /// see [`ValueBoxPointer::retain`] and [`ValueBoxPointer::downgrade`].
+    pub fn new_shared(object: T) -> Self
+    where
+        T: Send + Sync,
+    {
+        Self::with_value(ValueBoxValue::Shared(Arc::new(RwLock::new(Some(
+            Box::new(object),
+        )))))
     }
 
     pub fn null() -> Self {
-        Self::Value(None)
+        Self::with_value(ValueBoxValue::Value(None))
+    }
+
+    fn with_value(value: ValueBoxValue<T>) -> Self {
+        Self {
+            value_type: ValueBoxType::of::<T>(),
+            value,
+            borrow: BorrowFlag::default(),
+        }
+    }
+
+    pub fn value_type(&self) -> &ValueBoxType {
+        &self.value_type
+    }
+
+    /// Return true if the access to the value is synchronised, see [`ValueBox::new_shared`].
+    pub fn is_shared(&self) -> bool {
+        matches!(
+            self.value,
+            ValueBoxValue::Shared(_) | ValueBoxValue::Weak(_)
+        )
+    }
+
+    /// Return true if the box does not keep its value alive, see [`ValueBoxPointer::downgrade`].
+    pub fn is_weak(&self) -> bool {
+        matches!(self.value, ValueBoxValue::Weak(_))
     }
 
     pub fn has_value(&self) -> bool {
-        match self {
-            Self::Value(value) => value.has_value(),
+        match &self.value {
+            ValueBoxValue::Value(value) => value.has_value(),
+            ValueBoxValue::Shared(value) => value.has_value(),
+            ValueBoxValue::Weak(value) => value.has_value(),
             #[cfg(feature = "phlow")]
-            Self::PhlowValue(value) => {
+            ValueBoxValue::PhlowValue(value) => {
                 <crate::PhlowValue as ValueBoxContainer<T>>::has_value(value)
             }
         }
     }
 
     pub fn replace_value(&mut self, object: T) -> Option<T> {
-        match self {
-            Self::Value(value) => value.replace_value(object),
-            #[cfg(feature = "phlow")]
-            Self::PhlowValue(value) => value.replace_value(object),
-        }
+        self.value.replace_value(object)
     }
 
     pub fn set_value(&mut self, object: T) {
@@ -51,23 +209,46 @@ impl<T: Any> ValueBox<T> {
     where
         T: Clone,
     {
-        match self {
-            Self::Value(value) => value.clone_value(),
+        match &self.value {
+            ValueBoxValue::Value(value) => value.clone_value(),
+            ValueBoxValue::Shared(value) => value.clone_value(),
+            ValueBoxValue::Weak(value) => value.clone_value(),
             #[cfg(feature = "phlow")]
-            Self::PhlowValue(value) => value.clone_value(),
+            ValueBoxValue::PhlowValue(value) => value.clone_value(),
         }
     }
 
     pub fn take_value(&mut self) -> Option<T> {
+        self.value.take_value()
+    }
+
+    pub fn into_raw(self) -> *mut Self {
+        let pointer = into_raw(Box::new(self));
+        #[cfg(feature = "census")]
+        crate::census_record(pointer as *const c_void, type_name::<T>());
+        pointer
+    }
+}
+
+impl<T: Any> ValueBoxValue<T> {
+    fn replace_value(&mut self, object: T) -> Option<T> {
         match self {
-            Self::Value(value) => value.take_value(),
+            ValueBoxValue::Value(value) => value.replace_value(object),
+            ValueBoxValue::Shared(value) => value.replace_value(object),
+            ValueBoxValue::Weak(value) => value.replace_value(object),
             #[cfg(feature = "phlow")]
-            Self::PhlowValue(value) => value.take_value(),
+            ValueBoxValue::PhlowValue(value) => value.replace_value(object),
         }
     }
 
-    pub fn into_raw(self) -> *mut Self {
-        into_raw(Box::new(self))
+    fn take_value(&mut self) -> Option<T> {
+        match self {
+            ValueBoxValue::Value(value) => value.take_value(),
+            ValueBoxValue::Shared(value) => value.take_value(),
+            ValueBoxValue::Weak(value) => value.take_value(),
+            #[cfg(feature = "phlow")]
+            ValueBoxValue::PhlowValue(value) => value.take_value(),
+        }
     }
 }
 
@@ -77,56 +258,304 @@ impl<T: 'static> ValueBox<T> {
     /// Note: By definition `PhlowObject` owns the value, therefore
     /// internally we change the storage container for the value to `PhlowObject`.
     pub fn phlow_object(&mut self) -> Option<phlow::PhlowObject> {
-        match self {
-            Self::Value(_) => None,
-            Self::PhlowValue(value) => value.phlow_object(),
+        match &mut self.value {
+            ValueBoxValue::Value(_) | ValueBoxValue::Shared(_) | ValueBoxValue::Weak(_) => None,
+            ValueBoxValue::PhlowValue(value) => value.phlow_object(),
         }
     }
 }
 
-#[repr(transparent)]
+/// A reference to a box behind a raw pointer. The box is only accessed by a shared reference,
+/// except for the value of a box that is not shared while it is borrowed mutably.
 pub struct BoxRef<T: Any> {
-    value_box: ManuallyDrop<Box<ValueBox<T>>>,
+    value_box: *mut ValueBox<T>,
+    /// Keeps the box registered behind a handle alive while it is accessed
+    pin: Option<SlotPin>,
 }
 
 impl<T: Any> BoxRef<T> {
+    pub(crate) fn pinned(mut self, pin: SlotPin) -> Self {
+        self.pin = Some(pin);
+        self
+    }
+
+    fn value_box(&self) -> &ValueBox<T> {
+        unsafe { &*self.value_box }
+    }
+
+    /// The borrow flag is only ever accessed by a shared reference
+    /// and the box outlives any borrow of it, see [`BoxRef`]'s `Drop`.
+    fn borrow_flag<'flag>(&self) -> &'flag BorrowFlag {
+        unsafe { &(*self.value_box).borrow }
+    }
+
+    /// Borrow a box mutably, the value of a shared box is then locked for writing by the caller.
+    fn borrow_value_mut<'flag>(&self) -> Result<BorrowGuard<'flag>> {
+        if self.value_box().is_shared() {
+            self.borrow_shared(true)
+        } else {
+            self.borrow_flag().borrow_mut::<T>()
+        }
+    }
+
+    /// Borrow a shared box, so that it is not freed while the caller locks its value.
+    fn borrow_shared<'flag>(&self, mutably: bool) -> Result<BorrowGuard<'flag>> {
+        let value = match &self.value_box().value {
+            ValueBoxValue::Shared(value) => Arc::as_ptr(value),
+            ValueBoxValue::Weak(value) => Weak::as_ptr(value),
+            _ => return BoxerError::NotShared(type_name::<T>().to_string()).into(),
+        };
+        self.borrow_flag()
+            .borrow_shared::<T>(value as *const (), mutably)
+    }
+
+    /// The value of a box that is not shared is never accessed by multiple threads,
+    /// a mutable reference to it is therefore only aliased by the reentrant borrows
+    /// that the borrow flag prevents.
+    fn unshared_value(&mut self) -> &mut ValueBoxValue<T> {
+        debug_assert!(!self.value_box().is_shared());
+        unsafe { &mut (*self.value_box).value }
+    }
+
     pub fn with_ref<R>(&self, op: impl FnOnce(&T) -> Result<R>) -> Result<R> {
-        match self.value_box.as_ref() {
-            ValueBox::Value(value) => op(value.as_ref().unwrap()),
-            #[cfg(feature = "phlow")]
-            ValueBox::PhlowValue(value) => match value.as_ref() {
-                crate::PhlowValue::Lazy(value) => {
-                    let value = &value.value;
-                    op(value.as_ref_safe::<T>().unwrap())
+        catch_panic(|| {
+            let value_box = self.value_box();
+            match &value_box.value {
+                ValueBoxValue::Value(value) => {
+                    let _borrow = value_box.borrow.borrow::<T>()?;
+                    op(value.as_ref().unwrap())
                 }
-                crate::PhlowValue::Object(object) => {
-                    let value = object.value_ref::<T>().unwrap();
-                    op(&value)
+                ValueBoxValue::Shared(value) => {
+                    let borrow = self.borrow_shared(false)?;
+                    Self::read_shared(value, &borrow, op)
                 }
-            },
-        }
+                ValueBoxValue::Weak(value) => {
+                    let borrow = self.borrow_shared(false)?;
+                    Self::read_shared(&Self::upgrade_weak(value)?, &borrow, op)
+                }
+                #[cfg(feature = "phlow")]
+                ValueBoxValue::PhlowValue(value) => {
+                    let _borrow = value_box.borrow.borrow::<T>()?;
+                    match value.as_ref() {
+                        crate::PhlowValue::Lazy(value) => {
+                            let value = &value.value;
+                            op(value.as_ref_safe::<T>().unwrap())
+                        }
+                        crate::PhlowValue::Object(object) => {
+                            let value = object.value_ref::<T>().unwrap();
+                            op(&value)
+                        }
+                    }
+                }
+            }
+        })
     }
 
     pub fn with_mut<R>(&mut self, op: impl FnOnce(&mut T) -> Result<R>) -> Result<R> {
-        match self.value_box.as_mut() {
-            ValueBox::Value(value) => value
-                .as_mut()
-                .ok_or_else(|| BoxerError::NoValue(type_name::<T>().to_string()))
-                .and_then(|value| op(value)),
-            #[cfg(feature = "phlow")]
-            ValueBox::PhlowValue(value) => match value.as_mut() {
-                crate::PhlowValue::Lazy(value) => value
-                    .value
-                    .as_mut_safe::<T>()
+        catch_panic(|| {
+            match &self.value_box().value {
+                ValueBoxValue::Shared(value) => {
+                    let _borrow = self.borrow_shared(true)?;
+                    return Self::write_shared(value, op);
+                }
+                ValueBoxValue::Weak(value) => {
+                    let _borrow = self.borrow_shared(true)?;
+                    return Self::write_shared(&Self::upgrade_weak(value)?, op);
+                }
+                _ => {}
+            }
+
+            let _borrow = self.borrow_flag().borrow_mut::<T>()?;
+            match self.unshared_value() {
+                ValueBoxValue::Value(value) => value
+                    .as_mut()
                     .ok_or_else(|| BoxerError::NoValue(type_name::<T>().to_string()))
                     .and_then(|value| op(value)),
-                crate::PhlowValue::Object(object) => {
-                    let mut value = object.value_mut::<T>().unwrap();
-                    op(&mut value)
-                }
-            },
+                ValueBoxValue::Shared(_) | ValueBoxValue::Weak(_) => unreachable!(),
+                #[cfg(feature = "phlow")]
+                ValueBoxValue::PhlowValue(value) => match value.as_mut() {
+                    crate::PhlowValue::Lazy(value) => value
+                        .value
+                        .as_mut_safe::<T>()
+                        .ok_or_else(|| BoxerError::NoValue(type_name::<T>().to_string()))
+                        .and_then(|value| op(value)),
+                    crate::PhlowValue::Object(object) => {
+                        let mut value = object.value_mut::<T>().unwrap();
+                        op(&mut value)
+                    }
+                },
+            }
+        })
+    }
+}
+
+impl<T: Any> BoxRef<T> {
+    /// Same as [`BoxRef::with_ref`], but fails with [`BoxerError::WouldBlock`]
+    /// if a shared box is locked for writing.
+    pub fn try_with_ref<R>(&self, op: impl FnOnce(&T) -> Result<R>) -> Result<R> {
+        match &self.value_box().value {
+            ValueBoxValue::Shared(value) => {
+                let _borrow = self.borrow_shared(false)?;
+                Self::try_read_shared(value, op)
+            }
+            ValueBoxValue::Weak(value) => {
+                let _borrow = self.borrow_shared(false)?;
+                Self::try_read_shared(&Self::upgrade_weak(value)?, op)
+            }
+            _ => self.with_ref(op),
+        }
+    }
+
+    /// Same as [`BoxRef::with_mut`], but fails with [`BoxerError::WouldBlock`]
+    /// if a shared box is locked by another thread.
+    pub fn try_with_mut<R>(&mut self, op: impl FnOnce(&mut T) -> Result<R>) -> Result<R> {
+        match &self.value_box().value {
+            ValueBoxValue::Shared(value) => {
+                let _borrow = self.borrow_shared(true)?;
+                Self::try_write_shared(value, op)
+            }
+            ValueBoxValue::Weak(value) => {
+                let _borrow = self.borrow_shared(true)?;
+                Self::try_write_shared(&Self::upgrade_weak(value)?, op)
+            }
+            _ => self.with_mut(op),
+        }
+    }
+
+    /// Create another strong box sharing the value with this one.
+    /// The value is dropped when the last strong box is released.
+    /// Only boxes created with [`ValueBox::new_shared`] can be retained,
+    /// other boxes fail with [`BoxerError::NotShared`].
+    /// A weak box is upgraded, which fails with [`BoxerError::NoValue`] if the value is gone.
+    pub fn retain(&self) -> Result<ValueBox<T>>
+    where
+        T: Send + Sync,
+    {
+        self.retain_shared()
+    }
+
+    /// Create a weak box that refers to the value without keeping it alive.
+    /// Only boxes created with [`ValueBox::new_shared`] can be downgraded,
+    /// other boxes fail with [`BoxerError::NotShared`].
+    pub fn downgrade(&self) -> Result<ValueBox<T>>
+    where
+        T: Send + Sync,
+    {
+        self.downgrade_shared()
+    }
+
+    /// Create a strong box from a weak one. Fails with [`BoxerError::NotWeak`]
+    /// if the box is not weak and with [`BoxerError::NoValue`] if the value is gone.
+    pub fn upgrade(&self) -> Result<ValueBox<T>>
+    where
+        T: Send + Sync,
+    {
+        self.upgrade_shared()
+    }
+
+    // the value of a shared box is `Send + Sync`, see `ValueBox::new_shared`
+    fn retain_shared(&self) -> Result<ValueBox<T>> {
+        match &self.value_box().value {
+            ValueBoxValue::Shared(value) => Ok(value.clone()),
+            ValueBoxValue::Weak(value) => Self::upgrade_weak(value),
+            _ => BoxerError::NotShared(type_name::<T>().to_string()).into(),
+        }
+        .map(|value| ValueBox::with_value(ValueBoxValue::Shared(value)))
+    }
+
+    fn downgrade_shared(&self) -> Result<ValueBox<T>> {
+        match &self.value_box().value {
+            ValueBoxValue::Shared(value) => Ok(Arc::downgrade(value)),
+            ValueBoxValue::Weak(value) => Ok(value.clone()),
+            _ => BoxerError::NotShared(type_name::<T>().to_string()).into(),
+        }
+        .map(|value| ValueBox::with_value(ValueBoxValue::Weak(value)))
+    }
+
+    fn upgrade_shared(&self) -> Result<ValueBox<T>> {
+        match &self.value_box().value {
+            ValueBoxValue::Weak(value) => Self::upgrade_weak(value),
+            _ => BoxerError::NotWeak(type_name::<T>().to_string()).into(),
+        }
+        .map(|value| ValueBox::with_value(ValueBoxValue::Shared(value)))
+    }
+
+    fn upgrade_weak(value: &Weak<SharedValue<T>>) -> Result<Arc<SharedValue<T>>> {
+        value
+            .upgrade()
+            .ok_or_else(|| BoxerError::NoValue(type_name::<T>().to_string()))
+    }
+
+    fn read_shared<R>(
+        value: &Arc<SharedValue<T>>,
+        borrow: &BorrowGuard,
+        op: impl FnOnce(&T) -> Result<R>,
+    ) -> Result<R> {
+        // waiting for a nested read lock would deadlock with a writer waiting for the outer one
+        if borrow.is_nested() {
+            return Self::try_read_shared(value, op);
+        }
+        let guard = value.read().unwrap_or_else(PoisonError::into_inner);
+        Self::with_shared_ref(guard, op)
+    }
+
+    fn write_shared<R>(
+        value: &Arc<SharedValue<T>>,
+        op: impl FnOnce(&mut T) -> Result<R>,
+    ) -> Result<R> {
+        let guard = value.write().unwrap_or_else(PoisonError::into_inner);
+        Self::with_shared_mut(guard, op)
+    }
+
+    fn try_read_shared<R>(
+        value: &Arc<SharedValue<T>>,
+        op: impl FnOnce(&T) -> Result<R>,
+    ) -> Result<R> {
+        match value.try_read() {
+            Ok(guard) => Self::with_shared_ref(guard, op),
+            Err(TryLockError::Poisoned(poisoned)) => {
+                Self::with_shared_ref(poisoned.into_inner(), op)
+            }
+            Err(TryLockError::WouldBlock) => {
+                BoxerError::WouldBlock(type_name::<T>().to_string()).into()
+            }
+        }
+    }
+
+    fn try_write_shared<R>(
+        value: &Arc<SharedValue<T>>,
+        op: impl FnOnce(&mut T) -> Result<R>,
+    ) -> Result<R> {
+        match value.try_write() {
+            Ok(guard) => Self::with_shared_mut(guard, op),
+            Err(TryLockError::Poisoned(poisoned)) => {
+                Self::with_shared_mut(poisoned.into_inner(), op)
+            }
+            Err(TryLockError::WouldBlock) => {
+                BoxerError::WouldBlock(type_name::<T>().to_string()).into()
+            }
         }
     }
+
+    fn with_shared_ref<R>(
+        guard: RwLockReadGuard<Option<Box<T>>>,
+        op: impl FnOnce(&T) -> Result<R>,
+    ) -> Result<R> {
+        guard
+            .as_deref()
+            .ok_or_else(|| BoxerError::NoValue(type_name::<T>().to_string()))
+            .and_then(op)
+    }
+
+    fn with_shared_mut<R>(
+        mut guard: RwLockWriteGuard<Option<Box<T>>>,
+        op: impl FnOnce(&mut T) -> Result<R>,
+    ) -> Result<R> {
+        guard
+            .as_deref_mut()
+            .ok_or_else(|| BoxerError::NoValue(type_name::<T>().to_string()))
+            .and_then(op)
+    }
 }
 
 impl<T: Any> Debug for BoxRef<T> {
@@ -134,7 +563,7 @@ impl<T: Any> Debug for BoxRef<T> {
         f.debug_struct("BoxRef")
             .field(
                 "value",
-                if self.value_box.has_value() {
+                if self.value_box().has_value() {
                     &"Some"
                 } else {
                     &"None"
@@ -145,12 +574,45 @@ impl<T: Any> Debug for BoxRef<T> {
 }
 
 impl<T: Any> BoxRef<T> {
-    pub fn replace(&mut self, value: T) -> Option<T> {
-        self.value_box.replace_value(value)
+    /// Replace the value of the box, failing with [`BoxerError::AlreadyBorrowed`]
+    /// if the box or the value of a shared box is borrowed by the current thread.
+    pub fn replace(&mut self, value: T) -> Result<Option<T>> {
+        let _borrow = self.borrow_value_mut()?;
+        Ok(self.replace_borrowed(value))
     }
 
-    pub fn take_value(&mut self) -> Option<T> {
-        self.value_box.take_value()
+    /// Take the value out of the box, failing with [`BoxerError::AlreadyBorrowed`]
+    /// if the box or the value of a shared box is borrowed by the current thread.
+    pub fn take_value(&mut self) -> Result<Option<T>> {
+        let _borrow = self.borrow_value_mut()?;
+        Ok(self.take_borrowed())
+    }
+
+    /// The caller must hold a mutable borrow of the box, see `BoxRef::borrow_value_mut`.
+    fn replace_borrowed(&mut self, value: T) -> Option<T> {
+        match &self.value_box().value {
+            ValueBoxValue::Shared(shared) => shared.replace_value(value),
+            ValueBoxValue::Weak(weak) => weak.replace_value(value),
+            _ => self.unshared_value().replace_value(value),
+        }
+    }
+
+    /// The caller must hold a mutable borrow of the box, see `BoxRef::borrow_value_mut`.
+    fn take_borrowed(&mut self) -> Option<T> {
+        match &self.value_box().value {
+            ValueBoxValue::Shared(shared) => shared.take_value(),
+            ValueBoxValue::Weak(weak) => weak.take_value(),
+            _ => self.unshared_value().take_value(),
+        }
+    }
+}
+
+impl<T: Any> Drop for BoxRef<T> {
+    fn drop(&mut self) {
+        // the box was released while it was borrowed, the borrows have ended since
+        if self.value_box().borrow.claim_released() {
+            unsafe { free_box(self.value_box) }
+        }
     }
 }
 
@@ -180,7 +642,8 @@ pub trait ValueBoxPointer<T: Any> {
     {
         match self.to_ref() {
             Ok(value) => value.with_ref(|value| op(Some(value))),
-            Err(_) => op(None),
+            Err(BoxerError::NullPointer(_)) => catch_panic(|| op(None)),
+            Err(error) => Err(error),
         }
     }
 
@@ -202,6 +665,24 @@ pub trait ValueBoxPointer<T: Any> {
         self.to_ref()?.with_mut(op)
     }
 
+    /// Same as [`ValueBoxPointer::with_ref`], but if the box is shared and another thread
+    /// is mutating the value, return [`BoxerError::WouldBlock`] instead of waiting.
+    fn try_with_ref<R: Any, F>(&self, op: F) -> Result<R>
+    where
+        F: FnOnce(&T) -> Result<R>,
+    {
+        self.to_ref()?.try_with_ref(op)
+    }
+
+    /// Same as [`ValueBoxPointer::with_mut`], but if the box is shared and another thread
+    /// is accessing the value, return [`BoxerError::WouldBlock`] instead of waiting.
+    fn try_with_mut<R: Any, F>(&self, op: F) -> Result<R>
+    where
+        F: FnOnce(&mut T) -> Result<R>,
+    {
+        self.to_ref()?.try_with_mut(op)
+    }
+
     /// Evaluate a given function that can not fail with a mutable reference to the boxed value.
     /// The lifetime of the reference can not outlive the closure.
     fn with_mut_ok<R: Any, F>(&self, op: F) -> Result<R>
@@ -233,6 +714,7 @@ pub trait ValueBoxPointer<T: Any> {
 
     /// Evaluate a given function with references to given boxed values.
     /// The lifetime of the reference can not outlive the closure.
+    #[deprecated(since = "3.0.0", note = "please use `ValueBoxRefs::with_refs` instead")]
     fn with_ref_ref<R: Any, F, P: Any>(&self, ptr: *mut ValueBox<P>, op: F) -> Result<R>
     where
         F: FnOnce(&T, &P) -> Result<R>,
@@ -242,6 +724,7 @@ pub trait ValueBoxPointer<T: Any> {
 
     /// Evaluate a given function with references to given boxed values.
     /// The lifetime of the reference can not outlive the closure.
+    #[deprecated(since = "3.0.0", note = "please use `ValueBoxRefs::with_refs` instead")]
     fn with_ref_ref_ref<R: Any, F, P1: Any, P2: Any>(
         &self,
         ptr1: *mut ValueBox<P1>,
@@ -256,6 +739,7 @@ pub trait ValueBoxPointer<T: Any> {
 
     /// Evaluate a given function with references to given boxed values.
     /// The lifetime of the reference can not outlive the closure.
+    #[deprecated(since = "3.0.0", note = "please use `ValueBoxRefs::with_refs` instead")]
     fn with_ref_ref_ref_ref<R: Any, F, P1: Any, P2: Any, P3: Any>(
         &self,
         ptr1: *mut ValueBox<P1>,
@@ -271,6 +755,34 @@ pub trait ValueBoxPointer<T: Any> {
         })
     }
 
+    /// Create another box sharing the value of a box created with [`ValueBox::new_shared`].
+    /// The value is dropped once all strong boxes sharing it are released.
+    /// See [`BoxRef::retain`].
+    fn retain(&self) -> Result<ValueBox<T>>
+    where
+        T: Send + Sync,
+    {
+        self.to_ref()?.retain()
+    }
+
+    /// Create a weak box that refers to the value without keeping it alive.
+    /// See [`BoxRef::downgrade`].
+    fn downgrade(&self) -> Result<ValueBox<T>>
+    where
+        T: Send + Sync,
+    {
+        self.to_ref()?.downgrade()
+    }
+
+    /// Create a strong box from a weak one, or fail with [`BoxerError::NoValue`]
+    /// if all strong boxes were released. See [`BoxRef::upgrade`].
+    fn upgrade(&self) -> Result<ValueBox<T>>
+    where
+        T: Send + Sync,
+    {
+        self.to_ref()?.upgrade()
+    }
+
     /// Evaluate a given function with the value taken out of the box
     /// and place the new value back. The value returned by the function
     /// must be of the same type as the box
@@ -279,11 +791,13 @@ pub trait ValueBoxPointer<T: Any> {
         F: FnOnce(T) -> T,
     {
         self.to_ref().and_then(|mut t| {
-            t.take_value()
+            // the box stays borrowed while its value is out, so that it is not released meanwhile
+            let _borrow = t.borrow_value_mut()?;
+            t.take_borrowed()
                 .ok_or_else(|| BoxerError::NoValue(type_name::<T>().to_string()))
-                .map(|previous_value| {
-                    let new_value = op(previous_value);
-                    t.replace(new_value);
+                .and_then(|previous_value| catch_panic(|| Ok(op(previous_value))))
+                .map(|new_value| {
+                    t.replace_borrowed(new_value);
                 })
         })
     }
@@ -356,35 +870,125 @@ pub trait ValueBoxPointer<T: Any> {
 
 impl<T: Any> ValueBoxPointer<T> for *mut ValueBox<T> {
     fn to_ref(&self) -> Result<BoxRef<T>> {
-        if self.is_null() {
-            return BoxerError::NullPointer(type_name::<T>().to_string()).into();
-        }
-        let value_box = ManuallyDrop::new(unsafe { from_raw(*self) });
+        let box_ref = BoxRef {
+            value_box: checked_pointer(*self)?,
+            pin: None,
+        };
 
-        if value_box.has_value() {
-            Ok(BoxRef { value_box })
+        if box_ref.value_box().has_value() {
+            Ok(box_ref)
         } else {
             BoxerError::NoValue(type_name::<T>().to_string()).into()
         }
     }
 
     fn take_value(&self) -> Result<T> {
+        let mut box_ref = BoxRef {
+            value_box: checked_pointer(*self)?,
+            pin: None,
+        };
+        box_ref
+            .take_value()?
+            .ok_or(BoxerError::NoValue(type_name::<T>().to_string()))
+    }
+
+    fn release(self) {
+        checked_pointer(self)
+            .map(|pointer| {
+                // a borrowed box is freed once the borrow ends, see `BoxRef`'s `Drop`
+                if unsafe { &*pointer }.borrow.release() {
+                    unsafe { free_box(pointer) }
+                }
+            })
+            .log();
+    }
+}
+
+/// Drop a box that is neither borrowed nor referenced anymore.
+unsafe fn free_box<T: Any>(pointer: *mut ValueBox<T>) {
+    #[cfg(feature = "census")]
+    crate::census_forget(pointer as *const c_void);
+    drop(from_raw(pointer));
+}
+
+/// Make sure that the pointer is not null and that the box holds a value of type `T`.
+fn checked_pointer<T: Any>(pointer: *mut ValueBox<T>) -> Result<*mut ValueBox<T>> {
+    let value_type = pointer.value_type()?;
+    if value_type.is::<T>() {
+        Ok(pointer)
+    } else {
+        BoxerError::WrongType {
+            expected: type_name::<T>().to_string(),
+            actual: value_type.type_name().to_string(),
+        }
+        .into()
+    }
+}
+
+/// Operations on a pointer to a value box that do not depend on the type of the boxed value.
+/// They only read the [`ValueBoxType`] stored in the box and are therefore safe to use
+/// when the static type of the pointer is unknown, for example `*mut ValueBox<c_void>`.
+pub trait AnyValueBoxPointer {
+    /// Get the type of the value that was put in the box.
+    fn value_type(&self) -> Result<ValueBoxType>;
+
+    /// Return true if the pointer is not null and the box still holds a value.
+    fn has_any_value(&self) -> bool;
+
+    /// Get the address of the boxed value or null if there is no value.
+    fn get_ptr(&self) -> *const c_void;
+
+    /// Create another box sharing the value, see [`ValueBoxPointer::retain`].
+    fn retain_any(&self) -> Result<Self>
+    where
+        Self: Sized;
+
+    /// Create a weak box referring to the value, see [`ValueBoxPointer::downgrade`].
+    fn downgrade_any(&self) -> Result<Self>
+    where
+        Self: Sized;
+
+    /// Create a strong box from a weak one, see [`ValueBoxPointer::upgrade`].
+    fn upgrade_any(&self) -> Result<Self>
+    where
+        Self: Sized;
+}
+
+impl<T: Any> AnyValueBoxPointer for *mut ValueBox<T> {
+    fn value_type(&self) -> Result<ValueBoxType> {
         if self.is_null() {
             return BoxerError::NullPointer(type_name::<T>().to_string()).into();
         }
-        let mut value_box = ManuallyDrop::new(unsafe { from_raw(*self) });
-        value_box
-            .take_value()
-            .ok_or(BoxerError::NoValue(type_name::<T>().to_string()))
+        // the type is the first field of the `#[repr(C)]` box,
+        // so its location does not depend on `T`
+        Ok(unsafe { *(*self as *const ValueBoxType) })
     }
 
-    fn release(self) {
-        let result = if self.is_null() {
-            BoxerError::NullPointer(type_name::<T>().to_string()).into()
-        } else {
-            unsafe { Ok(from_raw(self)) }
-        };
-        result.log();
+    fn has_any_value(&self) -> bool {
+        self.value_type()
+            .map(|value_type| (value_type.operations.has_value)(*self as *const c_void))
+            .unwrap_or(false)
+    }
+
+    fn get_ptr(&self) -> *const c_void {
+        self.value_type()
+            .map(|value_type| (value_type.operations.value_ptr)(*self as *const c_void))
+            .unwrap_or(std::ptr::null())
+    }
+
+    fn retain_any(&self) -> Result<Self> {
+        let value_type = self.value_type()?;
+        (value_type.operations.retain)(*self as *const c_void).map(|pointer| pointer as Self)
+    }
+
+    fn downgrade_any(&self) -> Result<Self> {
+        let value_type = self.value_type()?;
+        (value_type.operations.downgrade)(*self as *const c_void).map(|pointer| pointer as Self)
+    }
+
+    fn upgrade_any(&self) -> Result<Self> {
+        let value_type = self.value_type()?;
+        (value_type.operations.upgrade)(*self as *const c_void).map(|pointer| pointer as Self)
     }
 }
 
@@ -492,6 +1096,140 @@ mod test {
         value_box_ptr.release();
     }
 
+    #[test]
+    fn value_box_wrong_type() {
+        let ptr = ValueBox::new(42u32).into_raw();
+        let wrong_ptr = ptr as *mut ValueBox<String>;
+
+        assert!(matches!(
+            wrong_ptr.with_ref_ok(|value| value.len()),
+            Err(BoxerError::WrongType { .. })
+        ));
+        assert!(matches!(
+            wrong_ptr.take_value(),
+            Err(BoxerError::WrongType { .. })
+        ));
+        assert!(matches!(
+            wrong_ptr.with_option_ref(|value| Ok(value.is_some())),
+            Err(BoxerError::WrongType { .. })
+        ));
+        let null_ptr: *mut ValueBox<String> = std::ptr::null_mut();
+        assert!(!null_ptr
+            .with_option_ref(|value| Ok(value.is_some()))
+            .unwrap());
+        // releasing with the wrong type must not drop the value
+        wrong_ptr.release();
+
+        assert_eq!(ptr.with_ref_ok(|value| *value).unwrap(), 42);
+        ptr.release();
+    }
+
+    #[test]
+    fn value_box_any_pointer() {
+        let ptr = ValueBox::new(42u32).into_raw();
+        let void_ptr = ptr as *mut ValueBox<c_void>;
+
+        let value_type = void_ptr.value_type().unwrap();
+        assert!(value_type.is::<u32>());
+        assert_eq!(value_type.type_name(), "u32");
+        assert!(void_ptr.has_any_value());
+        assert_eq!(unsafe { *(void_ptr.get_ptr() as *const u32) }, 42);
+
+        ptr.take_value().unwrap();
+        assert!(!void_ptr.has_any_value());
+        assert!(void_ptr.get_ptr().is_null());
+        ptr.release();
+    }
+
+    #[test]
+    fn value_box_shared_between_threads() {
+        let ptr = ValueBox::new_shared(0usize).into_raw();
+        let address = ptr as usize;
+
+        let threads = (0..4)
+            .map(|_| {
+                std::thread::spawn(move || {
+                    let ptr = address as *mut ValueBox<usize>;
+                    for _ in 0..1000 {
+                        ptr.with_mut_ok(|value| *value += 1).unwrap();
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+        for thread in threads {
+            thread.join().unwrap();
+        }
+
+        assert_eq!(ptr.with_ref_ok(|value| *value).unwrap(), 4000);
+        ptr.release();
+    }
+
+    #[test]
+    fn value_box_shared_would_block() {
+        let ptr = ValueBox::new_shared(42).into_raw();
+
+        let result = ptr.with_ref(|_| {
+            let address = ptr as usize;
+            std::thread::spawn(move || {
+                let ptr = address as *mut ValueBox<i32>;
+                (
+                    ptr.try_with_ref(|value| Ok(*value)).ok(),
+                    matches!(
+                        ptr.try_with_mut(|value| Ok(*value)),
+                        Err(BoxerError::WouldBlock(_))
+                    ),
+                )
+            })
+            .join()
+            .map_err(|_| BoxerError::from("Thread panicked"))
+        });
+        assert_eq!(result.unwrap(), (Some(42), true));
+        assert!(matches!(
+            ptr.to_ref().unwrap().try_with_mut(|value| Ok(*value)),
+            Ok(42)
+        ));
+        ptr.release();
+    }
+
+    #[test]
+    fn value_box_retain_and_downgrade() {
+        let value = Arc::new(42);
+
+        let first = ValueBox::new_shared(value.clone()).into_raw();
+        let second = first.retain().unwrap().into_raw();
+        let weak = second.downgrade().unwrap().into_raw();
+        assert_eq!(Arc::strong_count(&value), 2);
+        assert!(matches!(second.upgrade(), Err(BoxerError::NotWeak(_))));
+
+        first.release();
+        assert_eq!(second.with_ref_ok(|value| **value).unwrap(), 42);
+        assert_eq!(weak.with_ref_ok(|value| **value).unwrap(), 42);
+
+        let upgraded = weak.upgrade().unwrap().into_raw();
+        second.release();
+        assert_eq!(Arc::strong_count(&value), 2);
+
+        upgraded.release();
+        assert_eq!(Arc::strong_count(&value), 1);
+        assert!(matches!(weak.upgrade(), Err(BoxerError::NoValue(_))));
+        assert!(!weak.has_value());
+        weak.release();
+    }
+
+    #[test]
+    fn value_box_panic() {
+        let ptr = ValueBox::new(vec![1, 2, 3]).into_raw();
+
+        let result = ptr.with_ref_ok(|vector| vector[5]);
+        assert!(matches!(result, Err(BoxerError::Panic(_))));
+
+        let result = ptr.with_mut_ok(|_| panic!("Oops"));
+        assert!(matches!(result, Err(BoxerError::Panic(message)) if message == "Oops"));
+
+        assert_eq!(ptr.with_ref_ok(|vector| vector.len()).unwrap(), 3);
+        ptr.release();
+    }
+
     #[test]
     fn value_box_drop() {
         let value = Rc::new(42);
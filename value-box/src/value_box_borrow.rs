@@ -0,0 +1,315 @@
+#[cfg(not(feature = "unchecked-borrows"))]
+use std::any::type_name;
+#[cfg(not(feature = "unchecked-borrows"))]
+use std::cell::RefCell;
+#[cfg(not(feature = "unchecked-borrows"))]
+use std::sync::atomic::{AtomicBool, AtomicIsize, Ordering};
+
+#[cfg(not(feature = "unchecked-borrows"))]
+use crate::BoxerError;
+use crate::Result;
+
+#[cfg(not(feature = "unchecked-borrows"))]
+const MUTABLY_BORROWED: isize = -1;
+
+#[cfg(not(feature = "unchecked-borrows"))]
+thread_local! {
+    /// The values of shared boxes locked by the current thread and whether they are locked for writing
+    static LOCKED_VALUES: RefCell<Vec<(usize, bool)>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Keeps track of the borrows of a value that is not shared, similar to a `RefCell`.
+/// The value can be borrowed either by any number of shared references or by one mutable reference,
+/// a reentrant borrow that would break this rule fails with [`BoxerError::AlreadyBorrowed`].
+/// The value of a shared box is guarded by its lock, which the current thread may not lock again
+/// for writing, while the box itself is borrowed by a shared reference for the duration of the lock.
+/// A box that is released while it is borrowed is freed once the borrows end.
+/// With the `unchecked-borrows` feature the borrows are not tracked and a box is freed right away.
+#[derive(Debug, Default)]
+pub(crate) struct BorrowFlag {
+    #[cfg(not(feature = "unchecked-borrows"))]
+    borrows: AtomicIsize,
+    #[cfg(not(feature = "unchecked-borrows"))]
+    released: AtomicBool,
+}
+
+/// Releases the borrow when dropped.
+pub(crate) struct BorrowGuard<'flag> {
+    #[cfg(not(feature = "unchecked-borrows"))]
+    flag: &'flag BorrowFlag,
+    /// The address of the shared value locked by the current thread
+    #[cfg(not(feature = "unchecked-borrows"))]
+    locked_value: Option<usize>,
+    /// The current thread already holds a read lock of the shared value
+    #[cfg(not(feature = "unchecked-borrows"))]
+    nested: bool,
+    #[cfg(feature = "unchecked-borrows")]
+    flag: std::marker::PhantomData<&'flag BorrowFlag>,
+}
+
+#[cfg(not(feature = "unchecked-borrows"))]
+impl BorrowFlag {
+    pub(crate) fn borrow<T>(&self) -> Result<BorrowGuard<'_>> {
+        self.borrows
+            .fetch_update(Ordering::SeqCst, Ordering::Relaxed, |borrows| {
+                (borrows >= 0).then_some(borrows + 1)
+            })
+            .map(|_| self.guard())
+            .map_err(|_| BoxerError::AlreadyBorrowed(type_name::<T>().to_string()))
+    }
+
+    pub(crate) fn borrow_mut<T>(&self) -> Result<BorrowGuard<'_>> {
+        self.borrows
+            .compare_exchange(0, MUTABLY_BORROWED, Ordering::SeqCst, Ordering::Relaxed)
+            .map(|_| self.guard())
+            .map_err(|_| BoxerError::AlreadyBorrowed(type_name::<T>().to_string()))
+    }
+
+    /// Borrow a shared box before locking its value at a given address.
+    /// Fails with [`BoxerError::AlreadyBorrowed`] if the current thread already locked the value
+    /// for writing or, when borrowing it mutably, at all, even through another box sharing it.
+    pub(crate) fn borrow_shared<T>(
+        &self,
+        value: *const (),
+        mutably: bool,
+    ) -> Result<BorrowGuard<'_>> {
+        let mut guard = self.borrow::<T>()?;
+        let value = value as usize;
+        guard.nested = LOCKED_VALUES.with(|locked_values| {
+            let mut locked_values = locked_values.borrow_mut();
+            let mut nested = false;
+            for (locked_value, locked_mutably) in locked_values.iter() {
+                if *locked_value == value {
+                    if mutably || *locked_mutably {
+                        return BoxerError::AlreadyBorrowed(type_name::<T>().to_string()).into();
+                    }
+                    nested = true;
+                }
+            }
+            locked_values.push((value, mutably));
+            Ok(nested)
+        })?;
+        guard.locked_value = Some(value);
+        Ok(guard)
+    }
+
+    /// Mark the box as released and return true if it is not borrowed and can be freed right away.
+    /// Otherwise the box must be freed once the last borrow ends, see [`BorrowFlag::claim_released`].
+    pub(crate) fn release(&self) -> bool {
+        self.released.store(true, Ordering::SeqCst);
+        self.claim()
+    }
+
+    /// Return true if the box was released while it was borrowed and
+    /// the borrows have ended since, in which case it must be freed by the caller.
+    pub(crate) fn claim_released(&self) -> bool {
+        self.released.load(Ordering::SeqCst) && self.claim()
+    }
+
+    /// Borrow the box mutably for good, so that only one caller gets to free it.
+    fn claim(&self) -> bool {
+        self.borrows
+            .compare_exchange(0, MUTABLY_BORROWED, Ordering::SeqCst, Ordering::Relaxed)
+            .is_ok()
+    }
+
+    fn guard(&self) -> BorrowGuard<'_> {
+        BorrowGuard {
+            flag: self,
+            locked_value: None,
+            nested: false,
+        }
+    }
+}
+
+#[cfg(not(feature = "unchecked-borrows"))]
+impl BorrowGuard<'_> {
+    /// Return true if the current thread already holds a read lock of the shared value,
+    /// which it may not wait for again as a waiting writer would wait for the outer lock.
+    pub(crate) fn is_nested(&self) -> bool {
+        self.nested
+    }
+}
+
+#[cfg(not(feature = "unchecked-borrows"))]
+impl Drop for BorrowGuard<'_> {
+    fn drop(&mut self) {
+        if let Some(value) = self.locked_value {
+            LOCKED_VALUES.with(|locked_values| {
+                let mut locked_values = locked_values.borrow_mut();
+                if let Some(index) = locked_values
+                    .iter()
+                    .rposition(|(locked_value, _)| *locked_value == value)
+                {
+                    locked_values.remove(index);
+                }
+            });
+        }
+
+        let borrows = &self.flag.borrows;
+        if borrows.load(Ordering::Relaxed) == MUTABLY_BORROWED {
+            borrows.store(0, Ordering::SeqCst);
+        } else {
+            borrows.fetch_sub(1, Ordering::SeqCst);
+        }
+    }
+}
+
+#[cfg(feature = "unchecked-borrows")]
+impl BorrowFlag {
+    pub(crate) fn borrow<T>(&self) -> Result<BorrowGuard<'_>> {
+        Ok(BorrowGuard {
+            flag: std::marker::PhantomData,
+        })
+    }
+
+    pub(crate) fn borrow_mut<T>(&self) -> Result<BorrowGuard<'_>> {
+        self.borrow::<T>()
+    }
+
+    pub(crate) fn borrow_shared<T>(
+        &self,
+        _value: *const (),
+        _mutably: bool,
+    ) -> Result<BorrowGuard<'_>> {
+        self.borrow::<T>()
+    }
+
+    pub(crate) fn release(&self) -> bool {
+        true
+    }
+
+    pub(crate) fn claim_released(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(feature = "unchecked-borrows")]
+impl BorrowGuard<'_> {
+    pub(crate) fn is_nested(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(all(test, not(feature = "unchecked-borrows")))]
+mod test {
+    use std::rc::Rc;
+    use std::sync::Arc;
+
+    use crate::{BoxerError, Result, ValueBox, ValueBoxPointer};
+
+    #[test]
+    fn reentrant_mutable_borrow() -> Result<()> {
+        let value = ValueBox::new(5).into_raw();
+
+        let result = value.with_mut(|_| value.with_mut_ok(|value| *value += 1));
+        assert!(matches!(result, Err(BoxerError::AlreadyBorrowed(_))));
+
+        let result = value.with_ref(|_| value.with_mut_ok(|value| *value += 1));
+        assert!(matches!(result, Err(BoxerError::AlreadyBorrowed(_))));
+
+        let result = value.with_mut(|_| value.with_ref_ok(|value| *value));
+        assert!(matches!(result, Err(BoxerError::AlreadyBorrowed(_))));
+
+        assert_eq!(value.with_ref(|a| value.with_ref_ok(|b| a + b))?, 10);
+        value.with_mut_ok(|value| *value += 1)?;
+
+        value.release();
+        Ok(())
+    }
+
+    #[test]
+    fn reentrant_shared_borrow() -> Result<()> {
+        let value = ValueBox::new_shared(5).into_raw();
+        let retained = value.retain()?.into_raw();
+
+        let result = value.with_mut(|_| value.with_mut_ok(|value| *value += 1));
+        assert!(matches!(result, Err(BoxerError::AlreadyBorrowed(_))));
+
+        let result = value.with_ref(|_| retained.with_mut_ok(|value| *value += 1));
+        assert!(matches!(result, Err(BoxerError::AlreadyBorrowed(_))));
+
+        let result = value.with_mut(|_| retained.try_with_ref(|value| Ok(*value)));
+        assert!(matches!(result, Err(BoxerError::AlreadyBorrowed(_))));
+
+        let result = value.with_ref(|_| retained.take_value());
+        assert!(matches!(result, Err(BoxerError::AlreadyBorrowed(_))));
+
+        assert_eq!(value.with_ref(|a| retained.with_ref_ok(|b| a + b))?, 10);
+        value.with_mut_ok(|value| *value += 1)?;
+        assert_eq!(retained.with_ref_ok(|value| *value)?, 6);
+
+        value.release();
+        retained.release();
+        Ok(())
+    }
+
+    #[test]
+    fn release_shared_while_borrowed() -> Result<()> {
+        let counter = Arc::new(5);
+        let value = ValueBox::new_shared(counter.clone()).into_raw();
+
+        value.with_mut_ok(|_| {
+            value.release();
+            assert_eq!(Arc::strong_count(&counter), 2);
+        })?;
+        assert_eq!(Arc::strong_count(&counter), 1);
+
+        let value = ValueBox::new_shared(counter.clone()).into_raw();
+        let weak = value.downgrade()?.into_raw();
+        weak.with_ref_ok(|_| {
+            weak.release();
+            assert_eq!(Arc::strong_count(&counter), 2);
+        })?;
+        value.release();
+        assert_eq!(Arc::strong_count(&counter), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn take_while_borrowed() -> Result<()> {
+        let value = ValueBox::new(5).into_raw();
+
+        let result = value.with_ref(|_| value.take_value());
+        assert!(matches!(result, Err(BoxerError::AlreadyBorrowed(_))));
+
+        let result = value.with_ref(|_| value.to_ref()?.replace(6));
+        assert!(matches!(result, Err(BoxerError::AlreadyBorrowed(_))));
+        assert_eq!(value.with_ref_ok(|value| *value)?, 5);
+
+        value.release();
+        Ok(())
+    }
+
+    #[test]
+    fn release_while_borrowed() -> Result<()> {
+        let counter = Rc::new(5);
+        let value = ValueBox::new(counter.clone()).into_raw();
+
+        value.with_ref_ok(|_| {
+            value.release();
+            assert_eq!(Rc::strong_count(&counter), 2);
+        })?;
+        assert_eq!(Rc::strong_count(&counter), 1);
+
+        let value = ValueBox::new(counter.clone()).into_raw();
+        value.replace_value(|previous| {
+            value.release();
+            previous
+        })?;
+        assert_eq!(Rc::strong_count(&counter), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn borrow_after_panic() -> Result<()> {
+        let value = ValueBox::new(5).into_raw();
+
+        let result = value.with_mut_ok(|_| panic!("Panic while borrowed"));
+        assert!(matches!(result, Err(BoxerError::Panic(_))));
+        assert_eq!(value.with_mut_ok(|value| *value)?, 5);
+
+        value.release();
+        Ok(())
+    }
+}
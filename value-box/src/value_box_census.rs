@@ -0,0 +1,123 @@
+use std::backtrace::Backtrace;
+use std::collections::BTreeMap;
+use std::ffi::c_void;
+use std::fmt::Write;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+static LIVE_BOXES: Mutex<BTreeMap<usize, LiveValueBox>> = Mutex::new(BTreeMap::new());
+static CAPTURE_BACKTRACES: AtomicBool = AtomicBool::new(false);
+
+/// A box that was passed to the foreign side with [`crate::ValueBox::into_raw`]
+/// and was not released yet.
+#[derive(Debug, Clone)]
+pub struct LiveValueBox {
+    pub address: usize,
+    pub type_name: &'static str,
+    /// Where the box was created, only present when backtraces are captured,
+    /// see [`census_capture_backtraces`].
+    pub backtrace: Option<Arc<Backtrace>>,
+}
+
+/// Enable or disable capturing of a backtrace every time a box is passed to the foreign side.
+/// Capturing is expensive and is disabled by default.
+pub fn census_capture_backtraces(enabled: bool) {
+    CAPTURE_BACKTRACES.store(enabled, Ordering::Relaxed);
+}
+
+/// Return the amount of boxes that were not released yet.
+pub fn census_live_count() -> usize {
+    live_boxes().len()
+}
+
+/// Return the amount of live boxes per type name.
+pub fn census_count_per_type() -> BTreeMap<&'static str, usize> {
+    let mut counts = BTreeMap::new();
+    for live_box in live_boxes().values() {
+        *counts.entry(live_box.type_name).or_insert(0) += 1;
+    }
+    counts
+}
+
+/// Return all boxes that were not released yet, ordered by their address.
+pub fn census_live_boxes() -> Vec<LiveValueBox> {
+    live_boxes().values().cloned().collect()
+}
+
+/// Describe all live boxes in a human readable form.
+pub fn census_dump() -> String {
+    let live_boxes = census_live_boxes();
+    let mut dump = String::new();
+
+    writeln!(dump, "Live value boxes: {}", live_boxes.len()).unwrap();
+    for (type_name, count) in census_count_per_type() {
+        writeln!(dump, "  {}: {}", type_name, count).unwrap();
+    }
+    for live_box in live_boxes {
+        writeln!(dump, "{:#x}: {}", live_box.address, live_box.type_name).unwrap();
+        if let Some(backtrace) = live_box.backtrace {
+            writeln!(dump, "{}", backtrace).unwrap();
+        }
+    }
+    dump
+}
+
+pub(crate) fn census_record(pointer: *const c_void, type_name: &'static str) {
+    let backtrace = if CAPTURE_BACKTRACES.load(Ordering::Relaxed) {
+        Some(Arc::new(Backtrace::force_capture()))
+    } else {
+        None
+    };
+
+    let address = pointer as usize;
+    live_boxes().insert(
+        address,
+        LiveValueBox {
+            address,
+            type_name,
+            backtrace,
+        },
+    );
+}
+
+pub(crate) fn census_forget(pointer: *const c_void) {
+    live_boxes().remove(&(pointer as usize));
+}
+
+fn live_boxes() -> MutexGuard<'static, BTreeMap<usize, LiveValueBox>> {
+    LIVE_BOXES
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{ValueBox, ValueBoxPointer};
+
+    struct CensusObject;
+
+    fn census_object_count() -> usize {
+        census_count_per_type()
+            .get(std::any::type_name::<CensusObject>())
+            .copied()
+            .unwrap_or(0)
+    }
+
+    #[test]
+    fn census_into_raw_and_release() {
+        let first = ValueBox::new(CensusObject).into_raw();
+        let second = ValueBox::new(CensusObject).into_raw();
+        assert_eq!(census_object_count(), 2);
+        assert!(census_live_boxes()
+            .iter()
+            .any(|live_box| live_box.address == first as usize));
+        assert!(census_dump().contains(std::any::type_name::<CensusObject>()));
+
+        first.release();
+        assert_eq!(census_object_count(), 1);
+
+        second.release();
+        assert_eq!(census_object_count(), 0);
+    }
+}
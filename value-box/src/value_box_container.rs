@@ -1,4 +1,5 @@
 use std::any::Any;
+use std::sync::{Arc, PoisonError, RwLock, TryLockError, Weak};
 
 pub(crate) trait ValueBoxContainer<T: Any> {
     fn replace_value(&mut self, object: T) -> Option<T>;
@@ -9,6 +10,17 @@ pub(crate) trait ValueBoxContainer<T: Any> {
     fn has_value(&self) -> bool;
 }
 
+/// A container whose value is accessed from multiple threads at the same time,
+/// therefore it is only ever accessed by a shared reference and locks internally.
+pub(crate) trait SharedValueBoxContainer<T: Any> {
+    fn replace_value(&self, object: T) -> Option<T>;
+    fn take_value(&self) -> Option<T>;
+    fn clone_value(&self) -> Option<T>
+    where
+        T: Clone;
+    fn has_value(&self) -> bool;
+}
+
 impl<T: Any> ValueBoxContainer<T> for Option<Box<T>> {
     fn replace_value(&mut self, object: T) -> Option<T> {
         self.replace(Box::new(object)).map(|boxed| *boxed)
@@ -29,3 +41,61 @@ impl<T: Any> ValueBoxContainer<T> for Option<Box<T>> {
         self.is_some()
     }
 }
+
+impl<T: Any> SharedValueBoxContainer<T> for Arc<RwLock<Option<Box<T>>>> {
+    fn replace_value(&self, object: T) -> Option<T> {
+        self.write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .replace_value(object)
+    }
+
+    fn take_value(&self) -> Option<T> {
+        self.write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .take_value()
+    }
+
+    fn clone_value(&self) -> Option<T>
+    where
+        T: Clone,
+    {
+        self.read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone_value()
+    }
+
+    fn has_value(&self) -> bool {
+        match self.try_read() {
+            Ok(value) => value.has_value(),
+            Err(TryLockError::Poisoned(value)) => value.into_inner().has_value(),
+            // the value is being mutated by another thread, which is only possible if it is there.
+            // The presence of the value is checked again once the lock is acquired
+            Err(TryLockError::WouldBlock) => true,
+        }
+    }
+}
+
+impl<T: Any> SharedValueBoxContainer<T> for Weak<RwLock<Option<Box<T>>>> {
+    fn replace_value(&self, object: T) -> Option<T> {
+        self.upgrade().and_then(|value| value.replace_value(object))
+    }
+
+    fn take_value(&self) -> Option<T> {
+        self.upgrade().and_then(|value| value.take_value())
+    }
+
+    fn clone_value(&self) -> Option<T>
+    where
+        T: Clone,
+    {
+        self.upgrade().and_then(|value| value.clone_value())
+    }
+
+    fn has_value(&self) -> bool {
+        self.upgrade()
+            .map(|value| {
+                <Arc<RwLock<Option<Box<T>>>> as SharedValueBoxContainer<T>>::has_value(&value)
+            })
+            .unwrap_or(false)
+    }
+}
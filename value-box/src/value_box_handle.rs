@@ -0,0 +1,379 @@
+use std::any::{type_name, Any};
+use std::ffi::c_void;
+use std::fmt::{Debug, Formatter};
+use std::marker::PhantomData;
+use std::sync::{Mutex, MutexGuard};
+
+use crate::{BoxRef, BoxerError, Result, ReturnBoxerResult, ValueBox, ValueBoxPointer};
+
+static REGISTRY: Mutex<ValueBoxRegistry> = Mutex::new(ValueBoxRegistry::new());
+
+/// An opaque handle to a [`ValueBox`] stored in the global registry.
+/// The lower 32 bits are the index of the slot and the upper 32 bits are
+/// the generation of that slot, so that a handle to a released box
+/// can not resolve to another box that later reuses the same slot.
+/// The handle is `#[repr(transparent)]` and can be passed over ffi as `u64`.
+#[repr(transparent)]
+pub struct ValueBoxHandle<T: Any> {
+    handle: u64,
+    value_type: PhantomData<*mut ValueBox<T>>,
+}
+
+impl<T: Any> ValueBoxHandle<T> {
+    /// Create a handle from an integer received from the foreign side.
+    pub fn from_u64(handle: u64) -> Self {
+        Self {
+            handle,
+            value_type: PhantomData,
+        }
+    }
+
+    /// A handle that never refers to a box, similar to a null pointer.
+    pub fn null() -> Self {
+        Self::from_u64(0)
+    }
+
+    pub fn is_null(&self) -> bool {
+        self.handle == 0
+    }
+
+    pub fn to_u64(self) -> u64 {
+        self.handle
+    }
+
+    /// Resolve the handle to the registered box and keep it from being freed
+    /// until the returned pin is dropped.
+    fn pin(self) -> Result<SlotPin> {
+        if self.is_null() {
+            return BoxerError::NullPointer(type_name::<T>().to_string()).into();
+        }
+        registry()
+            .pin(self.handle)
+            .map(|pointer| SlotPin {
+                handle: self.handle,
+                pointer,
+            })
+            .ok_or_else(|| self.invalid_handle())
+    }
+
+    fn invalid_handle(self) -> BoxerError {
+        BoxerError::InvalidHandle(type_name::<T>().to_string(), self.handle)
+    }
+}
+
+impl<T: Any> Clone for ValueBoxHandle<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Any> Copy for ValueBoxHandle<T> {}
+
+impl<T: Any> PartialEq for ValueBoxHandle<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.handle == other.handle
+    }
+}
+
+impl<T: Any> Eq for ValueBoxHandle<T> {}
+
+impl<T: Any> Debug for ValueBoxHandle<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ValueBoxHandle")
+            .field("index", &(self.handle as u32))
+            .field("generation", &((self.handle >> 32) as u32))
+            .finish()
+    }
+}
+
+impl<T: Any> From<u64> for ValueBoxHandle<T> {
+    fn from(handle: u64) -> Self {
+        Self::from_u64(handle)
+    }
+}
+
+impl<T: Any> From<ValueBoxHandle<T>> for u64 {
+    fn from(handle: ValueBoxHandle<T>) -> Self {
+        handle.to_u64()
+    }
+}
+
+impl<T: Any> ValueBoxPointer<T> for ValueBoxHandle<T> {
+    fn to_ref(&self) -> Result<BoxRef<T>> {
+        let pin = self.pin()?;
+        (pin.pointer as *mut ValueBox<T>)
+            .to_ref()
+            .map(|box_ref| box_ref.pinned(pin))
+    }
+
+    fn take_value(&self) -> Result<T> {
+        let pin = self.pin()?;
+        (pin.pointer as *mut ValueBox<T>).take_value()
+    }
+
+    fn release(self) {
+        if self.is_null() {
+            return Result::<()>::from(BoxerError::NullPointer(type_name::<T>().to_string())).log();
+        }
+
+        let removed = registry().remove(self.handle, release_box::<T>);
+        match removed {
+            SlotRemoval::Removed(pointer) => release_box::<T>(pointer),
+            // the last pin frees the box
+            SlotRemoval::Deferred => {}
+            SlotRemoval::Unknown => Result::<()>::from(self.invalid_handle()).log(),
+        }
+    }
+}
+
+fn release_box<T: Any>(pointer: *mut c_void) {
+    (pointer as *mut ValueBox<T>).release()
+}
+
+/// Keeps a registered box from being freed while it is accessed through a handle,
+/// a box released in the meantime is freed when the last pin is dropped.
+pub(crate) struct SlotPin {
+    handle: u64,
+    pointer: *mut c_void,
+}
+
+impl Drop for SlotPin {
+    fn drop(&mut self) {
+        // the box must be freed outside of the registry lock
+        let released = registry().unpin(self.handle);
+        if let Some((pointer, release)) = released {
+            release(pointer);
+        }
+    }
+}
+
+impl<T: Any> ValueBox<T> {
+    /// Register the box in the global registry and return a handle to it.
+    /// Unlike [`ValueBox::into_raw`], stale, released or forged handles are detected
+    /// and reported as [`BoxerError::InvalidHandle`] instead of dereferencing invalid memory.
+    pub fn into_handle(self) -> ValueBoxHandle<T> {
+        let pointer = self.into_raw() as *mut c_void;
+        ValueBoxHandle::from_u64(registry().insert(pointer))
+    }
+}
+
+/// Return the amount of boxes currently registered behind handles.
+pub fn value_box_handle_count() -> usize {
+    registry().len()
+}
+
+fn registry() -> MutexGuard<'static, ValueBoxRegistry> {
+    REGISTRY
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+struct ValueBoxSlot {
+    generation: u32,
+    pointer: *mut c_void,
+    /// The amount of [`SlotPin`]s accessing the box
+    pins: usize,
+    /// Set when the box is released while pinned
+    release: Option<ReleaseFn>,
+}
+
+/// Frees a box of the type it was registered with
+type ReleaseFn = fn(*mut c_void);
+
+enum SlotRemoval {
+    /// The box is not accessed and must be freed by the caller
+    Removed(*mut c_void),
+    /// The box is freed once it is unpinned
+    Deferred,
+    Unknown,
+}
+
+struct ValueBoxRegistry {
+    slots: Vec<ValueBoxSlot>,
+    free_slots: Vec<u32>,
+    len: usize,
+}
+
+/// The registry only stores the addresses of the boxes and never dereferences them.
+unsafe impl Send for ValueBoxRegistry {}
+
+impl ValueBoxRegistry {
+    const fn new() -> Self {
+        Self {
+            slots: Vec::new(),
+            free_slots: Vec::new(),
+            len: 0,
+        }
+    }
+
+    fn insert(&mut self, pointer: *mut c_void) -> u64 {
+        let index = match self.free_slots.pop() {
+            Some(index) => {
+                let slot = &mut self.slots[index as usize];
+                slot.pointer = pointer;
+                slot.pins = 0;
+                slot.release = None;
+                index
+            }
+            None => {
+                let index = u32::try_from(self.slots.len())
+                    .expect("The amount of value box handles must fit into u32");
+                // generations start with 1, so that the handle is never 0
+                self.slots.push(ValueBoxSlot {
+                    generation: 1,
+                    pointer,
+                    pins: 0,
+                    release: None,
+                });
+                index
+            }
+        };
+        self.len += 1;
+        Self::encode(index, self.slots[index as usize].generation)
+    }
+
+    /// Return the slot of a box that is registered and not released
+    fn slot_of(&mut self, handle: u64) -> Option<(u32, &mut ValueBoxSlot)> {
+        let (index, generation) = Self::decode(handle);
+        self.slots
+            .get_mut(index as usize)
+            .filter(|slot| {
+                slot.generation == generation && !slot.pointer.is_null() && slot.release.is_none()
+            })
+            .map(|slot| (index, slot))
+    }
+
+    fn pin(&mut self, handle: u64) -> Option<*mut c_void> {
+        self.slot_of(handle).map(|(_, slot)| {
+            slot.pins += 1;
+            slot.pointer
+        })
+    }
+
+    /// Return the box and the function to free it with
+    /// if it was released while pinned and this was the last pin.
+    fn unpin(&mut self, handle: u64) -> Option<(*mut c_void, ReleaseFn)> {
+        let (index, _) = Self::decode(handle);
+        let slot = &mut self.slots[index as usize];
+        slot.pins -= 1;
+        if slot.pins > 0 {
+            return None;
+        }
+        let release = slot.release.take()?;
+        Some((self.free_slot(index), release))
+    }
+
+    fn remove(&mut self, handle: u64, release: ReleaseFn) -> SlotRemoval {
+        match self.slot_of(handle) {
+            Some((_, slot)) if slot.pins > 0 => {
+                slot.release = Some(release);
+                SlotRemoval::Deferred
+            }
+            Some((index, _)) => SlotRemoval::Removed(self.free_slot(index)),
+            None => SlotRemoval::Unknown,
+        }
+    }
+
+    fn free_slot(&mut self, index: u32) -> *mut c_void {
+        let slot = &mut self.slots[index as usize];
+        let pointer = std::mem::replace(&mut slot.pointer, std::ptr::null_mut());
+        // a slot whose generation wrapped around is retired to never produce a repeated handle
+        slot.generation = slot.generation.wrapping_add(1);
+        if slot.generation != 0 {
+            self.free_slots.push(index);
+        }
+        self.len -= 1;
+        pointer
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn encode(index: u32, generation: u32) -> u64 {
+        ((generation as u64) << 32) | index as u64
+    }
+
+    fn decode(handle: u64) -> (u32, u32) {
+        (handle as u32, (handle >> 32) as u32)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::rc::Rc;
+
+    use super::*;
+
+    #[test]
+    fn handle_with_ref() -> Result<()> {
+        let handle = ValueBox::new(5).into_handle();
+        assert!(!handle.is_null());
+        assert_eq!(handle.with_ref_ok(|value| *value * 2)?, 10);
+        handle.release();
+
+        Ok(())
+    }
+
+    #[test]
+    fn handle_release_twice() {
+        let value = Rc::new(42);
+
+        let handle = ValueBox::new(value.clone()).into_handle();
+        assert_eq!(Rc::strong_count(&value), 2);
+        handle.release();
+        assert_eq!(Rc::strong_count(&value), 1);
+
+        // releasing a stale handle must not free anything
+        handle.release();
+        assert!(matches!(
+            handle.with_ref_ok(|value| **value),
+            Err(BoxerError::InvalidHandle(_, _))
+        ));
+    }
+
+    #[test]
+    fn stale_handle_does_not_resolve_to_reused_slot() {
+        let first = ValueBox::new(1).into_handle();
+        first.release();
+
+        let second = ValueBox::new(2).into_handle();
+        assert_ne!(first, second);
+        assert!(first.with_ref_ok(|value| *value).is_err());
+        assert_eq!(second.with_ref_ok(|value| *value).or_log(0), 2);
+        second.release();
+    }
+
+    #[test]
+    fn release_while_accessed() -> Result<()> {
+        let value = Rc::new(42);
+
+        let handle = ValueBox::new(value.clone()).into_handle();
+        let result = handle.with_ref_ok(|boxed| {
+            handle.release();
+            // the box is freed once the access is over
+            (Rc::strong_count(&value), **boxed)
+        })?;
+        assert_eq!(result, (2, 42));
+        assert_eq!(Rc::strong_count(&value), 1);
+        assert!(matches!(
+            handle.with_ref_ok(|value| **value),
+            Err(BoxerError::InvalidHandle(_, _))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn null_and_forged_handles() {
+        let null = ValueBoxHandle::<i32>::null();
+        assert!(matches!(null.take_value(), Err(BoxerError::NullPointer(_))));
+
+        let forged = ValueBoxHandle::<i32>::from_u64(0xDEAD_BEEF_0000_FFFF);
+        assert!(matches!(
+            forged.take_value(),
+            Err(BoxerError::InvalidHandle(_, 0xDEAD_BEEF_0000_FFFF))
+        ));
+        assert!(!forged.has_value());
+    }
+}
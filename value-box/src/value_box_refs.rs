@@ -0,0 +1,241 @@
+use std::any::{type_name, Any};
+use std::ffi::c_void;
+
+use crate::{AnyValueBoxPointer, BoxerError, Result, ValueBox, ValueBoxPointer};
+
+/// A box that can be borrowed together with other boxes, see [`ValueBoxRefs::with_refs`].
+/// A pointer `*mut ValueBox<T>` is borrowed as `&T`, wrapped in [`Mutably`] it is borrowed as `&mut T`.
+pub trait BoxBorrow {
+    type Borrowed<'a>;
+
+    /// Evaluate a given function with the borrowed value.
+    fn with_borrow<R: Any, F>(&self, op: F) -> Result<R>
+    where
+        F: for<'a> FnOnce(Self::Borrowed<'a>) -> Result<R>;
+
+    /// Borrows of the boxed value are shorter than the box.
+    fn shorten<'long: 'short, 'short>(borrowed: Self::Borrowed<'long>) -> Self::Borrowed<'short>;
+
+    /// The address of the value that is borrowed, which is shared by
+    /// all boxes referring to the same value, see [`ValueBoxPointer::retain`].
+    fn borrowed_address(&self) -> *const c_void;
+
+    fn is_mutable(&self) -> bool;
+
+    fn borrowed_type_name(&self) -> &'static str;
+}
+
+/// Borrow a box mutably when borrowing multiple boxes with [`ValueBoxRefs::with_refs`].
+#[derive(Debug)]
+pub struct Mutably<T: Any>(pub *mut ValueBox<T>);
+
+impl<T: Any> BoxBorrow for *mut ValueBox<T> {
+    type Borrowed<'a> = &'a T;
+
+    fn with_borrow<R: Any, F>(&self, op: F) -> Result<R>
+    where
+        F: for<'a> FnOnce(&'a T) -> Result<R>,
+    {
+        self.with_ref(op)
+    }
+
+    fn shorten<'long: 'short, 'short>(borrowed: &'long T) -> &'short T {
+        borrowed
+    }
+
+    fn borrowed_address(&self) -> *const c_void {
+        borrowed_address(*self)
+    }
+
+    fn is_mutable(&self) -> bool {
+        false
+    }
+
+    fn borrowed_type_name(&self) -> &'static str {
+        type_name::<T>()
+    }
+}
+
+impl<T: Any> BoxBorrow for Mutably<T> {
+    type Borrowed<'a> = &'a mut T;
+
+    fn with_borrow<R: Any, F>(&self, op: F) -> Result<R>
+    where
+        F: for<'a> FnOnce(&'a mut T) -> Result<R>,
+    {
+        self.0.with_mut(op)
+    }
+
+    fn shorten<'long: 'short, 'short>(borrowed: &'long mut T) -> &'short mut T {
+        borrowed
+    }
+
+    fn borrowed_address(&self) -> *const c_void {
+        borrowed_address(self.0)
+    }
+
+    fn is_mutable(&self) -> bool {
+        true
+    }
+
+    fn borrowed_type_name(&self) -> &'static str {
+        type_name::<T>()
+    }
+}
+
+/// The address of the boxed value, or of the box itself if it is empty or null.
+fn borrowed_address<T: Any>(pointer: *mut ValueBox<T>) -> *const c_void {
+    let value = pointer.get_ptr();
+    if value.is_null() {
+        pointer as *const c_void
+    } else {
+        value
+    }
+}
+
+/// Make sure that no value is borrowed mutably more than once,
+/// given the address, mutability and type name of each borrow.
+fn check_aliasing(borrows: &[(*const c_void, bool, &'static str)]) -> Result<()> {
+    for (index, (address, is_mutable, type_name)) in borrows.iter().enumerate() {
+        for (other_address, other_is_mutable, _) in &borrows[index + 1..] {
+            if (*is_mutable || *other_is_mutable) && address == other_address {
+                return BoxerError::AlreadyBorrowed(type_name.to_string()).into();
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Borrow a tuple of boxes at once, for example
+/// `(first, Mutably(second)).with_refs(|(first, second)| ...)`.
+/// Borrowing the same value mutably more than once fails with [`BoxerError::AlreadyBorrowed`].
+pub trait ValueBoxRefs {
+    type Borrowed<'a>;
+
+    /// Evaluate a given function with the borrowed values.
+    /// The lifetime of the references can not outlive the closure.
+    fn with_refs<R: Any, F>(&self, op: F) -> Result<R>
+    where
+        F: for<'a> FnOnce(Self::Borrowed<'a>) -> Result<R>;
+
+    /// Evaluate a given function that can not fail with the borrowed values.
+    /// The lifetime of the references can not outlive the closure.
+    fn with_refs_ok<R: Any, F>(&self, op: F) -> Result<R>
+    where
+        F: for<'a> FnOnce(Self::Borrowed<'a>) -> R,
+    {
+        self.with_refs(|borrowed| Ok(op(borrowed)))
+    }
+}
+
+/// Borrow the boxes of a tuple one after another,
+/// calling the function once all of them are borrowed.
+macro_rules! with_nested_borrows {
+    ($boxes:ident, $op:ident; [$($borrowed:ident: $B:ident),*];) => {
+        $op(($($B::shorten($borrowed),)*))
+    };
+    ($boxes:ident, $op:ident; [$($borrowed:ident: $B:ident),*]; $index:tt $next:ident: $N:ident $(, $rest_index:tt $rest:ident: $R:ident)*) => {
+        $boxes.$index.with_borrow(|$next| {
+            with_nested_borrows!($boxes, $op; [$($borrowed: $B,)* $next: $N]; $($rest_index $rest: $R),*)
+        })
+    };
+}
+
+macro_rules! value_box_refs {
+    ($($index:tt $borrowed:ident: $B:ident),+) => {
+        impl<$($B: BoxBorrow),+> ValueBoxRefs for ($($B,)+) {
+            type Borrowed<'a> = ($($B::Borrowed<'a>,)+);
+
+            fn with_refs<R: Any, F>(&self, op: F) -> Result<R>
+            where
+                F: for<'a> FnOnce(Self::Borrowed<'a>) -> Result<R>,
+            {
+                check_aliasing(&[$((
+                    self.$index.borrowed_address(),
+                    self.$index.is_mutable(),
+                    self.$index.borrowed_type_name(),
+                )),+])?;
+                with_nested_borrows!(self, op; []; $($index $borrowed: $B),+)
+            }
+        }
+    };
+}
+
+value_box_refs!(0 a: A);
+value_box_refs!(0 a: A, 1 b: B);
+value_box_refs!(0 a: A, 1 b: B, 2 c: C);
+value_box_refs!(0 a: A, 1 b: B, 2 c: C, 3 d: D);
+value_box_refs!(0 a: A, 1 b: B, 2 c: C, 3 d: D, 4 e: E);
+value_box_refs!(0 a: A, 1 b: B, 2 c: C, 3 d: D, 4 e: E, 5 f: G);
+value_box_refs!(0 a: A, 1 b: B, 2 c: C, 3 d: D, 4 e: E, 5 f: G, 6 g: H);
+value_box_refs!(0 a: A, 1 b: B, 2 c: C, 3 d: D, 4 e: E, 5 f: G, 6 g: H, 7 h: I);
+value_box_refs!(0 a: A, 1 b: B, 2 c: C, 3 d: D, 4 e: E, 5 f: G, 6 g: H, 7 h: I, 8 i: J);
+value_box_refs!(0 a: A, 1 b: B, 2 c: C, 3 d: D, 4 e: E, 5 f: G, 6 g: H, 7 h: I, 8 i: J, 9 j: K);
+value_box_refs!(0 a: A, 1 b: B, 2 c: C, 3 d: D, 4 e: E, 5 f: G, 6 g: H, 7 h: I, 8 i: J, 9 j: K, 10 k: L);
+value_box_refs!(0 a: A, 1 b: B, 2 c: C, 3 d: D, 4 e: E, 5 f: G, 6 g: H, 7 h: I, 8 i: J, 9 j: K, 10 k: L, 11 l: M);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn borrow_shared_and_mutable() -> Result<()> {
+        let source = ValueBox::new(vec![1, 2, 3]).into_raw();
+        let factor = ValueBox::new(10).into_raw();
+        let target = ValueBox::new(Vec::<i32>::new()).into_raw();
+
+        (source, factor, Mutably(target)).with_refs_ok(|(source, factor, target)| {
+            target.extend(source.iter().map(|each| each * factor));
+        })?;
+        assert_eq!(
+            target.with_ref_ok(|target| target.clone())?,
+            vec![10, 20, 30]
+        );
+
+        source.release();
+        factor.release();
+        target.release();
+        Ok(())
+    }
+
+    #[test]
+    fn borrow_the_same_box_twice() -> Result<()> {
+        let value = ValueBox::new(5).into_raw();
+
+        assert_eq!((value, value).with_refs_ok(|(a, b)| a + b)?, 10);
+
+        let result = (value, Mutably(value)).with_refs_ok(|(_, b)| *b += 1);
+        assert!(matches!(result, Err(BoxerError::AlreadyBorrowed(_))));
+
+        let result = (Mutably(value), Mutably(value)).with_refs_ok(|(_, b)| *b += 1);
+        assert!(matches!(result, Err(BoxerError::AlreadyBorrowed(_))));
+
+        assert_eq!(value.with_ref_ok(|value| *value)?, 5);
+        value.release();
+        Ok(())
+    }
+
+    #[test]
+    fn borrow_retained_value_twice() -> Result<()> {
+        let value = ValueBox::new_shared(5).into_raw();
+        let retained = value.retain()?.into_raw();
+
+        let result = (value, Mutably(retained)).with_refs_ok(|(_, b)| *b += 1);
+        assert!(matches!(result, Err(BoxerError::AlreadyBorrowed(_))));
+
+        value.release();
+        retained.release();
+        Ok(())
+    }
+
+    #[test]
+    fn borrow_null_box() {
+        let value = ValueBox::new(5).into_raw();
+        let null = std::ptr::null_mut::<ValueBox<i32>>();
+
+        let result = (value, Mutably(null)).with_refs_ok(|(a, b)| *b = *a);
+        assert!(matches!(result, Err(BoxerError::NullPointer(_))));
+
+        value.release();
+    }
+}
@@ -0,0 +1,121 @@
+use std::any::Any;
+
+use bincode::Options;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::{BoxerError, Result, ValueBox, ValueBoxPointer};
+
+/// The format of the bytes that a boxed value is serialized into.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[repr(u8)]
+pub enum SerializationFormat {
+    Json = 0,
+    Cbor = 1,
+    Bincode = 2,
+}
+
+impl TryFrom<u8> for SerializationFormat {
+    type Error = BoxerError;
+
+    fn try_from(value: u8) -> Result<Self> {
+        match value {
+            0 => Ok(Self::Json),
+            1 => Ok(Self::Cbor),
+            2 => Ok(Self::Bincode),
+            _ => BoxerError::InvalidArgument(format!("Unknown serialization format {}", value))
+                .into(),
+        }
+    }
+}
+
+/// Serialize a given value into the bytes of a given format.
+pub fn serialize_value<T: Serialize>(value: &T, format: SerializationFormat) -> Result<Vec<u8>> {
+    match format {
+        SerializationFormat::Json => serde_json::to_vec(value).map_err(serialization_error),
+        SerializationFormat::Cbor => {
+            let mut bytes = vec![];
+            ciborium::into_writer(value, &mut bytes).map_err(serialization_error)?;
+            Ok(bytes)
+        }
+        SerializationFormat::Bincode => bincode::serialize(value).map_err(serialization_error),
+    }
+}
+
+/// Deserialize a value from the bytes of a given format.
+/// The bincode length prefixes can not make it allocate more than the size of the bytes.
+pub fn deserialize_value<T: DeserializeOwned>(
+    bytes: &[u8],
+    format: SerializationFormat,
+) -> Result<T> {
+    match format {
+        SerializationFormat::Json => serde_json::from_slice(bytes).map_err(serialization_error),
+        SerializationFormat::Cbor => ciborium::from_reader(bytes).map_err(serialization_error),
+        SerializationFormat::Bincode => bincode::options()
+            .with_fixint_encoding()
+            .with_limit(bytes.len() as u64)
+            .deserialize(bytes)
+            .map_err(serialization_error),
+    }
+}
+
+fn serialization_error(error: impl std::fmt::Display) -> BoxerError {
+    BoxerError::SerializationError(error.to_string())
+}
+
+/// Serialize the value of a box, for example to take a snapshot of it.
+pub trait ValueBoxSerde<T: Any + Serialize> {
+    fn serialize_value(&self, format: SerializationFormat) -> Result<Vec<u8>>;
+}
+
+impl<T: Any + Serialize> ValueBoxSerde<T> for *mut ValueBox<T> {
+    fn serialize_value(&self, format: SerializationFormat) -> Result<Vec<u8>> {
+        self.with_ref(|value| serialize_value(value, format))
+    }
+}
+
+impl<T: Any + DeserializeOwned> ValueBox<T> {
+    /// Create a box with a value deserialized from the bytes of a given format.
+    pub fn deserialize(bytes: &[u8], format: SerializationFormat) -> Result<Self> {
+        deserialize_value(bytes, format).map(ValueBox::new)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn serialize_and_deserialize() -> Result<()> {
+        let value = ValueBox::new(vec![(1u32, String::from("one"))]).into_raw();
+
+        for format in [
+            SerializationFormat::Json,
+            SerializationFormat::Cbor,
+            SerializationFormat::Bincode,
+        ] {
+            let bytes = value.serialize_value(format)?;
+            let copy = ValueBox::<Vec<(u32, String)>>::deserialize(&bytes, format)?.into_raw();
+            assert_eq!(
+                copy.with_ref_ok(|copy| copy.clone())?,
+                vec![(1, String::from("one"))]
+            );
+            copy.release();
+        }
+
+        let result = ValueBox::<u32>::deserialize(b"not json", SerializationFormat::Json);
+        assert!(matches!(result, Err(BoxerError::SerializationError(_))));
+
+        let huge_length = u64::MAX.to_le_bytes();
+        let result = ValueBox::<Vec<u64>>::deserialize(&huge_length, SerializationFormat::Bincode);
+        assert!(matches!(result, Err(BoxerError::SerializationError(_))));
+
+        assert!(matches!(
+            SerializationFormat::try_from(3),
+            Err(BoxerError::InvalidArgument(_))
+        ));
+
+        value.release();
+        Ok(())
+    }
+}